@@ -276,6 +276,23 @@ wp_fsdb_signal_kind map_signal_kind(uint_T raw_type) {
     }
 }
 
+wp_fsdb_signal_direction map_signal_direction(uint_T raw_direction) {
+    switch (raw_direction) {
+    case FSDB_VD_INPUT:
+        return WP_FSDB_SIGNAL_DIRECTION_INPUT;
+    case FSDB_VD_OUTPUT:
+        return WP_FSDB_SIGNAL_DIRECTION_OUTPUT;
+    case FSDB_VD_INOUT:
+        return WP_FSDB_SIGNAL_DIRECTION_INOUT;
+    case FSDB_VD_BUFFER:
+        return WP_FSDB_SIGNAL_DIRECTION_BUFFER;
+    case FSDB_VD_LINKAGE:
+        return WP_FSDB_SIGNAL_DIRECTION_LINKAGE;
+    default:
+        return WP_FSDB_SIGNAL_DIRECTION_NONE;
+    }
+}
+
 bool is_known_non_vector_signal(wp_fsdb_signal_kind kind) {
     switch (kind) {
     case WP_FSDB_SIGNAL_KIND_EVENT:
@@ -1073,6 +1090,7 @@ bool_T scope_var_tree_callback(fsdbTreeCBType cb_type, void *client_data, void *
         record.name = scope == nullptr ? nullptr : scope->name;
         record.kind = static_cast<uint32_t>(scope == nullptr ? WP_FSDB_SCOPE_KIND_UNKNOWN : map_scope_kind(scope->type));
         record.hidden = scope != nullptr && scope->is_hidden_scope ? 1 : 0;
+        record.module = scope == nullptr ? nullptr : scope->module;
         return emit_tree_event(context, WP_FSDB_TREE_EVENT_SCOPE, &record, nullptr, nullptr);
     }
     case FSDB_TREE_CBT_VAR:
@@ -1095,6 +1113,9 @@ bool_T scope_var_tree_callback(fsdbTreeCBType cb_type, void *client_data, void *
         record.kind = static_cast<uint32_t>(signal_kind);
         record.packed_component = cb_type == FSDB_TREE_CBT_PACKED_COMP_VAR ? 1 : 0;
         record.value_encoding = static_cast<uint32_t>(classify_value_encoding(var, signal_kind));
+        record.direction = static_cast<uint32_t>(
+            var == nullptr ? WP_FSDB_SIGNAL_DIRECTION_NONE : map_signal_direction(var->direction)
+        );
         return emit_tree_event(context, WP_FSDB_TREE_EVENT_SIGNAL, nullptr, &record, nullptr);
     }
     case FSDB_TREE_CBT_UPSCOPE:
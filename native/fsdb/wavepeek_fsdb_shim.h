@@ -75,6 +75,15 @@ typedef enum wp_fsdb_signal_kind {
     WP_FSDB_SIGNAL_KIND_UNKNOWN = 32
 } wp_fsdb_signal_kind;
 
+typedef enum wp_fsdb_signal_direction {
+    WP_FSDB_SIGNAL_DIRECTION_NONE = 0,
+    WP_FSDB_SIGNAL_DIRECTION_INPUT = 1,
+    WP_FSDB_SIGNAL_DIRECTION_OUTPUT = 2,
+    WP_FSDB_SIGNAL_DIRECTION_INOUT = 3,
+    WP_FSDB_SIGNAL_DIRECTION_BUFFER = 4,
+    WP_FSDB_SIGNAL_DIRECTION_LINKAGE = 5
+} wp_fsdb_signal_direction;
+
 typedef enum wp_fsdb_datatype_kind {
     WP_FSDB_DATATYPE_KIND_ENUM = 0,
     WP_FSDB_DATATYPE_KIND_LOGIC = 1,
@@ -115,6 +124,7 @@ typedef struct wp_fsdb_scope_record {
     const char *name;
     uint32_t kind;
     int hidden;
+    const char *module;
 } wp_fsdb_scope_record;
 
 typedef struct wp_fsdb_signal_record {
@@ -128,6 +138,7 @@ typedef struct wp_fsdb_signal_record {
     uint32_t kind;
     int packed_component;
     uint32_t value_encoding;
+    uint32_t direction;
 } wp_fsdb_signal_record;
 
 typedef struct wp_fsdb_enum_label_record {
@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::cli::limits::LimitArg;
+use crate::cli::time_unit::OutputTimeUnit;
+
+#[derive(Debug, Args)]
+pub struct AuditArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Start of inclusive time range searched for the reset event (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of inclusive time range searched for reset and clock events (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+    /// Canonical scope path whose signals are audited, including nested scopes
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: String,
+    /// Reset deassertion event; its first match in range starts the count (e.g. 'posedge rst_n')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub reset: String,
+    /// Clock event counted after reset deassertion; also numbers `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: String,
+    /// Number of --clock events after reset deassertion at which signals are sampled
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 1,
+        help_heading = "Selection options"
+    )]
+    pub cycles: usize,
+    /// Maximum number of reported signals (`unlimited` disables truncation, value must be > 0)
+    #[arg(long, default_value = "50", help_heading = "Output options")]
+    pub max: LimitArg,
+    /// Machine-readable JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub json: bool,
+    /// Stream newline-delimited JSON output
+    #[arg(long, conflicts_with = "json", help_heading = "Output options")]
+    pub jsonl: bool,
+    /// Render times and durations in UNIT (dump keeps the dump time unit; auto picks per value), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Show canonical signal paths
+    #[arg(long, help_heading = "Output options")]
+    pub abs: bool,
+}
@@ -4,6 +4,7 @@ use clap::{Args, ValueEnum};
 
 use crate::cli::limits::LimitArg;
 use crate::cli::sampling::SampleMode;
+use crate::cli::time_unit::OutputTimeUnit;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
 #[value(rename_all = "kebab-case")]
@@ -38,6 +39,9 @@ pub struct ChangeArgs {
     /// Canonical scope path for scope-relative signal and trigger names
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
     /// Comma-separated top-related signal paths, or scope-relative names when --scope is set
     #[arg(
         long,
@@ -47,13 +51,16 @@ pub struct ChangeArgs {
         help_heading = "Selection options"
     )]
     pub signals: Vec<String>,
+    /// JSON layout file that decodes packed signals into named fields
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub layout: Option<PathBuf>,
     /// Event trigger expression (required; use `*` only with `--sample-mode native`)
     #[arg(long, required = true, help_heading = "Selection options")]
     pub on: String,
-    /// Value sampling mode for event-selected rows
+    /// Value sampling mode for event-selected rows: native, pre-edge, post-edge, or skew:<time>
     #[arg(
         long,
-        value_enum,
+        value_parser = SampleMode::parse,
         default_value_t = SampleMode::PreEdge,
         value_name = "MODE",
         help_heading = "Selection options"
@@ -71,6 +78,32 @@ pub struct ChangeArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Draw snapshot rows as text waveforms, one column per row
+    #[arg(long, conflicts_with_all = ["json", "jsonl"], help_heading = "Output options")]
+    pub wave: bool,
+    /// Total --wave drawing width in terminal columns (defaults to $COLUMNS, then 80)
+    #[arg(
+        long,
+        value_name = "COLS",
+        requires = "wave",
+        help_heading = "Output options"
+    )]
+    pub width: Option<usize>,
+    /// Draw --wave traces with plain ASCII glyphs instead of Unicode
+    #[arg(long, requires = "wave", help_heading = "Output options")]
+    pub ascii: bool,
     /// Unstable internal performance control (requires DEBUG=1).
     #[arg(
         long = "tune-engine",
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::cli::limits::LimitArg;
+use crate::cli::time_unit::OutputTimeUnit;
+
+#[derive(Debug, Args)]
+pub struct ClockArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Start of inclusive time range (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of inclusive time range (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+    /// Canonical scope path for a scope-relative clock signal name
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: Option<String>,
+    /// 1-bit clock signal path, or a scope-relative name when --scope is set
+    #[arg(long, value_name = "SIGNAL", help_heading = "Selection options")]
+    pub signal: String,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Report a gated segment when no rising edge follows for more than this many periods
+    #[arg(
+        long,
+        value_name = "PERIODS",
+        default_value_t = 2,
+        help_heading = "Selection options"
+    )]
+    pub gate_periods: u64,
+    /// Report a frequency change when a period differs from the current one by more than this percentage
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 5,
+        help_heading = "Selection options"
+    )]
+    pub tolerance: u64,
+    /// Maximum number of gated segments and of frequency changes (`unlimited` disables truncation, value must be > 0)
+    #[arg(long, default_value = "50", help_heading = "Output options")]
+    pub max: LimitArg,
+    /// Machine-readable JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub json: bool,
+    /// Stream newline-delimited JSON output
+    #[arg(long, conflicts_with = "json", help_heading = "Output options")]
+    pub jsonl: bool,
+    /// Render times and durations in UNIT (dump keeps the dump time unit; auto picks per value), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Show canonical signal paths
+    #[arg(long, help_heading = "Output options")]
+    pub abs: bool,
+}
@@ -0,0 +1,161 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand, ValueEnum};
+
+#[derive(Debug, Subcommand)]
+pub enum ExportCommand {
+    #[command(
+        about = "Write a GTKWave save file for selected signals.",
+        long_about = r#"Write a GTKWave save file for selected signals.
+
+Behavior:
+- Writes a `.gtkw` save file that opens the dump with the requested signals in `--signals` order.
+- Vector names carry their declared `[msb:lsb]` range so GTKWave can match them; `[width-1:0]` is used when the dump records no range.
+- `--group-by-scope` wraps consecutive signals from the same parent scope in a named GTKWave group.
+- `--radix` selects the display format for multi-bit signals; 1-bit signals always use binary.
+- `--cursor` places the primary marker and `--markers` places named markers A-Z (at most 26).
+- `--markers-from` reads marker times from the `time` fields of a saved `--json` or `--jsonl` result, for example from `property` or `extract`.
+- `--from`/`--to` set the initial visible window; omitted bounds use the dump bounds.
+- Times use dump `time_unit` in the written file.
+- Without `--out`, the save file is printed to stdout.
+
+Use this command to hand a failure found by wavepeek over to GTKWave at the right place."#,
+        after_long_help = "See also:\n  wavepeek docs show commands/export"
+    )]
+    Gtkw(GtkwArgs),
+    #[command(
+        about = "Write a Surfer command file for selected signals.",
+        long_about = r#"Write a Surfer command file for selected signals.
+
+Behavior:
+- Writes a Surfer command file (`.sucl`) for `surfer <WAVES> --command-file <FILE>`, not a Surfer state file: state files serialize Surfer's internal UI state, which changes between Surfer releases.
+- Adds the requested signals in `--signals` order with `variable_add`.
+- `--group-by-scope` inserts a divider named after each parent scope before its signals.
+- `--radix` selects the display format for multi-bit signals.
+- `--cursor` sets the cursor; `--markers` and `--markers-from` add numbered markers.
+- `--markers-from` reads marker times from the `time` fields of a saved `--json` or `--jsonl` result, for example from `property` or `extract`.
+- Times are written as raw dump ticks, which is how Surfer addresses time in commands.
+- There is no `--from`/`--to` because Surfer commands cannot set the visible time range.
+- Without `--out`, the command file is printed to stdout.
+
+Use this command to hand a failure found by wavepeek over to Surfer at the right place."#,
+        after_long_help = "See also:\n  wavepeek docs show commands/export"
+    )]
+    Surfer(SurferArgs),
+    #[command(
+        about = "Write hierarchy and value changes to a SQLite database.",
+        long_about = r#"Write hierarchy and value changes to a SQLite database.
+
+Behavior:
+- Writes a new SQLite 3 database to `--out`, replacing any existing file.
+- `scopes` holds the selected scope subtree with `parent_id`, `path`, `name`, `depth`, and `kind`.
+- `signals` holds one row per exported signal with `scope_id`, `path`, `name`, `kind`, and `width`.
+- `changes` holds `signal_id`, `raw_time`, and `value` bits (MSB first) for every value change in the window, plus the value at `--from`.
+- `metadata` records the dump path, `time_unit`, dump bounds, and the exported window; `raw_time` counts `time_unit` ticks.
+- Without `--signals`, every signal in the selected scopes is exported; events are listed without changes.
+- Indexes cover scope and signal paths, `(signal_id, raw_time)`, and `raw_time`.
+
+Use this command when an analysis needs SQL joins across many signals."#,
+        after_long_help = "See also:\n  wavepeek docs show commands/export"
+    )]
+    Sqlite(SqliteArgs),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+#[value(rename_all = "kebab-case")]
+pub enum Radix {
+    #[default]
+    Hex,
+    Bin,
+    Dec,
+    Signed,
+}
+
+#[derive(Debug, Args)]
+pub struct GtkwArgs {
+    #[command(flatten)]
+    pub save: SaveFileArgs,
+    /// Start of the initial visible window (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of the initial visible window (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct SurferArgs {
+    #[command(flatten)]
+    pub save: SaveFileArgs,
+}
+
+/// Flags shared by the viewer save-file exporters.
+#[derive(Debug, Args)]
+pub struct SaveFileArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Canonical scope path for scope-relative signal names
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: Option<String>,
+    /// Comma-separated top-related signal paths, or scope-relative names when --scope is set
+    #[arg(
+        long,
+        value_delimiter = ',',
+        num_args = 1..,
+        required = true,
+        help_heading = "Selection options"
+    )]
+    pub signals: Vec<String>,
+    /// Group consecutive signals by their parent scope
+    #[arg(long, help_heading = "Output options")]
+    pub group_by_scope: bool,
+    /// Display radix for multi-bit signals
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Radix::Hex,
+        value_name = "RADIX",
+        help_heading = "Output options"
+    )]
+    pub radix: Radix,
+    /// Cursor time (e.g. 1234ns)
+    #[arg(long, help_heading = "Output options")]
+    pub cursor: Option<String>,
+    /// Comma-separated marker times (e.g. 10ns,20ns)
+    #[arg(long, value_delimiter = ',', num_args = 1.., help_heading = "Output options")]
+    pub markers: Vec<String>,
+    /// Saved `--json` or `--jsonl` result whose row `time` fields become markers
+    #[arg(long, value_name = "FILE", help_heading = "Output options")]
+    pub markers_from: Option<PathBuf>,
+    /// Output file path (prints to stdout when omitted)
+    #[arg(long, value_name = "FILE", help_heading = "Output options")]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct SqliteArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Start of the exported window (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of the exported window (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+    /// Canonical scope path limiting the exported subtree
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: Option<String>,
+    /// Comma-separated signal paths to export instead of every signal in the selected scopes
+    #[arg(long, value_delimiter = ',', num_args = 1.., help_heading = "Selection options")]
+    pub signals: Vec<String>,
+    /// Output database path
+    #[arg(
+        long,
+        value_name = "FILE",
+        required = true,
+        help_heading = "Output options"
+    )]
+    pub out: PathBuf,
+}
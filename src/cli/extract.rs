@@ -3,6 +3,8 @@ use std::path::PathBuf;
 use clap::{Args, Subcommand, ValueEnum};
 
 use crate::cli::limits::LimitArg;
+use crate::cli::sampling::SampleMode;
+use crate::cli::time_unit::OutputTimeUnit;
 
 #[derive(Debug, Subcommand)]
 pub enum ExtractCommand {
@@ -119,10 +121,12 @@ Use this command to inspect AXI-Stream transfers without writing a generic extra
 
 Behavior:
 - Selects edge-only event timestamps with --on.
-- Always samples --when and --payload at the pre-edge sample point.
+- Samples --when and --payload at the pre-edge sample point unless --sample-mode selects post-edge or skew:<time>.
 - In single-source mode, --on, --when, and --payload define one source named by --name or "transfer".
 - In source-file mode, --source provides one or more sources and conflicts with --name, --on, --when, and --payload.
+- Payload entries with `*` or `?` are globs over scope-relative paths and entries starting with `re:` are regexes; matches expand in lexicographic order.
 - Contract for source-file mode is defined by `wavepeek schema --input`.
+- --layout decodes matching payload signals into named fields and lets --on and --when reference them as `desc.addr`.
 - JSON and JSONL rows include time, sample_time, source, and ordered payload values.
 
 Use this command to extract synchronous handshakes or transfer-like rows without joining property and value output outside wavepeek."#,
@@ -197,6 +201,18 @@ pub struct AhbArgs {
     /// Canonical scope path for scope-relative AHB signal names and include regexes
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Sample point relative to each edge: pre-edge, post-edge, or skew:<time>
+    #[arg(
+        long,
+        value_parser = SampleMode::parse,
+        default_value_t = SampleMode::PreEdge,
+        value_name = "MODE",
+        help_heading = "Selection options"
+    )]
+    pub sample_mode: SampleMode,
     /// Explicit AHB mapping STD_NAME=WAVES_NAME, e.g. haddr=dmem_haddr; may be repeated
     #[arg(
         long = "map",
@@ -232,6 +248,26 @@ pub struct AhbArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Write a self-contained HTML report with waveform strips to FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["json", "jsonl"],
+        help_heading = "Output options"
+    )]
+    pub html: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -336,6 +372,18 @@ pub struct ApbArgs {
     /// Canonical scope path for scope-relative APB signal names and include regexes
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Sample point relative to each edge: pre-edge, post-edge, or skew:<time>
+    #[arg(
+        long,
+        value_parser = SampleMode::parse,
+        default_value_t = SampleMode::PreEdge,
+        value_name = "MODE",
+        help_heading = "Selection options"
+    )]
+    pub sample_mode: SampleMode,
     /// Explicit APB mapping STD_NAME=WAVES_NAME, e.g. psel=uart_psel; may be repeated
     #[arg(
         long = "map",
@@ -362,6 +410,26 @@ pub struct ApbArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Write a self-contained HTML report with waveform strips to FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["json", "jsonl"],
+        help_heading = "Output options"
+    )]
+    pub html: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -426,6 +494,18 @@ pub struct AtbArgs {
     /// Canonical scope path for scope-relative ATB signal names and include regexes
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Sample point relative to each edge: pre-edge, post-edge, or skew:<time>
+    #[arg(
+        long,
+        value_parser = SampleMode::parse,
+        default_value_t = SampleMode::PreEdge,
+        value_name = "MODE",
+        help_heading = "Selection options"
+    )]
+    pub sample_mode: SampleMode,
     /// Explicit ATB mapping STD_NAME=WAVES_NAME, e.g. atvalid=etm_atvalid; may be repeated
     #[arg(
         long = "map",
@@ -452,6 +532,26 @@ pub struct AtbArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Write a self-contained HTML report with waveform strips to FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["json", "jsonl"],
+        help_heading = "Output options"
+    )]
+    pub html: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -586,6 +686,18 @@ pub struct AxiArgs {
     /// Canonical scope path for scope-relative AXI signal names and include regexes
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Sample point relative to each edge: pre-edge, post-edge, or skew:<time>
+    #[arg(
+        long,
+        value_parser = SampleMode::parse,
+        default_value_t = SampleMode::PreEdge,
+        value_name = "MODE",
+        help_heading = "Selection options"
+    )]
+    pub sample_mode: SampleMode,
     /// Explicit AXI mapping STD_NAME=WAVES_NAME, e.g. awvalid=cpu_dmem_awvalid; may be repeated
     #[arg(
         long = "map",
@@ -612,6 +724,26 @@ pub struct AxiArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Write a self-contained HTML report with waveform strips to FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["json", "jsonl"],
+        help_heading = "Output options"
+    )]
+    pub html: Option<PathBuf>,
 }
 
 #[derive(Debug, Args)]
@@ -661,6 +793,18 @@ pub struct AxiStreamArgs {
     /// Canonical scope path for scope-relative AXI-Stream signal names and include regexes
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Sample point relative to each edge: pre-edge, post-edge, or skew:<time>
+    #[arg(
+        long,
+        value_parser = SampleMode::parse,
+        default_value_t = SampleMode::PreEdge,
+        value_name = "MODE",
+        help_heading = "Selection options"
+    )]
+    pub sample_mode: SampleMode,
     /// Explicit AXI-Stream mapping STD_NAME=WAVES_NAME, e.g. tvalid=video_tvalid; may be repeated
     #[arg(
         long = "map",
@@ -687,6 +831,26 @@ pub struct AxiStreamArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Write a self-contained HTML report with waveform strips to FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["json", "jsonl"],
+        help_heading = "Output options"
+    )]
+    pub html: Option<PathBuf>,
 }
 
 #[derive(Debug, Args)]
@@ -711,6 +875,18 @@ pub struct GenericArgs {
     /// Canonical scope path for scope-relative event, predicate, and payload names
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Sample point relative to each edge: pre-edge, post-edge, or skew:<time>
+    #[arg(
+        long,
+        value_parser = SampleMode::parse,
+        default_value_t = SampleMode::PreEdge,
+        value_name = "MODE",
+        help_heading = "Selection options"
+    )]
+    pub sample_mode: SampleMode,
     /// Source name for single-source CLI mode (defaults to transfer)
     #[arg(long, help_heading = "Selection options")]
     pub name: Option<String>,
@@ -729,6 +905,9 @@ pub struct GenericArgs {
         help_heading = "Selection options"
     )]
     pub payload: Option<Vec<String>>,
+    /// JSON layout file that decodes packed payload signals into named fields
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub layout: Option<PathBuf>,
     /// Maximum number of extracted rows across all sources (`unlimited` disables truncation, value must be > 0)
     #[arg(long, default_value = "50", help_heading = "Output options")]
     pub max: LimitArg,
@@ -741,4 +920,24 @@ pub struct GenericArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Write a self-contained HTML report with waveform strips to FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["json", "jsonl"],
+        help_heading = "Output options"
+    )]
+    pub html: Option<PathBuf>,
 }
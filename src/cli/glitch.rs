@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::cli::limits::LimitArg;
+use crate::cli::time_unit::OutputTimeUnit;
+
+#[derive(Debug, Args)]
+pub struct GlitchArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Start of inclusive time range (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of inclusive time range (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+    /// Canonical scope path for scope-relative signal names
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// Comma-separated signal paths, or scope-relative names when --scope is set
+    #[arg(
+        long,
+        value_delimiter = ',',
+        num_args = 1..,
+        required = true,
+        help_heading = "Selection options"
+    )]
+    pub signals: Vec<String>,
+    /// Report values held for less than this duration (e.g. 1ns; omitted reports only zero-width glitches)
+    #[arg(long, value_name = "TIME", help_heading = "Selection options")]
+    pub min_width: Option<String>,
+    /// Maximum number of glitch rows (`unlimited` disables truncation, value must be > 0)
+    #[arg(long, default_value = "50", help_heading = "Output options")]
+    pub max: LimitArg,
+    /// Machine-readable JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub json: bool,
+    /// Stream newline-delimited JSON output
+    #[arg(long, conflicts_with = "json", help_heading = "Output options")]
+    pub jsonl: bool,
+    /// Render times and durations in UNIT (dump keeps the dump time unit; auto picks per value), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Show canonical signal paths
+    #[arg(long, help_heading = "Output options")]
+    pub abs: bool,
+}
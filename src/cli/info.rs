@@ -7,6 +7,15 @@ pub struct InfoArgs {
     /// Path to VCD/FST/FSDB waveform file
     #[arg(long, value_name = "FILE", help_heading = "Input options")]
     pub waves: PathBuf,
+    /// List the N nets with the most value changes (N defaults to 10)
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "10",
+        help_heading = "Output options"
+    )]
+    pub activity: Option<usize>,
     /// Machine-readable JSON output
     #[arg(long, help_heading = "Output options")]
     pub json: bool,
@@ -1,15 +1,24 @@
+pub mod audit;
+pub mod cdc;
 pub mod change;
+pub mod clock;
 pub mod docs;
+pub mod export;
 pub mod extract;
+pub mod glitch;
 pub mod info;
 pub mod limits;
 pub mod property;
+pub mod saif;
 pub mod sampling;
 pub mod schema;
 pub mod scope;
 pub mod signal;
 pub mod skill;
+pub mod time_unit;
+pub mod timing;
 pub mod value;
+pub mod view;
 
 use clap::error::ErrorKind;
 use clap::parser::ValueSource;
@@ -36,9 +45,14 @@ General conventions:
 - Default output is human-readable for waveform commands; `--json` enables machine-readable output and its contract is defined by `wavepeek schema`.
 - Time values require explicit units (`zs`, `as`, `fs`, `ps`, `ns`, `us`, `ms`, `s`) and integer magnitudes.
 - Parsed times are normalized to dump `time_unit`; time-window flags (`--from`, `--to`) use inclusive boundaries.
+- `--from`, `--to`, and `--at` also accept time expressions: `start`, `end`, an absolute time, `first(<event>)`, `last(<event>)`, or `nth(N, <event>)`, followed by optional `+D`/`-D` offsets (e.g. `end-200ns`, `first(posedge rst_n)+2us`); a leading `+D` in `--to` counts from `--from`, and in `--at` from the previous entry. Anchor events use `--on` syntax, are searched over the whole dump, and each resolved expression is reported as an info diagnostic.
+- With `--clock <event>` (value, change, property, extract, cdc, glitch, clock, and the required clocks of timing and audit), time tokens may count clock cycles: `Ncyc` is the start of cycle N (the N-th matching edge, counted from 1 like `nth(N, <event>)`) and `+Ncyc`/`-Ncyc` offsets move by whole cycles. Result rows then carry a `cycle` field for their `time`, omitted before the first edge.
+- Row timestamps use the dump time unit by default. `--time-unit <unit>` (value, change, property, extract, cdc, timing, glitch, clock, audit, and view) renders row times and durations in `zs` through `s`, or `auto` for the largest unit with a non-zero whole part, adding an exact decimal fraction when needed (e.g. `124.999ns`); `--ticks` adds the raw dump tick as an integer `tick` field in JSON rows. Rendered fractional times are output only and are not accepted back as time tokens.
+- `--sample-mode` on change, property, and extract picks the sample point for edge-only triggers: `pre-edge` (default) samples one dump tick before the edge, `post-edge` samples the settled values at the edge itself, and `skew:<time>` samples the given duration before the edge (e.g. `skew:20ps`), which must be a whole number of dump ticks. `sample_time` reports the chosen point; extract rejects `native` because its rows are always edge-sampled.
+- `--on` (change and property) also accepts time terms that need no clock: `every 10ns` or `every 10ns phase 3ns` for a regular grid, `at 100ns, 250ns` for explicit times, and `timestamps` for every dump timestamp (`\timestamps` names a signal instead); they compose with `or` and `iff` and sample like edge terms.
 - Process-level failures follow `fatal: <category>: <message>`."#,
     after_help = "Next steps:\n  wavepeek --help\n  wavepeek help <command-path...>\n  wavepeek docs\n  wavepeek skill",
-    help_template = "{about-with-newline}\nUsage: {usage}\n\nWaveform commands:\n  info      Show waveform metadata\n  scope     Explore hierarchy scopes\n  signal    Explore signals within scope\n  value     Get signal values at explicit time point(s)\n  change    List signal changes over a time range\n  property  Evaluate properties over a time range\n  extract   Extract event rows from waveform signals\n\nHelper commands:\n  schema    Print canonical JSON schema contract\n  docs      Browse embedded documentation\n  skill     Print packaged agent skill Markdown\n  help      Show help for the given subcommand(s)\n\nOptions:\n{options}{after-help}"
+    help_template = "{about-with-newline}\nUsage: {usage}\n\nWaveform commands:\n  info      Show waveform metadata\n  scope     Explore hierarchy scopes\n  signal    Explore signals within scope\n  value     Get signal values at explicit time point(s)\n  change    List signal changes over a time range\n  property  Evaluate properties over a time range\n  cdc       Report clock-domain crossing hazards\n  timing    Check data changes against setup/hold windows\n  glitch    Find short pulses and zero-width glitches\n  audit     Report signals still holding x or z bits after reset\n  clock     Measure clock period, duty cycle, gating, and frequency changes\n  view      Draw signals as text waveforms over a time window\n  saif      Write switching activity as a SAIF file\n  extract   Extract event rows from waveform signals\n  export    Write viewer save files or a SQLite database\n\nHelper commands:\n  schema    Print canonical JSON schema contract\n  docs      Browse embedded documentation\n  skill     Print packaged agent skill Markdown\n  help      Show help for the given subcommand(s)\n\nOptions:\n{options}{after-help}"
 )]
 pub struct Cli {
     /// Print semver version
@@ -54,7 +68,7 @@ pub struct Cli {
 #[derive(Debug, Subcommand)]
 enum Command {
     #[command(flatten, next_help_heading = "Waveform commands")]
-    Waveform(WaveformCommand),
+    Waveform(Box<WaveformCommand>),
     #[command(flatten, next_help_heading = "Helper commands")]
     Helper(HelperCommand),
 }
@@ -67,6 +81,8 @@ enum WaveformCommand {
 
 Behavior:
 - Prints available metadata (e.g. time unit, start/end times, etc.) in free form
+- Also reports file format, backend, scope/signal/timestamp counts, and header date/simulator/timescale when recorded
+- `--activity [N]` adds the N nets with the most value changes (default 10), each net listed once
 - `--json` uses the machine contract defined by `wavepeek schema`."#,
         after_long_help = "See also:\n  wavepeek docs show commands/info"
     )]
@@ -76,10 +92,13 @@ Behavior:
         long_about = r#"Provides deterministic hierarchy traversal over scope paths.
 
 Behavior:
-- Finds all scopes matching `--filter` and displays scope name, depth, and kind.
+- Finds all scopes matching `--filter` and displays scope name, depth, kind, and module type when the dump records one.
+- `--module-type` keeps only scopes whose recorded module or definition type matches the regex.
+- `--with-source` adds the HDL declaration and instantiation locations the dump records on each scope.
 - Traversal order is stable: pre-order depth-first, with lexicographic child ordering.
 - Includes stable scope kind aliases from hierarchy data (not only modules); excluded backend-specific spellings are normalized to the stable contract surface.
 - `--tree` switches from flat list to visual hierarchy rendering.
+- `--compare FILE` lists only scopes missing from one dump or whose kind differs; `--remap-prefix A=B` maps a testbench top in this dump onto the other one.
 - Truncation and disabled-limit conditions emit coded diagnostics.
 - `--json` uses the machine contract defined by `wavepeek schema`.
 
@@ -92,9 +111,13 @@ Use this command to explore hierarchy shape before narrowing to signal-level que
         long_about = r#"Provides scope-local signal listings.
 
 Behavior:
-- Finds all signals matching `--filter` within the selected scope and displays name, kind, and available metadata (for example width).
+- Finds all signals matching `--filter` within the selected scope and displays name, kind, and available metadata (for example width and port direction).
 - Default mode lists only direct signals in the selected scope.
 - Recursive mode walks child scopes depth-first in stable lexicographic order; `--max-depth` limits recursion when set.
+- `--global` searches every scope instead of `--scope` and ranks results: whole-name `--filter` matches first, then shallower paths, then lexicographic path order.
+- `--path`, `--kind`, `--width`/`--min-width`/`--max-width`, and `--direction` filter on canonical path, kind, bit width, and recorded port direction.
+- `--aliases-of` lists every path bound to the same waveform data as one signal, such as port-connected copies of a net; `--dedupe-aliases` keeps only the first listed path of each net.
+- `--compare FILE` lists only signals missing from one dump or whose kind or width differs; `--remap-prefix A=B` maps a testbench top in this dump onto the other one.
 - Includes stable signal kind aliases (not only wires); excluded backend-specific VHDL spellings are normalized to the stable contract surface.
 - Truncation and disabled-limit conditions emit coded diagnostics.
 - `--json` uses the machine contract defined by `wavepeek schema`.
@@ -111,7 +134,12 @@ Behavior:
 - By default, signal names are top-related canonical paths (e.g. `top.cpu.state`).
 - For deep hierarchies, set `--scope` once with a canonical scope path and use shorter scope-relative names in `--signals`.
 - Do not mix top-related canonical names and scope-relative names in one request.
+- A `--signals` entry with `*` or `?` is a glob over the scope-relative path (wildcards stop at `.`); an entry starting with `re:` is a regex over the same path. Matches expand in lexicographic order, an info diagnostic lists them, and a pattern that matches nothing is an error.
+- A `--signals` entry naming a struct, union, or array scope samples every field in declaration order; its value is a compact assignment pattern such as `'{hdr:'{len:4'h4},data:8'h5a}` (array elements keyed by index) and JSON adds a nested `fields` object. Only `value` samples whole aggregates; `change` and `property` take individual fields.
+- A `--signals` entry may end in a constant bit or part select (`data[15:8]`, `flags[3]`, `data[8+:8]`, `data[15-:8]`); bits count from 0 at the LSB, and the value is printed at the selected width.
+- `--layout FILE` takes a `signal.layouts` JSON document (see `wavepeek schema --input`); signals it matches keep their raw value and gain decoded fields, printed as `'{addr:8'h12,op:READ}` and emitted as JSON `fields`.
 - `--at` accepts one explicit time token or a comma-separated list in one argument.
+- `--at` entries may be time expressions such as `nth(2, posedge clk)` or `+5ns` (relative to the previous entry); commas inside anchor parentheses do not split entries.
 - Output preserves the input order from `--at` and `--signals`, including duplicates.
 - Human output emits one `@<time>` row per requested time with `display=value` fields, matching `change`.
 - When following up a `change` or `property` JSON row, prefer that row's `sample_time` field for `--at`; in `pre-edge` mode, `time` is the selected trigger timestamp and `sample_time` is where values were sampled.
@@ -134,8 +162,13 @@ Behavior:
 - `--on` is required. Use explicit clock edges such as `--on 'posedge clk'` for RTL-style sampling.
 - Value sampling defaults to pre-edge sampling: displayed values are sampled just before edge-only triggers while row timestamps stay at the trigger edge.
 - Use `--sample-mode native` for raw wildcard or plain-signal triggers such as `--on '*'`.
+- `--layout FILE` lets `--on` and `--eval` reference packed signal fields as `desc.addr`; rows carry no values, so nothing is decoded in the output.
 - JSON and JSONL rows include both `time` (selected event timestamp) and `sample_time` (where values were sampled); text output shows `sample@<time>` only when it differs from `time`.
 - Rows are emitted only when sampled signal values changed from prior sampled state.
+- `--signals` accepts globs (`*`, `?`) and `re:` regexes over scope-relative paths, expanded in lexicographic order with an info diagnostic.
+- With `--scope`, dotted names in `--signals` and `--on` are accepted as struct or union fields, such as `pkt.hdr.len`.
+- `--signals` entries may end in a constant bit or part select such as `data[15:8]` or `data[8+:8]`; a selected entry only triggers a row when its selected bits change.
+- `--layout FILE` decodes matching signals into named fields in each row, and `--on` can reference a layout field as `desc.addr`.
 - Empty-result, truncation, and explicitly disabled-limit conditions emit coded diagnostics.
 - `--json` uses the machine contract defined by `wavepeek schema`.
 
@@ -153,6 +186,7 @@ Behavior:
 - `--on` is required. Use explicit clock edges such as `--on 'posedge clk'` for RTL-style sampling.
 - Value sampling defaults to pre-edge sampling: `--eval` reads values just before edge-only triggers while row timestamps stay at the trigger edge.
 - Use `--sample-mode native` for raw wildcard or plain-signal triggers such as `--on '*'`.
+- `--layout FILE` lets `--on` and `--eval` reference packed signal fields as `desc.addr`; rows carry no values, so nothing is decoded in the output.
 - JSON and JSONL rows include both `time` (selected event timestamp) and `sample_time` (where `--eval` was sampled); text output shows `sample@<time>` only when it differs from `time`.
 - Empty-result, truncation, and explicitly disabled-limit conditions emit coded diagnostics.
 - Remotely similar to a SystemVerilog assert, but without temporal expressions.
@@ -161,6 +195,129 @@ Behavior:
 Use this command to check event-driven property matches and transitions over bounded time windows."#
     )]
     Property(property::PropertyArgs),
+    #[command(
+        about = "Reports clock-domain crossing hazards against a destination clock.",
+        long_about = r#"Reports clock-domain crossing hazards against a destination clock.
+
+Behavior:
+- Checks each `--signals` entry as a signal launched by `--src-clock` and captured by `--dst-clock`; both take event expressions such as `'posedge clk_a'` that name their clock signal.
+- `window` rows report a settled change within `--window` of the nearest destination edge, with the signed `offset` from that edge (negative before it).
+- `multi_bit` rows report a multi-bit signal whose values sampled just before two consecutive destination edges differ in more than one bit, a non-Gray crossing.
+- Rows carry the latest source edge at or before the launching change as `src_edge`, when there is one.
+- Rows are ordered by time, then signal path; `--from`/`--to` bound the reported times.
+- `--signals` accepts globs (`*`, `?`) and `re:` regexes over scope-relative paths, expanded in lexicographic order with an info diagnostic.
+- Empty-result, truncation, and explicitly disabled-limit conditions emit coded diagnostics.
+- `--json` uses the machine contract defined by `wavepeek schema`.
+
+Use this command to find dynamic crossing hazards that only show up in simulation."#
+    )]
+    Cdc(cdc::CdcArgs),
+    #[command(
+        about = "Reports data changes inside setup/hold windows around clock edges.",
+        long_about = r#"Reports data changes inside setup/hold windows around clock edges.
+
+Behavior:
+- Checks every change of each `--signals` entry against the `--clock` edges, similar to Verilog `$setuphold` but run on the dump after simulation.
+- A change at or before an edge and at most `--setup` before it is a `setup` row; a change after an edge and at most `--hold` after it is a `hold` row.
+- Windows are inclusive, so a change exactly `--setup` before or `--hold` after an edge is reported with `slack` `0`; slack is negative by the time the change intrudes into the window.
+- One change inside the windows of two edges produces one row per edge.
+- Every write that changes the value is checked, including zero-delay glitches within one timestamp.
+- `--clock` takes an event expression such as `'posedge clk'` that names its clock signal.
+- Rows are ordered by change time, then signal path; `--from`/`--to` bound the checked changes.
+- `--signals` accepts globs (`*`, `?`) and `re:` regexes over scope-relative paths, expanded in lexicographic order with an info diagnostic.
+- Empty-result, truncation, and explicitly disabled-limit conditions emit coded diagnostics.
+- `--json` uses the machine contract defined by `wavepeek schema`.
+
+Use this command to check timing-annotated gate-level dumps without re-running simulation with timing checks enabled."#
+    )]
+    Timing(timing::TimingArgs),
+    #[command(
+        about = "Finds short pulses and zero-width glitches on selected signals.",
+        long_about = r#"Finds short pulses and zero-width glitches on selected signals.
+
+Behavior:
+- `pulse` rows report a value held for less than `--min-width` between two recorded changes, with its `width`; without `--min-width`, only zero-width glitches are reported.
+- `zero_width` rows report a value that the dump recorded and replaced within one timestamp, such as repeated VCD value changes; their `width` is `0`.
+- Each row shows the value before the glitch, the glitch value, and the value that replaced it.
+- Pulses are measured on whole values, so a multi-bit signal reports a pulse when any combination of bits lasts too briefly.
+- `change` collapses same-timestamp activity into one row by design, so zero-width glitches are visible only here.
+- Rows are ordered by time, then signal path; `--from`/`--to` bound glitch start times.
+- `--signals` accepts globs (`*`, `?`) and `re:` regexes over scope-relative paths, expanded in lexicographic order with an info diagnostic.
+- Empty-result, truncation, and explicitly disabled-limit conditions emit coded diagnostics.
+- `--json` uses the machine contract defined by `wavepeek schema`.
+
+Use this command to find the hazards that break gate-level and asynchronous-reset designs."#
+    )]
+    Glitch(glitch::GlitchArgs),
+    #[command(
+        about = "Reports signals still holding x or z bits after reset.",
+        long_about = r#"Reports signals still holding x or z bits after reset.
+
+Behavior:
+- The first `--reset` event in the selected time range marks reset deassertion, for example `'posedge rst_n'`.
+- Signals are sampled at the `--cycles`-th `--clock` event after that point, including updates made at that timestamp; `--cycles 0` samples at the reset event itself.
+- Every bit-vector signal under `--scope` and its nested scopes is sampled; rows report signals with any bit that is not `0` or `1`, and signals with no value at all.
+- Rows are grouped by scope in lexicographic order, then ordered by signal path; JSON rows stay flat and carry the group in their `scope` field.
+- Rows are grouped by scope in lexicographic order, then ordered by signal path.
+- `--reset` and `--clock` names are scope-relative; `*` is rejected because it names no signal.
+- An info diagnostic reports the sample time; empty-result, truncation, and explicitly disabled-limit conditions emit coded diagnostics.
+- `--json` uses the machine contract defined by `wavepeek schema`.
+
+Use this command to list uninitialized flops and undriven nets that simulation tolerates but silicon does not."#
+    )]
+    Audit(audit::AuditArgs),
+    #[command(
+        about = "Measures clock period, duty cycle, gating, and frequency changes.",
+        long_about = r#"Measures clock period, duty cycle, gating, and frequency changes.
+
+Behavior:
+- `--signal` names one 1-bit clock; rising edges settle at `1` and falling edges settle at `0`, from any other value.
+- Periods run from one rising edge to the next; `nominal` is their median, and `min`, `max`, and `mean` cover every period outside gated segments.
+- `frequency` is the frequency of the nominal period; `duty_cycle` percentages measure high time over periods that contain a falling edge.
+- A gated segment is a gap with no rising edge for more than `--gate-periods` periods; it covers the stretch held after the last normal period, low from the falling edge or high from the rising edge, reports that level, and has no end when the clock stays stopped through `--to`.
+- A frequency change is reported at the rising edge that starts a period differing from the current segment period by more than `--tolerance` percent and the median of it and the next two periods differs too; that median is reported as the new period and later periods are judged against it.
+- A segment period is the median of its first three periods, so one gated or jittery period does not become the reference; gaps are judged against it, so a slower clock after a frequency change is not reported as gated.
+- `--max` bounds gated segments and frequency changes separately; fewer than two usable rising edges, truncation, and explicitly disabled limits emit coded diagnostics.
+- `--json` uses the machine contract defined by `wavepeek schema`.
+
+Use this command to check clock switching, DVFS steps, and clock gating, which `change --signals clk` shows only as raw edges."#
+    )]
+    Clock(clock::ClockArgs),
+    #[command(
+        about = "Draws selected signals as text waveforms over a time window.",
+        long_about = r#"Draws selected signals as text waveforms over a time window.
+
+Behavior:
+- Samples each requested signal at evenly spaced time points across the inclusive `--from`/`--to` window, one point per drawing column.
+- 1-bit signals are drawn as low/high traces with rising and falling edges; wider signals are drawn as `<value>` segments in hex.
+- Unknown (`x`) values are shaded and high-impedance (`z`) values are drawn as a mid-level line.
+- A time ruler in the dump `time_unit` is printed above the traces.
+- Total line width is bounded by `--width`, which defaults to `$COLUMNS` (or 80 when unset).
+- `--ascii` switches to plain ASCII glyphs for terminals without Unicode support.
+- `--signals` accepts globs (`*`, `?`) and `re:` regexes over scope-relative paths, expanded in lexicographic order with an info diagnostic.
+- Pulses narrower than one column may be hidden; use `change` for exact transition rows.
+- Output is human-only; there is no `--json` mode.
+
+Use this command for a quick visual check of signal activity, for example over SSH in CI containers."#
+    )]
+    View(view::ViewArgs),
+    #[command(
+        about = "Writes per-net switching activity as a SAIF 2.0 file.",
+        long_about = r#"Writes per-net switching activity as a SAIF 2.0 file.
+
+Behavior:
+- Computes per-bit `T0`, `T1`, `TX`, `TZ`, `TC`, and `IG` values over the `--from`/`--to` window; omitted bounds use the dump bounds.
+- Durations are counted in the dump `time_unit`, which is written as the SAIF `TIMESCALE`.
+- `TC` counts settled `0`/`1` transitions only; transitions into or out of `x` or `z` are not toggles.
+- `IG` counts zero-width pulses, which a dump records only when several values are written at one timestamp; it is always `0` for FSDB input.
+- Writes the dump hierarchy as nested `INSTANCE` blocks; `--scope` limits output to one subtree.
+- Vector nets are split into bits named after the declared index range, for example `data\[7\]`.
+- Non-bit-vector signals such as reals, strings, and events are skipped.
+- Without `--out`, the SAIF file is printed to stdout.
+
+Use this command to derive power-analysis activity from an existing dump instead of re-running simulation."#
+    )]
+    Saif(saif::SaifArgs),
     #[command(
         subcommand,
         about = "Extract row-oriented waveform data.",
@@ -169,6 +326,14 @@ Use this command to check event-driven property matches and transitions over bou
 Use nested extractors for protocol-neutral or protocol-specific event rows. The generic extractor selects edge events, evaluates a predicate at the pre-edge sample point, and emits ordered payload values."#
     )]
     Extract(extract::ExtractCommand),
+    #[command(
+        subcommand,
+        about = "Write waveform viewer save files or a SQLite database.",
+        long_about = r#"Write waveform viewer save files or a SQLite database.
+
+Use nested exporters to open a dump in GTKWave or Surfer with the requested signals, grouping, radix, cursor, and markers already set up, or to write hierarchy and value changes to a SQLite database for ad-hoc SQL."#
+    )]
+    Export(export::ExportCommand),
 }
 
 #[derive(Debug, Subcommand)]
@@ -232,7 +397,7 @@ pub fn run() -> Result<(), WavepeekError> {
     let argv: Vec<_> = std::env::args_os().collect();
     let parse_argv = if argv.len() == 1 {
         vec![argv[0].clone(), "-h".into()]
-    } else if argv.len() == 2 && matches!(argv[1].to_str(), Some("docs" | "extract")) {
+    } else if argv.len() == 2 && matches!(argv[1].to_str(), Some("docs" | "extract" | "export")) {
         vec![argv[0].clone(), argv[1].clone(), "-h".into()]
     } else {
         argv
@@ -297,7 +462,10 @@ fn build_cli_command() -> clap::Command {
     if let Some(help) = command.find_subcommand_mut("help") {
         *help = help.clone().about("Show help for the given subcommand(s)");
     }
-    for command_name in ["info", "scope", "signal", "value", "change", "property"] {
+    for command_name in [
+        "info", "scope", "signal", "value", "change", "property", "cdc", "timing", "glitch",
+        "audit", "clock", "view", "saif",
+    ] {
         if let Some(subcommand) = command.find_subcommand_mut(command_name) {
             *subcommand = with_other_help_options(subcommand.clone());
         }
@@ -316,6 +484,13 @@ fn build_cli_command() -> clap::Command {
             *generic = with_other_help_options(generic.clone());
         }
     }
+    if let Some(export) = command.find_subcommand_mut("export") {
+        for exporter in ["gtkw", "surfer", "sqlite"] {
+            if let Some(subcommand) = export.find_subcommand_mut(exporter) {
+                *subcommand = with_other_help_options(subcommand.clone());
+            }
+        }
+    }
     command
 }
 
@@ -452,6 +627,10 @@ fn dispatch(command: Command) -> Result<(), WavepeekError> {
         let mut writer = JsonlWriter::new(stdout.lock(), engine_command.name());
         return engine::run_jsonl(engine_command, &mut writer);
     }
+    if engine_command.html_path().is_some() {
+        let (result, report) = engine::report::run(engine_command)?;
+        return output::write_html(result, &report);
+    }
 
     let result = engine::run(engine_command)?;
     output::write(result)
@@ -459,13 +638,20 @@ fn dispatch(command: Command) -> Result<(), WavepeekError> {
 
 fn into_engine_command(command: Command) -> EngineCommand {
     match command {
-        Command::Waveform(command) => match command {
+        Command::Waveform(command) => match *command {
             WaveformCommand::Info(args) => EngineCommand::Info(args),
             WaveformCommand::Scope(args) => EngineCommand::Scope(args),
             WaveformCommand::Signal(args) => EngineCommand::Signal(args),
             WaveformCommand::Value(args) => EngineCommand::Value(args),
             WaveformCommand::Change(args) => EngineCommand::Change(args),
             WaveformCommand::Property(args) => EngineCommand::Property(args),
+            WaveformCommand::Cdc(args) => EngineCommand::Cdc(args),
+            WaveformCommand::Timing(args) => EngineCommand::Timing(args),
+            WaveformCommand::Glitch(args) => EngineCommand::Glitch(args),
+            WaveformCommand::Audit(args) => EngineCommand::Audit(args),
+            WaveformCommand::Clock(args) => EngineCommand::Clock(args),
+            WaveformCommand::View(args) => EngineCommand::View(args),
+            WaveformCommand::Saif(args) => EngineCommand::Saif(args),
             WaveformCommand::Extract(command) => match command {
                 extract::ExtractCommand::Ahb(args) => EngineCommand::ExtractAhb(*args),
                 extract::ExtractCommand::Apb(args) => EngineCommand::ExtractApb(*args),
@@ -474,6 +660,11 @@ fn into_engine_command(command: Command) -> EngineCommand {
                 extract::ExtractCommand::AxiStream(args) => EngineCommand::ExtractAxiStream(*args),
                 extract::ExtractCommand::Generic(args) => EngineCommand::ExtractGeneric(*args),
             },
+            WaveformCommand::Export(command) => match command {
+                export::ExportCommand::Gtkw(args) => EngineCommand::ExportGtkw(args),
+                export::ExportCommand::Surfer(args) => EngineCommand::ExportSurfer(args),
+                export::ExportCommand::Sqlite(args) => EngineCommand::ExportSqlite(args),
+            },
         },
         Command::Helper(command) => match command {
             HelperCommand::Schema(args) => EngineCommand::Schema(args),
@@ -596,7 +787,7 @@ mod tests {
         match command {
             EngineCommand::Signal(args) => {
                 assert_eq!(args.waves, PathBuf::from("fixtures/sample.vcd"));
-                assert_eq!(args.scope, "top.cpu");
+                assert_eq!(args.scope.as_deref(), Some("top.cpu"));
                 assert!(args.recursive);
                 assert_eq!(args.max_depth, LimitArg::Numeric(3));
                 assert_eq!(args.max, LimitArg::Numeric(7));
@@ -4,6 +4,7 @@ use clap::{Args, ValueEnum};
 
 use crate::cli::limits::LimitArg;
 use crate::cli::sampling::SampleMode;
+use crate::cli::time_unit::OutputTimeUnit;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
 #[value(rename_all = "kebab-case")]
@@ -29,13 +30,19 @@ pub struct PropertyArgs {
     /// Canonical scope path for scope-relative signal and event names
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
+    /// JSON layout file whose packed signal fields `--on` and `--eval` can reference as `signal.field`
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub layout: Option<PathBuf>,
     /// Event trigger expression (required; use `*` only with `--sample-mode native`)
     #[arg(long, required = true, help_heading = "Selection options")]
     pub on: String,
-    /// Value sampling mode for event-selected rows
+    /// Value sampling mode for event-selected rows: native, pre-edge, post-edge, or skew:<time>
     #[arg(
         long,
-        value_enum,
+        value_parser = SampleMode::parse,
         default_value_t = SampleMode::PreEdge,
         value_name = "MODE",
         help_heading = "Selection options"
@@ -62,4 +69,24 @@ pub struct PropertyArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Write a self-contained HTML report with waveform strips to FILE
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["json", "jsonl"],
+        help_heading = "Output options"
+    )]
+    pub html: Option<PathBuf>,
 }
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct SaifArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Start of the activity window (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of the activity window (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+    /// Canonical scope path whose subtree is written (omitted means the whole dump)
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: Option<String>,
+    /// Output file path (prints to stdout when omitted)
+    #[arg(long, value_name = "FILE", help_heading = "Output options")]
+    pub out: Option<PathBuf>,
+}
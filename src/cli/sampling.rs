@@ -1,9 +1,39 @@
-use clap::ValueEnum;
+use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
-#[value(rename_all = "kebab-case")]
+/// Where event-selected rows sample values relative to their trigger timestamp.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum SampleMode {
     Native,
     #[default]
     PreEdge,
+    PostEdge,
+    /// Clocking-block style input skew, kept as a time token until the dump precision is known.
+    Skew(String),
+}
+
+impl SampleMode {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "native" => Ok(Self::Native),
+            "pre-edge" => Ok(Self::PreEdge),
+            "post-edge" => Ok(Self::PostEdge),
+            _ => match value.strip_prefix("skew:") {
+                Some(skew) if !skew.is_empty() => Ok(Self::Skew(skew.to_string())),
+                _ => Err(format!(
+                    "invalid sample mode '{value}': expected native, pre-edge, post-edge, or skew:<time>"
+                )),
+            },
+        }
+    }
+}
+
+impl fmt::Display for SampleMode {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Native => formatter.write_str("native"),
+            Self::PreEdge => formatter.write_str("pre-edge"),
+            Self::PostEdge => formatter.write_str("post-edge"),
+            Self::Skew(skew) => write!(formatter, "skew:{skew}"),
+        }
+    }
 }
@@ -9,18 +9,40 @@ pub struct ScopeArgs {
     /// Path to VCD/FST/FSDB waveform file
     #[arg(long, value_name = "FILE", help_heading = "Input options")]
     pub waves: PathBuf,
+    /// Second dump to compare against; lists only scopes that differ
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["tree", "with_source"],
+        help_heading = "Input options"
+    )]
+    pub compare: Option<PathBuf>,
+    /// Map a dump path prefix onto the --compare dump (`tb_a.dut=tb_b.dut`, repeatable)
+    #[arg(
+        long,
+        value_name = "A=B",
+        requires = "compare",
+        help_heading = "Input options"
+    )]
+    pub remap_prefix: Vec<String>,
     /// Maximum traversal depth (`unlimited` disables depth truncation)
     #[arg(long, default_value = "5", help_heading = "Selection options")]
     pub max_depth: LimitArg,
     /// Regex filter for full scope path
     #[arg(long, default_value = ".*", help_heading = "Selection options")]
     pub filter: String,
+    /// Regex filter for the module or definition type name (`fifo_.*`)
+    #[arg(long, value_name = "REGEX", help_heading = "Selection options")]
+    pub module_type: Option<String>,
     /// Maximum number of entries (`unlimited` disables truncation, value must be > 0)
     #[arg(long, default_value = "50", help_heading = "Output options")]
     pub max: LimitArg,
     /// Render hierarchy as an indented tree
     #[arg(long, help_heading = "Output options")]
     pub tree: bool,
+    /// Include the HDL declaration and instantiation locations recorded in the dump
+    #[arg(long, help_heading = "Output options")]
+    pub with_source: bool,
     /// Machine-readable JSON output
     #[arg(long, help_heading = "Output options")]
     pub json: bool,
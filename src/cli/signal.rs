@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 
 use crate::cli::limits::LimitArg;
 
@@ -10,17 +10,87 @@ pub struct SignalArgs {
     #[arg(long, value_name = "FILE", help_heading = "Input options")]
     pub waves: PathBuf,
     /// Exact scope path (e.g. top.cpu)
-    #[arg(long, help_heading = "Input options")]
-    pub scope: String,
+    #[arg(
+        long,
+        required_unless_present_any = ["global", "aliases_of"],
+        help_heading = "Input options"
+    )]
+    pub scope: Option<String>,
+    /// Search every scope in the dump instead of one --scope; results are ranked
+    #[arg(
+        long,
+        conflicts_with_all = ["scope", "recursive"],
+        help_heading = "Input options"
+    )]
+    pub global: bool,
+    /// List every path bound to the same waveform data as this signal (e.g. top.cpu.clk)
+    #[arg(
+        long,
+        value_name = "SIGNAL",
+        conflicts_with_all = ["scope", "global", "recursive"],
+        help_heading = "Input options"
+    )]
+    pub aliases_of: Option<String>,
+    /// Second dump to compare against; lists only signals that differ
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["aliases_of", "dedupe_aliases"],
+        help_heading = "Input options"
+    )]
+    pub compare: Option<PathBuf>,
+    /// Map a dump path prefix onto the --compare dump (`tb_a.dut=tb_b.dut`, repeatable)
+    #[arg(
+        long,
+        value_name = "A=B",
+        requires = "compare",
+        help_heading = "Input options"
+    )]
+    pub remap_prefix: Vec<String>,
     /// Maximum number of entries (`unlimited` disables truncation, value must be > 0)
     #[arg(long, default_value = "50", help_heading = "Output options")]
     pub max: LimitArg,
     /// Regex filter for signal name
     #[arg(long, default_value = ".*", help_heading = "Selection options")]
     pub filter: String,
+    /// Regex filter for canonical signal path
+    #[arg(long, value_name = "REGEX", help_heading = "Selection options")]
+    pub path: Option<String>,
+    /// Comma-separated signal kinds to keep (e.g. wire,reg)
+    #[arg(long, value_delimiter = ',', num_args = 1.., help_heading = "Selection options")]
+    pub kind: Vec<String>,
+    /// Keep only signals of exactly this bit width
+    #[arg(
+        long,
+        conflicts_with_all = ["min_width", "max_width"],
+        help_heading = "Selection options"
+    )]
+    pub width: Option<u32>,
+    /// Keep only signals at least this many bits wide
+    #[arg(long, help_heading = "Selection options")]
+    pub min_width: Option<u32>,
+    /// Keep only signals at most this many bits wide
+    #[arg(long, help_heading = "Selection options")]
+    pub max_width: Option<u32>,
+    /// Comma-separated port directions to keep
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        num_args = 1..,
+        help_heading = "Selection options"
+    )]
+    pub direction: Vec<PortDirection>,
     /// Recursively include nested child scopes
     #[arg(long, help_heading = "Selection options")]
     pub recursive: bool,
+    /// Keep only the first listed path of each net whose aliases share waveform data
+    #[arg(
+        long,
+        conflicts_with = "aliases_of",
+        help_heading = "Selection options"
+    )]
+    pub dedupe_aliases: bool,
     /// Maximum recursion depth below --scope (`unlimited` disables this limit)
     #[arg(
         long,
@@ -39,3 +109,25 @@ pub struct SignalArgs {
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum PortDirection {
+    Input,
+    Output,
+    Inout,
+    Buffer,
+    Linkage,
+}
+
+impl PortDirection {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Output => "output",
+            Self::Inout => "inout",
+            Self::Buffer => "buffer",
+            Self::Linkage => "linkage",
+        }
+    }
+}
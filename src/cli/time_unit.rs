@@ -0,0 +1,17 @@
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+#[value(rename_all = "lowercase")]
+pub enum OutputTimeUnit {
+    #[default]
+    Dump,
+    Auto,
+    Zs,
+    As,
+    Fs,
+    Ps,
+    Ns,
+    Us,
+    Ms,
+    S,
+}
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::cli::limits::LimitArg;
+use crate::cli::time_unit::OutputTimeUnit;
+
+#[derive(Debug, Args)]
+pub struct TimingArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Start of inclusive time range (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of inclusive time range (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+    /// Canonical scope path for scope-relative signal and clock names
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: Option<String>,
+    /// Reference clock edge event the data signals are checked against; also numbers `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: String,
+    /// Comma-separated data signal paths, or scope-relative names when --scope is set
+    #[arg(
+        long,
+        value_delimiter = ',',
+        num_args = 1..,
+        required = true,
+        help_heading = "Selection options"
+    )]
+    pub signals: Vec<String>,
+    /// Setup limit: data must be stable this long before each clock edge (e.g. 200ps)
+    #[arg(long, value_name = "TIME", help_heading = "Selection options")]
+    pub setup: String,
+    /// Hold limit: data must stay stable this long after each clock edge (e.g. 100ps)
+    #[arg(long, value_name = "TIME", help_heading = "Selection options")]
+    pub hold: String,
+    /// Maximum number of violation rows (`unlimited` disables truncation, value must be > 0)
+    #[arg(long, default_value = "50", help_heading = "Output options")]
+    pub max: LimitArg,
+    /// Machine-readable JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub json: bool,
+    /// Stream newline-delimited JSON output
+    #[arg(long, conflicts_with = "json", help_heading = "Output options")]
+    pub jsonl: bool,
+    /// Render times and durations in UNIT (dump keeps the dump time unit; auto picks per value), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
+    /// Show canonical signal paths
+    #[arg(long, help_heading = "Output options")]
+    pub abs: bool,
+}
@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use clap::Args;
 
+use crate::cli::time_unit::OutputTimeUnit;
+
 #[derive(Debug, Args)]
 pub struct ValueArgs {
     /// Path to VCD/FST/FSDB waveform file
@@ -13,6 +15,9 @@ pub struct ValueArgs {
     /// Canonical scope path for scope-relative signal names
     #[arg(long, help_heading = "Selection options")]
     pub scope: Option<String>,
+    /// Reference clock event for `cyc` time units and row `cycle` fields (e.g. 'posedge clk')
+    #[arg(long, value_name = "EVENT", help_heading = "Selection options")]
+    pub clock: Option<String>,
     /// Comma-separated top-related signal paths, or scope-relative names when --scope is set
     #[arg(
         long,
@@ -22,6 +27,9 @@ pub struct ValueArgs {
         help_heading = "Selection options"
     )]
     pub signals: Vec<String>,
+    /// JSON layout file that decodes packed signals into named fields
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub layout: Option<PathBuf>,
     /// Show canonical signal paths
     #[arg(long, help_heading = "Output options")]
     pub abs: bool,
@@ -31,4 +39,16 @@ pub struct ValueArgs {
     /// Stream newline-delimited JSON output
     #[arg(long, conflicts_with = "json", help_heading = "Output options")]
     pub jsonl: bool,
+    /// Render row times in UNIT (dump keeps the dump time unit; auto picks per row), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Add each row's raw dump tick as an integer `tick` field in JSON output
+    #[arg(long, help_heading = "Output options")]
+    pub ticks: bool,
 }
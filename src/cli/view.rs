@@ -0,0 +1,48 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::cli::time_unit::OutputTimeUnit;
+
+#[derive(Debug, Args)]
+pub struct ViewArgs {
+    /// Path to VCD/FST/FSDB waveform file
+    #[arg(long, value_name = "FILE", help_heading = "Input options")]
+    pub waves: PathBuf,
+    /// Start of inclusive time window (e.g. 1234ns; omitted means dump start)
+    #[arg(long, help_heading = "Selection options")]
+    pub from: Option<String>,
+    /// End of inclusive time window (e.g. 1234ns; omitted means dump end)
+    #[arg(long, help_heading = "Selection options")]
+    pub to: Option<String>,
+    /// Canonical scope path for scope-relative signal names
+    #[arg(long, help_heading = "Selection options")]
+    pub scope: Option<String>,
+    /// Comma-separated top-related signal paths, or scope-relative names when --scope is set
+    #[arg(
+        long,
+        value_delimiter = ',',
+        num_args = 1..,
+        required = true,
+        help_heading = "Selection options"
+    )]
+    pub signals: Vec<String>,
+    /// Total drawing width in terminal columns (defaults to $COLUMNS, then 80)
+    #[arg(long, value_name = "COLS", help_heading = "Output options")]
+    pub width: Option<usize>,
+    /// Draw traces with plain ASCII glyphs instead of Unicode
+    #[arg(long, help_heading = "Output options")]
+    pub ascii: bool,
+    /// Render ruler times in UNIT (dump keeps the dump time unit; auto picks per label), with exact decimal fractions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputTimeUnit::Dump,
+        value_name = "UNIT",
+        help_heading = "Output options"
+    )]
+    pub time_unit: OutputTimeUnit,
+    /// Show canonical signal paths
+    #[arg(long, help_heading = "Output options")]
+    pub abs: bool,
+}
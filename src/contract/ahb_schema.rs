@@ -317,6 +317,11 @@ fn common_event_properties(profile: &AhbProfileSpec, kind: &str) -> Map<String,
             "time".to_string(),
             json!({"$ref": "#/$defs/normalizedTime"}),
         ),
+        ("tick".to_string(), json!({"type": "integer", "minimum": 0})),
+        (
+            "cycle".to_string(),
+            json!({"type": "integer", "minimum": 0}),
+        ),
         (
             "sample_time".to_string(),
             json!({"$ref": "#/$defs/normalizedTime"}),
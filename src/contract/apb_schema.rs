@@ -328,6 +328,16 @@ fn apb_profile_event_schema(profile: &ApbProfileSpec, event: &str, direction: &s
                 "$ref": "#/$defs/normalizedTime",
                 "description": "Selected APB event timestamp."
             },
+            "tick": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Raw dump tick of time; present with --ticks."
+            },
+            "cycle": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+            },
             "sample_time": {
                 "$ref": "#/$defs/normalizedTime",
                 "description": "Pre-edge timestamp used to classify and sample the APB event."
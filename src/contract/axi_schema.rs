@@ -241,6 +241,16 @@ fn axi_channel_transfer_schema(profile: &AxiProfileSpec, channel: &AxiChannelSpe
                 "$ref": "#/$defs/normalizedTime",
                 "description": "Selected AXI transfer event timestamp."
             },
+            "tick": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Raw dump tick of time; present with --ticks."
+            },
+            "cycle": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+            },
             "sample_time": {
                 "$ref": "#/$defs/normalizedTime",
                 "description": "Pre-edge timestamp used to evaluate ready/valid and sample payload values."
@@ -98,7 +98,7 @@ impl JsonSchema for NormalizedTime<'_> {
     fn json_schema(_: &mut SchemaGenerator) -> Schema {
         json_schema!({
             "type": "string",
-            "description": "Normalized timestamp rendered in the dump's time unit, for example 10ns."
+            "description": "Normalized timestamp rendered in the dump's time unit, for example 10ns, or in the --time-unit unit with an exact decimal fraction, for example 124.999ns."
         })
     }
 }
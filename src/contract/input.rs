@@ -203,6 +203,59 @@ pub struct ExtractAxiStreamSourceInput<'a> {
     maps: BTreeMap<&'a str, &'a str>,
 }
 
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "signalLayoutsInput")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct SignalLayoutsInput<'a> {
+    #[serde(rename = "$schema")]
+    #[schemars(schema_with = "input_schema_url_schema")]
+    #[schemars(description = "Input schema URL for this layout document.")]
+    schema: &'a str,
+    #[schemars(schema_with = "signal_layouts_kind_schema")]
+    #[schemars(description = "Input document kind discriminator.")]
+    kind: &'a str,
+    #[schemars(schema_with = "layouts_schema")]
+    #[schemars(
+        description = "Ordered layouts; the first entry whose signal matches a canonical path applies."
+    )]
+    layouts: Vec<SignalLayout<'a>>,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "signalLayout")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct SignalLayout<'a> {
+    #[schemars(
+        description = "Canonical signal path, glob over canonical paths, or `re:` regex selecting the packed signals this layout decodes."
+    )]
+    signal: &'a str,
+    #[schemars(schema_with = "layout_fields_schema")]
+    #[schemars(description = "Named bit ranges of the packed signal.")]
+    fields: Vec<SignalLayoutField<'a>>,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "signalLayoutField")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct SignalLayoutField<'a> {
+    #[schemars(schema_with = "layout_field_name_schema")]
+    #[schemars(description = "Field name used in decoded output and `signal.field` expressions.")]
+    name: &'a str,
+    #[schemars(schema_with = "bit_index_schema")]
+    #[schemars(description = "Most significant bit of the field, counting from 0 at the LSB.")]
+    msb: u32,
+    #[schemars(schema_with = "bit_index_schema")]
+    #[schemars(description = "Least significant bit of the field, counting from 0 at the LSB.")]
+    lsb: u32,
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "layout_enum_schema")]
+    #[schemars(
+        description = "Labels keyed by field value in decimal or 0x-prefixed hex; matching values render as the label."
+    )]
+    labels: Option<BTreeMap<&'a str, &'a str>>,
+}
+
 #[derive(Debug, JsonSchema, Serialize)]
 #[schemars(rename = "extractGenericSource")]
 #[schemars(extend("additionalProperties" = true))]
@@ -246,6 +299,10 @@ fn extract_axistream_kind_schema(_: &mut SchemaGenerator) -> Schema {
     json_schema!({"type": "string", "const": "extract.axistream.source"})
 }
 
+fn signal_layouts_kind_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({"type": "string", "const": "signal.layouts"})
+}
+
 fn apb_profile_schema(_: &mut SchemaGenerator) -> Schema {
     json_schema!({"type": "string", "enum": ["apb3", "apb4", "apb5"]})
 }
@@ -314,3 +371,38 @@ fn maps_schema(_: &mut SchemaGenerator) -> Schema {
         "additionalProperties": {"type": "string"}
     })
 }
+
+fn layouts_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "array",
+        "minItems": 1,
+        "items": {"$ref": "#/$defs/signalLayout"}
+    })
+}
+
+fn layout_fields_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "array",
+        "minItems": 1,
+        "items": {"$ref": "#/$defs/signalLayoutField"}
+    })
+}
+
+fn layout_field_name_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "string",
+        "pattern": "^[A-Za-z_][A-Za-z0-9_$]*$"
+    })
+}
+
+fn bit_index_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({"type": "integer", "minimum": 0})
+}
+
+fn layout_enum_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "object",
+        "propertyNames": {"pattern": "^(0|[1-9][0-9]*|0[xX][0-9A-Fa-f]+)$"},
+        "additionalProperties": {"type": "string", "minLength": 1}
+    })
+}
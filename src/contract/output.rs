@@ -53,6 +53,11 @@ pub enum OutputData<'a> {
     Value(Vec<ValueSnapshot<'a>>),
     Change(Vec<ChangeSnapshot<'a>>),
     Property(Vec<PropertyRow<'a>>),
+    Cdc(Vec<CdcHazard<'a>>),
+    Timing(Vec<TimingViolation<'a>>),
+    Glitch(Vec<GlitchRow<'a>>),
+    Audit(Vec<AuditRow<'a>>),
+    Clock(ClockData<'a>),
     ExtractAhb(ExtractAhbData<'a>),
     ExtractApb(ExtractApbData<'a>),
     ExtractAtb(ExtractAtbData<'a>),
@@ -89,6 +94,21 @@ impl<'a> OutputData<'a> {
             (CommandName::Property, CommandData::Property(rows)) => {
                 Ok(Self::Property(rows.iter().map(PropertyRow::from).collect()))
             }
+            (CommandName::Cdc, CommandData::Cdc(rows)) => {
+                Ok(Self::Cdc(rows.iter().map(CdcHazard::from).collect()))
+            }
+            (CommandName::Timing, CommandData::Timing(rows)) => Ok(Self::Timing(
+                rows.iter().map(TimingViolation::from).collect(),
+            )),
+            (CommandName::Glitch, CommandData::Glitch(rows)) => {
+                Ok(Self::Glitch(rows.iter().map(GlitchRow::from).collect()))
+            }
+            (CommandName::Audit, CommandData::Audit(rows)) => {
+                Ok(Self::Audit(rows.iter().map(AuditRow::from).collect()))
+            }
+            (CommandName::Clock, CommandData::Clock(data)) => {
+                Ok(Self::Clock(ClockData::from(data)))
+            }
             (CommandName::ExtractAhb, CommandData::ExtractAhb(data)) => {
                 Ok(Self::ExtractAhb(ExtractAhbData::from(data)))
             }
@@ -133,6 +153,56 @@ pub struct InfoData<'a> {
     time_start: NormalizedTime<'a>,
     #[schemars(description = "Latest timestamp present in the waveform.")]
     time_end: NormalizedTime<'a>,
+    #[schemars(description = "Detected waveform file format, for example vcd, fst, or fsdb.")]
+    format: &'a str,
+    #[schemars(description = "Waveform reader backend that opened the file.")]
+    backend: &'a str,
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Number of scopes in the dump hierarchy.")]
+    scope_count: usize,
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Number of signal paths in the dump hierarchy, counting every alias path."
+    )]
+    signal_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Number of distinct timestamps with recorded changes when the backend indexes them."
+    )]
+    timestamp_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(description = "Dump creation date exactly as recorded in the header.")]
+    date: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(description = "Simulator name and version exactly as recorded in the header.")]
+    simulator: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Timescale exactly as declared in the header, such as the VCD $timescale text."
+    )]
+    timescale: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Most active nets by value-change count, present only with --activity."
+    )]
+    activity: Option<Vec<InfoActivity<'a>>>,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "infoActivity")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct InfoActivity<'a> {
+    #[schemars(description = "Canonical path listed for this net; alias paths are not repeated.")]
+    path: CanonicalPath<'a>,
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Settled value changes over the whole dump.")]
+    changes: u64,
 }
 
 impl<'a> From<&'a crate::engine::info::InfoData> for InfoData<'a> {
@@ -141,6 +211,23 @@ impl<'a> From<&'a crate::engine::info::InfoData> for InfoData<'a> {
             time_unit: data.time_unit.as_str(),
             time_start: NormalizedTime::new(data.time_start.as_str()),
             time_end: NormalizedTime::new(data.time_end.as_str()),
+            format: data.format.as_str(),
+            backend: data.backend.as_str(),
+            scope_count: data.scope_count,
+            signal_count: data.signal_count,
+            timestamp_count: data.timestamp_count,
+            date: data.date.as_deref(),
+            simulator: data.simulator.as_deref(),
+            timescale: data.timescale.as_deref(),
+            activity: data.activity.as_ref().map(|activity| {
+                activity
+                    .iter()
+                    .map(|entry| InfoActivity {
+                        path: CanonicalPath::new(entry.path.as_str()),
+                        changes: entry.changes,
+                    })
+                    .collect()
+            }),
         }
     }
 }
@@ -158,6 +245,28 @@ pub struct ScopeEntry<'a> {
     depth: usize,
     #[schemars(description = "Stable scope kind alias for this scope entry.")]
     kind: ScopeKind<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Module or definition type name of this scope when the dump records one."
+    )]
+    module: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "HDL declaration location recorded in the dump, present only with --with-source."
+    )]
+    source: Option<SourceLocation<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "HDL location where the parent instantiates this scope, present only with --with-source."
+    )]
+    instantiation: Option<SourceLocation<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(description = "How this scope differs from the --compare dump.")]
+    compare: Option<CompareInfo<'a>>,
 }
 
 impl<'a> TryFrom<&'a crate::engine::scope::ScopeEntry> for ScopeEntry<'a> {
@@ -168,6 +277,10 @@ impl<'a> TryFrom<&'a crate::engine::scope::ScopeEntry> for ScopeEntry<'a> {
             path: CanonicalPath::new(entry.path.as_str()),
             depth: entry.depth,
             kind: validate_scope_kind(entry.kind.as_str())?,
+            module: entry.module.as_deref(),
+            source: entry.source.as_ref().map(SourceLocation::from),
+            instantiation: entry.instantiation.as_ref().map(SourceLocation::from),
+            compare: entry.compare.as_ref().map(CompareInfo::from),
         })
     }
 }
@@ -187,6 +300,35 @@ pub struct SignalEntry<'a> {
     #[schemars(schema_with = "signal_width_schema")]
     #[schemars(description = "Declared packed bit width when the waveform backend reports one.")]
     width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "port_direction_schema")]
+    #[schemars(description = "Port direction when the dump records the signal as a port.")]
+    direction: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(description = "How this signal differs from the --compare dump.")]
+    compare: Option<CompareInfo<'a>>,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "sourceLocation")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct SourceLocation<'a> {
+    #[schemars(description = "Source file path exactly as recorded in the dump.")]
+    file: &'a str,
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Line number recorded in the dump.")]
+    line: u64,
+}
+
+impl<'a> From<&'a crate::waveform::SourceLocation> for SourceLocation<'a> {
+    fn from(source: &'a crate::waveform::SourceLocation) -> Self {
+        Self {
+            file: source.file.as_str(),
+            line: source.line,
+        }
+    }
 }
 
 impl<'a> TryFrom<&'a crate::engine::signal::SignalEntry> for SignalEntry<'a> {
@@ -198,14 +340,73 @@ impl<'a> TryFrom<&'a crate::engine::signal::SignalEntry> for SignalEntry<'a> {
             path: CanonicalPath::new(entry.path.as_str()),
             kind: validate_signal_kind(entry.kind.as_str())?,
             width: entry.width,
+            direction: entry.direction.as_deref(),
+            compare: entry.compare.as_ref().map(CompareInfo::from),
         })
     }
 }
 
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "compareInfo")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct CompareInfo<'a> {
+    #[schemars(
+        description = "`only-a` and `only-b` entries exist in one dump only; `changed` entries differ in kind or width."
+    )]
+    status: CompareStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(description = "Kind recorded in the --compare dump, present for changed entries.")]
+    kind: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "signal_width_schema")]
+    #[schemars(
+        description = "Width recorded in the --compare dump, present for changed entries that have one."
+    )]
+    width: Option<u32>,
+}
+
+impl<'a> From<&'a crate::engine::hierarchy_diff::CompareInfo> for CompareInfo<'a> {
+    fn from(info: &'a crate::engine::hierarchy_diff::CompareInfo) -> Self {
+        Self {
+            status: info.status.into(),
+            kind: info.kind.as_deref(),
+            width: info.width,
+        }
+    }
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "compareStatus")]
+pub enum CompareStatus {
+    OnlyA,
+    OnlyB,
+    Changed,
+}
+
+impl From<crate::engine::hierarchy_diff::CompareStatus> for CompareStatus {
+    fn from(status: crate::engine::hierarchy_diff::CompareStatus) -> Self {
+        match status {
+            crate::engine::hierarchy_diff::CompareStatus::OnlyA => Self::OnlyA,
+            crate::engine::hierarchy_diff::CompareStatus::OnlyB => Self::OnlyB,
+            crate::engine::hierarchy_diff::CompareStatus::Changed => Self::Changed,
+        }
+    }
+}
+
 fn signal_width_schema(generator: &mut SchemaGenerator) -> Schema {
     positive_integer_schema(generator)
 }
 
+fn port_direction_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "string",
+        "enum": ["input", "output", "inout", "buffer", "linkage"]
+    })
+}
+
 fn positive_integer_schema(_: &mut SchemaGenerator) -> Schema {
     json_schema!({
         "type": "integer",
@@ -228,6 +429,12 @@ pub struct SampledSignalValue<'a> {
     path: CanonicalPath<'a>,
     #[schemars(description = "Sampled value for this signal in the selected timestamp snapshot.")]
     value: SampledValue<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Field values keyed by field name, present when the requested name is a struct or union or a --layout entry decodes the signal."
+    )]
+    fields: Option<SampledFields<'a>>,
 }
 
 impl<'a> From<&'a crate::engine::value::ValueSignalValue> for SampledSignalValue<'a> {
@@ -235,10 +442,67 @@ impl<'a> From<&'a crate::engine::value::ValueSignalValue> for SampledSignalValue
         Self {
             path: CanonicalPath::new(signal.path.as_str()),
             value: SampledValue::new(signal.value.as_str()),
+            fields: signal.fields.as_ref().map(sampled_fields),
         }
     }
 }
 
+/// One field: a sampled literal or layout enum label, or the fields of a nested struct.
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "sampledFieldValue")]
+#[serde(untagged)]
+pub enum SampledFieldValue<'a> {
+    Value(SampledValue<'a>),
+    Fields(SampledFields<'a>),
+}
+
+/// Field values as a JSON object whose keys keep declaration order.
+#[derive(Debug)]
+pub struct SampledFields<'a>(Vec<(&'a str, SampledFieldValue<'a>)>);
+
+impl Serialize for SampledFields<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(name, field)| (name, field)))
+    }
+}
+
+impl JsonSchema for SampledFields<'_> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        BTreeMap::<&str, SampledFieldValue<'_>>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        BTreeMap::<&str, SampledFieldValue<'_>>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        BTreeMap::<&str, SampledFieldValue<'_>>::json_schema(generator)
+    }
+}
+
+fn sampled_fields(fields: &crate::engine::value::ValueFields) -> SampledFields<'_> {
+    SampledFields(
+        fields
+            .iter()
+            .map(|(name, field)| {
+                let field = match field {
+                    crate::engine::value::ValueField::Value(value) => {
+                        SampledFieldValue::Value(SampledValue::new(value.as_str()))
+                    }
+                    crate::engine::value::ValueField::Fields(children) => {
+                        SampledFieldValue::Fields(sampled_fields(children))
+                    }
+                };
+                (name, field)
+            })
+            .collect(),
+    )
+}
+
 #[derive(Debug, JsonSchema, Serialize)]
 #[schemars(rename = "changeSignalValue")]
 #[schemars(extend("additionalProperties" = true))]
@@ -247,6 +511,12 @@ pub struct ChangeSignalValue<'a> {
     path: CanonicalPath<'a>,
     #[schemars(description = "Changed signal value at the reported sample point.")]
     value: SampledValue<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Field values decoded from value, present when a --layout entry applies."
+    )]
+    fields: Option<SampledFields<'a>>,
 }
 
 impl<'a> From<&'a crate::engine::change::ChangeSignalValue> for ChangeSignalValue<'a> {
@@ -254,6 +524,7 @@ impl<'a> From<&'a crate::engine::change::ChangeSignalValue> for ChangeSignalValu
         Self {
             path: CanonicalPath::new(signal.path.as_str()),
             value: SampledValue::new(signal.value.as_str()),
+            fields: signal.fields.as_ref().map(sampled_fields),
         }
     }
 }
@@ -264,6 +535,18 @@ impl<'a> From<&'a crate::engine::change::ChangeSignalValue> for ChangeSignalValu
 pub struct ValueSnapshot<'a> {
     #[schemars(description = "Timestamp requested by the value command.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(description = "Signal values sampled at this timestamp.")]
     signals: Vec<SampledSignalValue<'a>>,
 }
@@ -272,6 +555,8 @@ impl<'a> From<&'a crate::engine::value::ValueSnapshot> for ValueSnapshot<'a> {
     fn from(snapshot: &'a crate::engine::value::ValueSnapshot) -> Self {
         Self {
             time: NormalizedTime::new(snapshot.time.as_str()),
+            tick: snapshot.tick,
+            cycle: snapshot.cycle,
             signals: snapshot
                 .signals
                 .iter()
@@ -287,6 +572,18 @@ impl<'a> From<&'a crate::engine::value::ValueSnapshot> for ValueSnapshot<'a> {
 pub struct ChangeSnapshot<'a> {
     #[schemars(description = "Trigger timestamp emitted by the change command.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(description = "Timestamp used to sample values for this change row.")]
     sample_time: NormalizedTime<'a>,
     #[schemars(description = "Changed signal values for this row.")]
@@ -297,6 +594,8 @@ impl<'a> From<&'a crate::engine::change::ChangeSnapshot> for ChangeSnapshot<'a>
     fn from(snapshot: &'a crate::engine::change::ChangeSnapshot) -> Self {
         Self {
             time: NormalizedTime::new(snapshot.time.as_str()),
+            tick: snapshot.tick,
+            cycle: snapshot.cycle,
             sample_time: NormalizedTime::new(snapshot.sample_time.as_str()),
             signals: snapshot
                 .signals
@@ -345,6 +644,18 @@ impl From<crate::engine::property::PropertyResultKind> for PropertyKind {
 pub struct PropertyRow<'a> {
     #[schemars(description = "Trigger timestamp emitted by the property command.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(description = "Timestamp used to evaluate the property expression.")]
     sample_time: NormalizedTime<'a>,
     #[schemars(description = "Property result kind captured for this row.")]
@@ -355,12 +666,493 @@ impl<'a> From<&'a crate::engine::property::PropertyCaptureRow> for PropertyRow<'
     fn from(row: &'a crate::engine::property::PropertyCaptureRow) -> Self {
         Self {
             time: NormalizedTime::new(row.time.as_str()),
+            tick: row.tick,
+            cycle: row.cycle,
             sample_time: NormalizedTime::new(row.sample_time.as_str()),
             kind: row.kind.into(),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CdcHazardKind {
+    Window,
+    MultiBit,
+}
+
+impl JsonSchema for CdcHazardKind {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "cdcHazardKind".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({"type": "string", "enum": ["window", "multi_bit"]})
+    }
+}
+
+impl From<crate::engine::cdc::CdcHazardKind> for CdcHazardKind {
+    fn from(kind: crate::engine::cdc::CdcHazardKind) -> Self {
+        match kind {
+            crate::engine::cdc::CdcHazardKind::Window => Self::Window,
+            crate::engine::cdc::CdcHazardKind::MultiBit => Self::MultiBit,
+        }
+    }
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "cdcHazard")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct CdcHazard<'a> {
+    #[schemars(
+        description = "Time of the crossing change for window rows, or of the destination edge for multi_bit rows."
+    )]
+    time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
+    #[schemars(description = "Hazard kind reported for this row.")]
+    kind: CdcHazardKind,
+    #[schemars(description = "Canonical path of the crossing signal.")]
+    path: CanonicalPath<'a>,
+    #[schemars(
+        description = "Value before the change or at the previous destination sample; null before the first recorded value."
+    )]
+    previous: Option<SampledValue<'a>>,
+    #[schemars(description = "Value after the change or at this destination sample.")]
+    value: SampledValue<'a>,
+    #[schemars(description = "Destination clock edge the hazard is reported against.")]
+    dst_edge: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Signed distance from dst_edge to the change, for example -200ps; present on window rows."
+    )]
+    offset: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Number of bits that differ between the two destination samples; present on multi_bit rows."
+    )]
+    bits_changed: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Latest --src-clock edge at or before the change that launched value; absent when there is none."
+    )]
+    src_edge: Option<NormalizedTime<'a>>,
+}
+
+impl<'a> From<&'a crate::engine::cdc::CdcHazard> for CdcHazard<'a> {
+    fn from(row: &'a crate::engine::cdc::CdcHazard) -> Self {
+        Self {
+            time: NormalizedTime::new(row.time.as_str()),
+            tick: row.tick,
+            cycle: row.cycle,
+            kind: row.kind.into(),
+            path: CanonicalPath::new(row.path.as_str()),
+            previous: row.previous.as_deref().map(SampledValue::new),
+            value: SampledValue::new(row.value.as_str()),
+            dst_edge: NormalizedTime::new(row.dst_edge.as_str()),
+            offset: row.offset.as_deref(),
+            bits_changed: row.bits_changed,
+            src_edge: row.src_edge.as_deref().map(NormalizedTime::new),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimingCheckKind {
+    Setup,
+    Hold,
+}
+
+impl JsonSchema for TimingCheckKind {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "timingCheckKind".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({"type": "string", "enum": ["setup", "hold"]})
+    }
+}
+
+impl From<crate::engine::timing::TimingCheckKind> for TimingCheckKind {
+    fn from(kind: crate::engine::timing::TimingCheckKind) -> Self {
+        match kind {
+            crate::engine::timing::TimingCheckKind::Setup => Self::Setup,
+            crate::engine::timing::TimingCheckKind::Hold => Self::Hold,
+        }
+    }
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "timingViolation")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct TimingViolation<'a> {
+    #[schemars(description = "Time of the data change.")]
+    time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
+    #[schemars(description = "Timing check the change falls into relative to edge.")]
+    kind: TimingCheckKind,
+    #[schemars(description = "Canonical path of the data signal.")]
+    path: CanonicalPath<'a>,
+    #[schemars(description = "Value one dump tick before the change.")]
+    previous: SampledValue<'a>,
+    #[schemars(description = "Value after the change.")]
+    value: SampledValue<'a>,
+    #[schemars(description = "Clock edge the change is checked against.")]
+    edge: NormalizedTime<'a>,
+    #[schemars(
+        description = "Measured distance from the change to the edge minus the --setup or --hold limit, for example -150ps; 0 means the change is exactly on the window boundary."
+    )]
+    slack: &'a str,
+}
+
+impl<'a> From<&'a crate::engine::timing::TimingViolation> for TimingViolation<'a> {
+    fn from(row: &'a crate::engine::timing::TimingViolation) -> Self {
+        Self {
+            time: NormalizedTime::new(row.time.as_str()),
+            tick: row.tick,
+            cycle: row.cycle,
+            kind: row.kind.into(),
+            path: CanonicalPath::new(row.path.as_str()),
+            previous: SampledValue::new(row.previous.as_str()),
+            value: SampledValue::new(row.value.as_str()),
+            edge: NormalizedTime::new(row.edge.as_str()),
+            slack: row.slack.as_str(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlitchKind {
+    Pulse,
+    ZeroWidth,
+}
+
+impl JsonSchema for GlitchKind {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "glitchKind".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({"type": "string", "enum": ["pulse", "zero_width"]})
+    }
+}
+
+impl From<crate::engine::glitch::GlitchKind> for GlitchKind {
+    fn from(kind: crate::engine::glitch::GlitchKind) -> Self {
+        match kind {
+            crate::engine::glitch::GlitchKind::Pulse => Self::Pulse,
+            crate::engine::glitch::GlitchKind::ZeroWidth => Self::ZeroWidth,
+        }
+    }
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "glitchRow")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct GlitchRow<'a> {
+    #[schemars(description = "Time the glitch value appeared.")]
+    time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
+    #[schemars(description = "Glitch kind reported for this row.")]
+    kind: GlitchKind,
+    #[schemars(description = "Canonical path of the glitching signal.")]
+    path: CanonicalPath<'a>,
+    #[schemars(
+        description = "Value before the glitch; null when the glitch starts the signal's recorded history."
+    )]
+    previous: Option<SampledValue<'a>>,
+    #[schemars(description = "Value held during the glitch.")]
+    value: SampledValue<'a>,
+    #[schemars(description = "Value that replaced the glitch value.")]
+    next: SampledValue<'a>,
+    #[schemars(description = "How long the glitch value was held; 0 for zero_width rows.")]
+    width: NormalizedTime<'a>,
+}
+
+impl<'a> From<&'a crate::engine::glitch::GlitchRow> for GlitchRow<'a> {
+    fn from(row: &'a crate::engine::glitch::GlitchRow) -> Self {
+        Self {
+            time: NormalizedTime::new(row.time.as_str()),
+            tick: row.tick,
+            cycle: row.cycle,
+            kind: row.kind.into(),
+            path: CanonicalPath::new(row.path.as_str()),
+            previous: row.previous.as_deref().map(SampledValue::new),
+            value: SampledValue::new(row.value.as_str()),
+            next: SampledValue::new(row.next.as_str()),
+            width: NormalizedTime::new(row.width.as_str()),
+        }
+    }
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "clockData")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct ClockData<'a> {
+    #[schemars(description = "Canonical path of the measured clock signal.")]
+    path: CanonicalPath<'a>,
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Rising edges in the selected time range.")]
+    rising_edges: usize,
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Falling edges in the selected time range.")]
+    falling_edges: usize,
+    #[schemars(
+        description = "Rising-to-rising period statistics outside gated segments; null with fewer than two usable rising edges."
+    )]
+    period: Option<ClockPeriod<'a>>,
+    #[schemars(
+        description = "Frequency of the nominal period, for example 100MHz; null when period is null."
+    )]
+    frequency: Option<&'a str>,
+    #[schemars(
+        description = "High-time percentages over periods that contain a falling edge; null when no period does."
+    )]
+    duty_cycle: Option<ClockDutyCycle>,
+    #[schemars(
+        description = "Segments where no rising edge came for more than --gate-periods periods."
+    )]
+    gated: Vec<ClockGate<'a>>,
+    #[schemars(
+        description = "Points where the period left the current segment period by more than --tolerance percent."
+    )]
+    frequency_changes: Vec<ClockFrequencyChange<'a>>,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "clockPeriod")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct ClockPeriod<'a> {
+    #[schemars(description = "Median period.")]
+    nominal: NormalizedTime<'a>,
+    #[schemars(description = "Shortest period.")]
+    min: NormalizedTime<'a>,
+    #[schemars(description = "Longest period.")]
+    max: NormalizedTime<'a>,
+    #[schemars(description = "Mean period, rounded to 0.001 of the dump time unit.")]
+    mean: NormalizedTime<'a>,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "clockDutyCycle")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct ClockDutyCycle {
+    #[schemars(description = "Lowest per-period high-time percentage.")]
+    min: f64,
+    #[schemars(description = "Highest per-period high-time percentage.")]
+    max: f64,
+    #[schemars(description = "Total high time over total measured period time, as a percentage.")]
+    mean: f64,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "clockGate")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct ClockGate<'a> {
+    #[schemars(description = "Edge after which the clock held level.")]
+    start: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of start; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of start, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once start reaches that edge."
+    )]
+    cycle: Option<u64>,
+    #[schemars(
+        description = "Edge where the clock resumed toggling; null when it stayed stopped through the end of the range."
+    )]
+    end: Option<NormalizedTime<'a>>,
+    #[schemars(description = "Time from start to end, or to the end of the range.")]
+    duration: NormalizedTime<'a>,
+    #[schemars(description = "Level the clock held while stopped.")]
+    level: SampledValue<'a>,
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "clockFrequencyChange")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct ClockFrequencyChange<'a> {
+    #[schemars(description = "Rising edge that starts the first period at the new frequency.")]
+    time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
+    #[schemars(description = "Segment period before the change.")]
+    previous_period: NormalizedTime<'a>,
+    #[schemars(description = "First period after the change.")]
+    period: NormalizedTime<'a>,
+    #[schemars(description = "Frequency before the change.")]
+    previous_frequency: &'a str,
+    #[schemars(description = "Frequency after the change.")]
+    frequency: &'a str,
+}
+
+impl<'a> From<&'a crate::engine::clock::ClockData> for ClockData<'a> {
+    fn from(data: &'a crate::engine::clock::ClockData) -> Self {
+        Self {
+            path: CanonicalPath::new(data.path.as_str()),
+            rising_edges: data.rising_edges,
+            falling_edges: data.falling_edges,
+            period: data.period.as_ref().map(|period| ClockPeriod {
+                nominal: NormalizedTime::new(period.nominal.as_str()),
+                min: NormalizedTime::new(period.min.as_str()),
+                max: NormalizedTime::new(period.max.as_str()),
+                mean: NormalizedTime::new(period.mean.as_str()),
+            }),
+            frequency: data.frequency.as_deref(),
+            duty_cycle: data.duty_cycle.as_ref().map(|duty| ClockDutyCycle {
+                min: duty.min,
+                max: duty.max,
+                mean: duty.mean,
+            }),
+            gated: data
+                .gated
+                .iter()
+                .map(|gate| ClockGate {
+                    start: NormalizedTime::new(gate.start.as_str()),
+                    tick: gate.tick,
+                    cycle: gate.cycle,
+                    end: gate.end.as_deref().map(NormalizedTime::new),
+                    duration: NormalizedTime::new(gate.duration.as_str()),
+                    level: SampledValue::new(gate.level.as_str()),
+                })
+                .collect(),
+            frequency_changes: data
+                .frequency_changes
+                .iter()
+                .map(|change| ClockFrequencyChange {
+                    time: NormalizedTime::new(change.time.as_str()),
+                    tick: change.tick,
+                    cycle: change.cycle,
+                    previous_period: NormalizedTime::new(change.previous_period.as_str()),
+                    period: NormalizedTime::new(change.period.as_str()),
+                    previous_frequency: change.previous_frequency.as_str(),
+                    frequency: change.frequency.as_str(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(rename = "auditRow")]
+#[schemars(extend("additionalProperties" = true))]
+pub struct AuditRow<'a> {
+    #[schemars(description = "Sample time, --cycles clock events after reset deassertion.")]
+    time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
+    #[schemars(
+        description = "Canonical path of the scope that declares the signal. Rows are sorted by scope, then path, so consecutive rows with the same scope form one group."
+    )]
+    scope: CanonicalPath<'a>,
+    #[schemars(description = "Signal name within its scope.")]
+    name: &'a str,
+    #[schemars(description = "Canonical path of the signal.")]
+    path: CanonicalPath<'a>,
+    #[schemars(
+        description = "Sampled value; null when the signal has no value at the sample time."
+    )]
+    value: Option<SampledValue<'a>>,
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Bits that are not 0 or 1 at the sample time.")]
+    unknown_bits: u32,
+    #[schemars(
+        description = "First later time the signal held only 0/1 bits; null when it never does before the dump end."
+    )]
+    first_known: Option<NormalizedTime<'a>>,
+}
+
+impl<'a> From<&'a crate::engine::audit::AuditRow> for AuditRow<'a> {
+    fn from(row: &'a crate::engine::audit::AuditRow) -> Self {
+        Self {
+            time: NormalizedTime::new(row.time.as_str()),
+            tick: row.tick,
+            cycle: row.cycle,
+            scope: CanonicalPath::new(row.scope.as_str()),
+            name: row.name.as_str(),
+            path: CanonicalPath::new(row.path.as_str()),
+            value: row.value.as_deref().map(SampledValue::new),
+            unknown_bits: row.unknown_bits,
+            first_known: row.first_known.as_deref().map(NormalizedTime::new),
+        }
+    }
+}
+
 #[derive(Debug, JsonSchema, Serialize)]
 #[schemars(rename = "extractPayloadValue")]
 #[schemars(extend("additionalProperties" = true))]
@@ -369,6 +1161,12 @@ pub struct ExtractPayloadValue<'a> {
     path: CanonicalPath<'a>,
     #[schemars(description = "Sampled payload value formatted as a Verilog-style literal string.")]
     value: SampledValue<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(
+        description = "Field values decoded from value, present when a --layout entry applies."
+    )]
+    fields: Option<SampledFields<'a>>,
 }
 
 impl<'a> From<&'a crate::engine::extract::ExtractPayloadValue> for ExtractPayloadValue<'a> {
@@ -376,6 +1174,7 @@ impl<'a> From<&'a crate::engine::extract::ExtractPayloadValue> for ExtractPayloa
         Self {
             path: CanonicalPath::new(value.path.as_str()),
             value: SampledValue::new(value.value.as_str()),
+            fields: value.fields.as_ref().map(sampled_fields),
         }
     }
 }
@@ -386,6 +1185,18 @@ impl<'a> From<&'a crate::engine::extract::ExtractPayloadValue> for ExtractPayloa
 pub struct ExtractGenericRow<'a> {
     #[schemars(description = "Selected event timestamp emitted by extract generic.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(
         description = "Pre-edge timestamp used to evaluate the predicate and sample payload values."
     )]
@@ -400,6 +1211,8 @@ impl<'a> From<&'a crate::engine::extract::ExtractGenericRow> for ExtractGenericR
     fn from(row: &'a crate::engine::extract::ExtractGenericRow) -> Self {
         Self {
             time: NormalizedTime::new(row.time.as_str()),
+            tick: row.tick,
+            cycle: row.cycle,
             sample_time: NormalizedTime::new(row.sample_time.as_str()),
             source: row.source.as_str(),
             payload: row.payload.iter().map(ExtractPayloadValue::from).collect(),
@@ -469,6 +1282,14 @@ impl<'a> From<&'a crate::engine::ahb::AhbInitialDataPhase> for ExtractAhbInitial
 #[schemars(rename = "extractAhbEvent")]
 pub struct ExtractAhbEvent<'a> {
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    cycle: Option<u64>,
     sample_time: NormalizedTime<'a>,
     profile: &'a str,
     event: &'a str,
@@ -487,6 +1308,8 @@ impl<'a> From<&'a crate::engine::ahb::AhbEvent> for ExtractAhbEvent<'a> {
     fn from(event: &'a crate::engine::ahb::AhbEvent) -> Self {
         Self {
             time: NormalizedTime::new(event.time.as_str()),
+            tick: event.tick,
+            cycle: event.cycle,
             sample_time: NormalizedTime::new(event.sample_time.as_str()),
             profile: event.profile.as_str(),
             event: event.event.as_str(),
@@ -567,6 +1390,18 @@ impl<'a> From<&'a crate::engine::apb::ApbSignalMapping> for ExtractApbMapping<'a
 pub struct ExtractApbEvent<'a> {
     #[schemars(description = "Selected APB event timestamp.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(description = "Pre-edge timestamp used to classify and sample the APB event.")]
     sample_time: NormalizedTime<'a>,
     #[schemars(description = "APB profile name for this event row.")]
@@ -583,6 +1418,8 @@ impl<'a> From<&'a crate::engine::apb::ApbEvent> for ExtractApbEvent<'a> {
     fn from(event: &'a crate::engine::apb::ApbEvent) -> Self {
         Self {
             time: NormalizedTime::new(event.time.as_str()),
+            tick: event.tick,
+            cycle: event.cycle,
             sample_time: NormalizedTime::new(event.sample_time.as_str()),
             profile: event.profile.as_str(),
             event: event.event.as_str(),
@@ -682,6 +1519,18 @@ impl From<crate::engine::atb::AtbEventKind> for ExtractAtbEventKind {
 pub struct ExtractAtbEvent<'a> {
     #[schemars(description = "Selected ATB event timestamp.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(
         description = "Pre-edge timestamp used to evaluate the ATB predicate and sample payload values."
     )]
@@ -700,6 +1549,8 @@ impl<'a> From<&'a crate::engine::atb::AtbEvent> for ExtractAtbEvent<'a> {
     fn from(event: &'a crate::engine::atb::AtbEvent) -> Self {
         Self {
             time: NormalizedTime::new(event.time.as_str()),
+            tick: event.tick,
+            cycle: event.cycle,
             sample_time: NormalizedTime::new(event.sample_time.as_str()),
             profile: event.profile.as_str(),
             event: event.event.into(),
@@ -773,6 +1624,18 @@ impl<'a> From<&'a crate::engine::axi::AxiSignalMapping> for ExtractAxiMapping<'a
 pub struct ExtractAxiTransfer<'a> {
     #[schemars(description = "Selected AXI transfer event timestamp.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(
         description = "Pre-edge timestamp used to evaluate ready/valid and sample payload values."
     )]
@@ -793,6 +1656,8 @@ impl<'a> From<&'a crate::engine::axi::AxiTransfer> for ExtractAxiTransfer<'a> {
     fn from(transfer: &'a crate::engine::axi::AxiTransfer) -> Self {
         Self {
             time: NormalizedTime::new(transfer.time.as_str()),
+            tick: transfer.tick,
+            cycle: transfer.cycle,
             sample_time: NormalizedTime::new(transfer.sample_time.as_str()),
             profile: transfer.profile.as_str(),
             channel: transfer.channel.as_str(),
@@ -874,6 +1739,18 @@ impl<'a> From<&'a crate::engine::axistream::AxiStreamSignalMapping>
 pub struct ExtractAxiStreamTransfer<'a> {
     #[schemars(description = "Selected AXI-Stream transfer event timestamp.")]
     time: NormalizedTime<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(description = "Raw dump tick of time; present with --ticks.")]
+    tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(default)]
+    #[schemars(schema_with = "nonnegative_integer_schema")]
+    #[schemars(
+        description = "Clock cycle of time, counted from 1 at the first --clock edge like nth(N, <event>); present with --clock once time reaches that edge."
+    )]
+    cycle: Option<u64>,
     #[schemars(
         description = "Pre-edge timestamp used to evaluate the handshake and sample payload values."
     )]
@@ -888,6 +1765,8 @@ impl<'a> From<&'a crate::engine::axistream::AxiStreamTransfer> for ExtractAxiStr
     fn from(transfer: &'a crate::engine::axistream::AxiStreamTransfer) -> Self {
         Self {
             time: NormalizedTime::new(transfer.time.as_str()),
+            tick: transfer.tick,
+            cycle: transfer.cycle,
             sample_time: NormalizedTime::new(transfer.sample_time.as_str()),
             profile: transfer.profile.as_str(),
             payload: transfer
@@ -1094,10 +1973,14 @@ mod tests {
             human_options: HumanRenderOptions::default(),
             data: CommandData::Value(vec![crate::engine::value::ValueSnapshot {
                 time: "5ns".to_string(),
+                tick: None,
+                cycle: None,
                 signals: vec![crate::engine::value::ValueSignalValue {
                     display: "sig".to_string(),
                     path: "top.sig".to_string(),
                     value: "1'h1".to_string(),
+                    fields: None,
+                    decoded: false,
                 }],
             }]),
             diagnostics: Vec::new(),
@@ -6,16 +6,17 @@ use super::common::{
 };
 use super::input::{
     ExtractAhbSourceInput, ExtractApbSourceInput, ExtractAtbSourceInput, ExtractAxiSourceInput,
-    ExtractAxiStreamSourceInput, ExtractGenericSource, ExtractGenericSourcesInput,
+    ExtractAxiStreamSourceInput, ExtractGenericSource, ExtractGenericSourcesInput, SignalLayout,
+    SignalLayoutField, SignalLayoutsInput,
 };
 use super::output::{
-    ChangeSignalValue, ChangeSnapshot, DocsSearchData, DocsSearchMatch, DocsTopicsData,
-    ExtractAhbAddressSnapshot, ExtractAhbData, ExtractAhbEvent, ExtractAhbInitialDataPhase,
-    ExtractAhbMapping, ExtractApbData, ExtractApbEvent, ExtractApbMapping, ExtractAtbData,
-    ExtractAtbEvent, ExtractAtbMapping, ExtractAxiData, ExtractAxiMapping, ExtractAxiStreamData,
-    ExtractAxiStreamMapping, ExtractAxiStreamTransfer, ExtractAxiTransfer, ExtractGenericRow,
-    ExtractPayloadValue, InfoData, PropertyRow, SampledSignalValue, ScopeEntry, SignalEntry,
-    TopicSummary, ValueSnapshot,
+    AuditRow, CdcHazard, ChangeSignalValue, ChangeSnapshot, ClockData, DocsSearchData,
+    DocsSearchMatch, DocsTopicsData, ExtractAhbAddressSnapshot, ExtractAhbData, ExtractAhbEvent,
+    ExtractAhbInitialDataPhase, ExtractAhbMapping, ExtractApbData, ExtractApbEvent,
+    ExtractApbMapping, ExtractAtbData, ExtractAtbEvent, ExtractAtbMapping, ExtractAxiData,
+    ExtractAxiMapping, ExtractAxiStreamData, ExtractAxiStreamMapping, ExtractAxiStreamTransfer,
+    ExtractAxiTransfer, ExtractGenericRow, ExtractPayloadValue, GlitchRow, InfoData, PropertyRow,
+    SampledSignalValue, ScopeEntry, SignalEntry, TimingViolation, TopicSummary, ValueSnapshot,
 };
 use super::stream::{
     BeginRecord, DiagnosticRecord, EndRecord, ExtractAhbContext, ExtractApbContext,
@@ -95,6 +96,11 @@ fn output_schema_value() -> Value {
                     ref_schema("valueData"),
                     ref_schema("changeData"),
                     ref_schema("propertyData"),
+                    ref_schema("cdcData"),
+                    ref_schema("timingData"),
+                    ref_schema("glitchData"),
+                    ref_schema("auditData"),
+                    ref_schema("clockData"),
                     ref_schema("extractAhbData"),
                     ref_schema("extractApbData"),
                     ref_schema("extractAtbData"),
@@ -114,6 +120,11 @@ fn output_schema_value() -> Value {
             command_data_branch("value", "valueData"),
             command_data_branch("change", "changeData"),
             command_data_branch("property", "propertyData"),
+            command_data_branch("cdc", "cdcData"),
+            command_data_branch("timing", "timingData"),
+            command_data_branch("glitch", "glitchData"),
+            command_data_branch("audit", "auditData"),
+            command_data_branch("clock", "clockData"),
             command_data_branch("extract ahb", "extractAhbData"),
             command_data_branch("extract apb", "extractApbData"),
             command_data_branch("extract atb", "extractAtbData"),
@@ -157,6 +168,7 @@ fn input_schema_value() -> Value {
             ref_schema("extractAtbSourceInput"),
             ref_schema("extractAxiSourceInput"),
             ref_schema("extractAxiStreamSourceInput"),
+            ref_schema("signalLayoutsInput"),
         ],
         "$defs": Value::Object(defs),
     })
@@ -213,6 +225,22 @@ fn output_defs() -> Value {
         "propertyData".to_string(),
         json!({"type": "array", "items": ref_schema("propertyRow")}),
     );
+    object.insert(
+        "cdcData".to_string(),
+        json!({"type": "array", "items": ref_schema("cdcHazard")}),
+    );
+    object.insert(
+        "timingData".to_string(),
+        json!({"type": "array", "items": ref_schema("timingViolation")}),
+    );
+    object.insert(
+        "glitchData".to_string(),
+        json!({"type": "array", "items": ref_schema("glitchRow")}),
+    );
+    object.insert(
+        "auditData".to_string(),
+        json!({"type": "array", "items": ref_schema("auditRow")}),
+    );
     object.insert(
         "extractGenericData".to_string(),
         json!({"type": "array", "items": ref_schema("extractGenericRow")}),
@@ -247,6 +275,11 @@ fn stream_defs() -> Value {
                 ref_schema("valueItemRecord"),
                 ref_schema("changeItemRecord"),
                 ref_schema("propertyItemRecord"),
+                ref_schema("cdcItemRecord"),
+                ref_schema("timingItemRecord"),
+                ref_schema("glitchItemRecord"),
+                ref_schema("auditItemRecord"),
+                ref_schema("clockItemRecord"),
                 ref_schema("extractAhbItemRecord"),
                 ref_schema("extractApbItemRecord"),
                 ref_schema("extractAtbItemRecord"),
@@ -263,6 +296,11 @@ fn stream_defs() -> Value {
         ("valueItemRecord", "itemRecordForValueSnapshot"),
         ("changeItemRecord", "itemRecordForChangeSnapshot"),
         ("propertyItemRecord", "itemRecordForPropertyRow"),
+        ("cdcItemRecord", "itemRecordForCdcHazard"),
+        ("timingItemRecord", "itemRecordForTimingViolation"),
+        ("glitchItemRecord", "itemRecordForGlitchRow"),
+        ("auditItemRecord", "itemRecordForAuditRow"),
+        ("clockItemRecord", "itemRecordForClockData"),
         ("extractAhbItemRecord", "itemRecordForExtractAhbEvent"),
         ("extractApbItemRecord", "itemRecordForExtractApbEvent"),
         ("extractAtbItemRecord", "itemRecordForExtractAtbEvent"),
@@ -282,6 +320,11 @@ fn stream_defs() -> Value {
         ("itemRecordForValueSnapshot", "value", "valueSnapshot"),
         ("itemRecordForChangeSnapshot", "change", "changeSnapshot"),
         ("itemRecordForPropertyRow", "property", "propertyRow"),
+        ("itemRecordForCdcHazard", "cdc", "cdcHazard"),
+        ("itemRecordForTimingViolation", "timing", "timingViolation"),
+        ("itemRecordForGlitchRow", "glitch", "glitchRow"),
+        ("itemRecordForAuditRow", "audit", "auditRow"),
+        ("itemRecordForClockData", "clock", "clockData"),
         (
             "itemRecordForExtractAhbEvent",
             "extract ahb",
@@ -404,6 +447,11 @@ fn generated_waveform_payload_defs() -> Map<String, Value> {
     generator.subschema_for::<ValueSnapshot<'static>>();
     generator.subschema_for::<ChangeSnapshot<'static>>();
     generator.subschema_for::<PropertyRow<'static>>();
+    generator.subschema_for::<CdcHazard<'static>>();
+    generator.subschema_for::<TimingViolation<'static>>();
+    generator.subschema_for::<GlitchRow<'static>>();
+    generator.subschema_for::<AuditRow<'static>>();
+    generator.subschema_for::<ClockData<'static>>();
     generator.subschema_for::<ExtractPayloadValue<'static>>();
     generator.subschema_for::<ExtractAhbMapping<'static>>();
     generator.subschema_for::<ExtractAhbAddressSnapshot<'static>>();
@@ -441,6 +489,9 @@ fn generated_input_payload_defs() -> Map<String, Value> {
     generator.subschema_for::<ExtractAtbSourceInput<'static>>();
     generator.subschema_for::<ExtractAxiSourceInput<'static>>();
     generator.subschema_for::<ExtractAxiStreamSourceInput<'static>>();
+    generator.subschema_for::<SignalLayoutsInput<'static>>();
+    generator.subschema_for::<SignalLayout<'static>>();
+    generator.subschema_for::<SignalLayoutField<'static>>();
     let mut defs = generator.take_definitions(true);
     ahb_schema::apply_input_defs(&mut defs);
     apb_schema::apply_input_defs(&mut defs);
@@ -505,6 +556,11 @@ fn output_commands() -> Vec<&'static str> {
         "value",
         "change",
         "property",
+        "cdc",
+        "timing",
+        "glitch",
+        "audit",
+        "clock",
         "extract ahb",
         "extract apb",
         "extract atb",
@@ -524,6 +580,11 @@ fn stream_commands() -> Vec<&'static str> {
         "value",
         "change",
         "property",
+        "cdc",
+        "timing",
+        "glitch",
+        "audit",
+        "clock",
         "extract ahb",
         "extract apb",
         "extract atb",
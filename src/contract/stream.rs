@@ -7,10 +7,11 @@ use crate::error::WavepeekError;
 
 use super::common::ContractDiagnostic;
 use super::output::{
-    ChangeSnapshot, ExtractAhbEvent, ExtractAhbInitialDataPhase, ExtractAhbMapping,
-    ExtractApbEvent, ExtractApbMapping, ExtractAtbEvent, ExtractAtbMapping, ExtractAxiMapping,
-    ExtractAxiStreamMapping, ExtractAxiStreamTransfer, ExtractAxiTransfer, ExtractGenericRow,
-    InfoData, PropertyRow, ScopeEntry, SignalEntry, ValueSnapshot,
+    AuditRow, CdcHazard, ChangeSnapshot, ClockData, ExtractAhbEvent, ExtractAhbInitialDataPhase,
+    ExtractAhbMapping, ExtractApbEvent, ExtractApbMapping, ExtractAtbEvent, ExtractAtbMapping,
+    ExtractAxiMapping, ExtractAxiStreamMapping, ExtractAxiStreamTransfer, ExtractAxiTransfer,
+    ExtractGenericRow, GlitchRow, InfoData, PropertyRow, ScopeEntry, SignalEntry, TimingViolation,
+    ValueSnapshot,
 };
 use super::schema::STREAM_SCHEMA_URL;
 
@@ -378,6 +379,11 @@ pub enum StreamItemData<'a> {
     Value(ValueSnapshot<'a>),
     Change(ChangeSnapshot<'a>),
     Property(PropertyRow<'a>),
+    Cdc(CdcHazard<'a>),
+    Timing(TimingViolation<'a>),
+    Glitch(GlitchRow<'a>),
+    Audit(AuditRow<'a>),
+    Clock(ClockData<'a>),
     ExtractAhb(ExtractAhbEvent<'a>),
     ExtractApb(ExtractApbEvent<'a>),
     ExtractAtb(ExtractAtbEvent<'a>),
@@ -432,6 +438,41 @@ impl StreamItem for crate::engine::property::PropertyCaptureRow {
     }
 }
 
+impl StreamItem for crate::engine::cdc::CdcHazard {
+    fn stream_item(&self, command: CommandName) -> Result<StreamItemData<'_>, WavepeekError> {
+        require_item_command(command, CommandName::Cdc)?;
+        Ok(StreamItemData::Cdc(CdcHazard::from(self)))
+    }
+}
+
+impl StreamItem for crate::engine::timing::TimingViolation {
+    fn stream_item(&self, command: CommandName) -> Result<StreamItemData<'_>, WavepeekError> {
+        require_item_command(command, CommandName::Timing)?;
+        Ok(StreamItemData::Timing(TimingViolation::from(self)))
+    }
+}
+
+impl StreamItem for crate::engine::glitch::GlitchRow {
+    fn stream_item(&self, command: CommandName) -> Result<StreamItemData<'_>, WavepeekError> {
+        require_item_command(command, CommandName::Glitch)?;
+        Ok(StreamItemData::Glitch(GlitchRow::from(self)))
+    }
+}
+
+impl StreamItem for crate::engine::audit::AuditRow {
+    fn stream_item(&self, command: CommandName) -> Result<StreamItemData<'_>, WavepeekError> {
+        require_item_command(command, CommandName::Audit)?;
+        Ok(StreamItemData::Audit(AuditRow::from(self)))
+    }
+}
+
+impl StreamItem for crate::engine::clock::ClockData {
+    fn stream_item(&self, command: CommandName) -> Result<StreamItemData<'_>, WavepeekError> {
+        require_item_command(command, CommandName::Clock)?;
+        Ok(StreamItemData::Clock(ClockData::from(self)))
+    }
+}
+
 impl StreamItem for crate::engine::ahb::AhbEvent {
     fn stream_item(&self, command: CommandName) -> Result<StreamItemData<'_>, WavepeekError> {
         require_item_command(command, CommandName::ExtractAhb)?;
@@ -534,6 +575,11 @@ fn require_stream_command(command: CommandName) -> Result<(), WavepeekError> {
         | CommandName::Value
         | CommandName::Change
         | CommandName::Property
+        | CommandName::Cdc
+        | CommandName::Timing
+        | CommandName::Glitch
+        | CommandName::Audit
+        | CommandName::Clock
         | CommandName::ExtractAhb
         | CommandName::ExtractApb
         | CommandName::ExtractAtb
@@ -574,6 +620,15 @@ mod tests {
             time_unit: "1ns".to_string(),
             time_start: "0ns".to_string(),
             time_end: "10ns".to_string(),
+            format: "vcd".to_string(),
+            backend: "wellen".to_string(),
+            scope_count: 1,
+            signal_count: 2,
+            timestamp_count: Some(3),
+            date: None,
+            simulator: None,
+            timescale: None,
+            activity: None,
         };
 
         assert!(ItemRecord::new(1, CommandName::Change, &item).is_err());
@@ -587,6 +642,8 @@ mod tests {
             path: "top.clk".to_string(),
             kind: "wire".to_string(),
             width: Some(1),
+            direction: None,
+            compare: None,
         };
         let value = serde_json::to_value(
             ItemRecord::new(1, CommandName::Signal, &item).expect("signal item should convert"),
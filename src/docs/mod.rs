@@ -904,7 +904,7 @@ mod tests {
 
         let summary = export_catalog(&out_dir, false).expect("export should succeed");
 
-        assert_eq!(summary.topics.len(), 24);
+        assert_eq!(summary.topics.len(), 32);
         assert!(out_dir.join("commands").join("change.md").exists());
         assert!(out_dir.join("manifest.json").exists());
         assert!(!out_dir.join("wavepeek.md").exists());
@@ -1230,7 +1230,7 @@ mod tests {
         assert!(suggest_topics("   ", 10).is_empty());
         let suggestions = suggest_topics("command", 10);
         assert!(suggestions.len() > 1);
-        assert_eq!(suggestions[0].id, "commands/change");
+        assert_eq!(suggestions[0].id, "commands/audit");
         assert_eq!(suggest_topics("command", 0).len(), 0);
         assert!(
             suggest_topics("First change", 5)
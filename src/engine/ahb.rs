@@ -10,13 +10,16 @@ use crate::cli::extract::AhbArgs;
 use crate::contract::schema::INPUT_SCHEMA_URL;
 use crate::debug_trace::DebugTrace;
 use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
 use crate::engine::expr_runtime::{
     SharedWaveform, bind_waveform_event_expr, candidate_sources_for_handles,
     event_candidate_handles, event_expr_matches, open_shared_waveform,
 };
 use crate::engine::extract::{initial_diagnostics, max_entries, parse_bound_time};
+use crate::engine::sample_point::{required_edge_sample_offset, sample_time_before};
 use crate::engine::signal_mapping;
-use crate::engine::time::{format_raw_timestamp, parse_dump_time_context};
+use crate::engine::time::{TimeFormat, parse_dump_time_context};
+use crate::engine::time_expr::resolve_time_window;
 use crate::engine::value_format::format_verilog_literal;
 use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
 use crate::error::WavepeekError;
@@ -132,6 +135,13 @@ pub struct AhbInitialDataPhase {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AhbEvent {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Dump tick of `time`, which `--html` strips are centered on.
+    #[serde(skip)]
+    pub raw_time: u64,
     pub sample_time: String,
     pub profile: String,
     pub event: String,
@@ -647,6 +657,9 @@ impl Walker {
                 if collect_events {
                     events.push(AhbEvent {
                         time: time.to_string(),
+                        tick: None,
+                        cycle: None,
+                        raw_time: 0,
                         sample_time: sample_time.to_string(),
                         profile: self.profile.clone(),
                         event: "address".to_string(),
@@ -713,6 +726,9 @@ impl Walker {
     ) -> AhbEvent {
         AhbEvent {
             time: time.to_string(),
+            tick: None,
+            cycle: None,
+            raw_time: 0,
             sample_time: sample_time.to_string(),
             profile: self.profile.clone(),
             event: transfer.as_str().expect("known transfer").to_string(),
@@ -725,6 +741,9 @@ impl Walker {
     fn boundary_event(&self, time: &str, sample_time: &str, kind: &str) -> AhbEvent {
         AhbEvent {
             time: time.to_string(),
+            tick: None,
+            cycle: None,
+            raw_time: 0,
             sample_time: sample_time.to_string(),
             profile: self.profile.clone(),
             event: kind.to_string(),
@@ -766,6 +785,9 @@ impl Walker {
 
         AhbEvent {
             time: time.to_string(),
+            tick: None,
+            cycle: None,
+            raw_time: 0,
             sample_time: sample_time.to_string(),
             profile: self.profile.clone(),
             event: kind.to_string(),
@@ -836,6 +858,8 @@ fn run_with_sink<S: AhbEventSink + ?Sized>(
 
     let metadata = waveform.borrow().metadata()?;
     let dump_time = parse_dump_time_context(&metadata)?;
+    let time_format = TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks);
+    let sample_offset = required_edge_sample_offset(&args.sample_mode, dump_time, HELP)?;
     let dump_start_raw =
         u64::try_from(dump_time.dump_start_zs / dump_time.dump_tick_zs).map_err(|_| {
             WavepeekError::Internal("dump start timestamp exceeds supported range".into())
@@ -844,11 +868,28 @@ fn run_with_sink<S: AhbEventSink + ?Sized>(
         u64::try_from(dump_time.dump_end_zs / dump_time.dump_tick_zs).map_err(|_| {
             WavepeekError::Internal("dump end timestamp exceeds supported range".into())
         })?;
-    let from_raw = match args.from.as_deref() {
+    let clock = CycleClock::load(
+        &waveform,
+        args.scope.as_deref(),
+        args.clock.as_deref(),
+        dump_time,
+        HELP,
+    )?;
+    let window = resolve_time_window(
+        &waveform,
+        args.scope.as_deref(),
+        clock.as_ref(),
+        dump_time,
+        HELP,
+        args.from.as_deref(),
+        args.to.as_deref(),
+        &mut diagnostics,
+    )?;
+    let from_raw = match window.from.as_deref() {
         Some(value) => parse_bound_time(value, "--from", dump_time, &metadata, HELP)?,
         None => dump_start_raw,
     };
-    let to_raw = match args.to.as_deref() {
+    let to_raw = match window.to.as_deref() {
         Some(value) => parse_bound_time(value, "--to", dump_time, &metadata, HELP)?,
         None => dump_end_raw,
     };
@@ -888,7 +929,8 @@ fn run_with_sink<S: AhbEventSink + ?Sized>(
                     timestamp,
                     dump_start_raw,
                     dump_end_raw,
-                    dump_time.dump_tick,
+                    sample_offset,
+                    time_format,
                 )? {
                     walker.process_edge(time.as_str(), sample_time.as_str(), &samples, false);
                 }
@@ -916,13 +958,18 @@ fn run_with_sink<S: AhbEventSink + ?Sized>(
                 timestamp,
                 dump_start_raw,
                 dump_end_raw,
-                dump_time.dump_tick,
+                sample_offset,
+                time_format,
             )?
             else {
                 return Ok(std::ops::ControlFlow::Continue(()));
             };
             let events = walker.process_edge(time.as_str(), sample_time.as_str(), &samples, true);
-            for event in events {
+            let cycle = clock.as_ref().and_then(|clock| clock.cycle_at(timestamp));
+            for mut event in events {
+                event.tick = time_format.tick(timestamp);
+                event.cycle = cycle;
+                event.raw_time = timestamp;
                 if max.is_some_and(|limit| emitted == limit) {
                     truncated = true;
                     return Ok(std::ops::ControlFlow::Break(()));
@@ -1117,6 +1164,7 @@ fn candidate_chunk_raw_span(dump_tick_zs: u128) -> u64 {
     u64::try_from(raw_span).unwrap_or(u64::MAX).max(1)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn sample_rising_edge(
     waveform: &SharedWaveform,
     plan: &Arc<SamplePlan>,
@@ -1124,7 +1172,8 @@ fn sample_rising_edge(
     timestamp: u64,
     dump_start_raw: u64,
     dump_end_raw: u64,
-    dump_tick: crate::engine::time::ParsedTime,
+    sample_offset: u64,
+    time_format: TimeFormat,
 ) -> Result<Option<(String, String, EdgeSamples)>, WavepeekError> {
     let previous_timestamp = waveform.borrow().previous_sample_time(timestamp);
     let frame = EventEvalFrame {
@@ -1135,15 +1184,14 @@ fn sample_rising_edge(
     if !event_expr_matches(clock.source.as_str(), &clock.event, &clock.host, &frame)? {
         return Ok(None);
     }
-    let Some(sample_timestamp) = timestamp
-        .checked_sub(1)
-        .filter(|value| *value >= dump_start_raw && *value <= dump_end_raw)
+    let Some(sample_timestamp) = sample_time_before(timestamp, sample_offset, dump_start_raw)
+        .filter(|value| *value <= dump_end_raw)
     else {
         return Ok(None);
     };
     Ok(Some((
-        format_raw_timestamp(timestamp, dump_tick)?,
-        format_raw_timestamp(sample_timestamp, dump_tick)?,
+        time_format.format(timestamp)?,
+        time_format.format(sample_timestamp)?,
         sample_edge(waveform, plan, sample_timestamp)?,
     )))
 }
@@ -51,6 +51,13 @@ pub struct ApbEventPayload {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ApbEvent {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Dump tick of `time`, which `--html` strips are centered on.
+    #[serde(skip)]
+    pub raw_time: u64,
     pub sample_time: String,
     pub profile: String,
     pub event: String,
@@ -184,6 +191,9 @@ impl<S: ApbEventSink + ?Sized> ExtractRowSink for GenericToApbSink<'_, S> {
 
         self.sink.emit(ApbEvent {
             time: row.time,
+            tick: row.tick,
+            cycle: row.cycle,
+            raw_time: row.raw_time,
             sample_time: row.sample_time,
             profile: self.context.profile.clone(),
             event: event_kind,
@@ -487,7 +497,12 @@ fn run_with_sink<S: ApbEventSink + ?Sized>(
             from: args.from,
             to: args.to,
             scope: args.scope,
+            clock: args.clock,
+            sample_mode: args.sample_mode,
+            time_unit: args.time_unit,
+            ticks: args.ticks,
             max: args.max,
+            layout: None,
         },
         plan,
         waveform,
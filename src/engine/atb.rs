@@ -71,6 +71,13 @@ impl AtbEventKind {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AtbEvent {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Dump tick of `time`, which `--html` strips are centered on.
+    #[serde(skip)]
+    pub raw_time: u64,
     pub sample_time: String,
     pub profile: String,
     pub event: AtbEventKind,
@@ -187,6 +194,9 @@ impl<S: AtbEventSink + ?Sized> ExtractRowSink for GenericToAtbSink<'_, S> {
             .collect();
         self.sink.emit(AtbEvent {
             time: row.time,
+            tick: row.tick,
+            cycle: row.cycle,
+            raw_time: row.raw_time,
             sample_time: row.sample_time,
             profile: self.context.profile.clone(),
             event,
@@ -354,7 +364,12 @@ fn run_with_sink<S: AtbEventSink + ?Sized>(
             from: args.from,
             to: args.to,
             scope: args.scope,
+            clock: args.clock,
+            sample_mode: args.sample_mode,
+            time_unit: args.time_unit,
+            ticks: args.ticks,
             max: args.max,
+            layout: None,
         },
         plan,
         waveform,
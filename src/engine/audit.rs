@@ -0,0 +1,230 @@
+use serde::Serialize;
+
+use crate::cli::audit::AuditArgs;
+use crate::debug_trace::DebugTrace;
+use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::expr_runtime::{
+    SharedWaveform, named_event_match_times, open_shared_waveform_traced,
+};
+use crate::engine::extract::{initial_diagnostics, max_entries, truncate_rows};
+use crate::engine::time::TimeFormat;
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::value_format::format_verilog_literal;
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::waveform::SignalTransitions;
+
+const HELP: &str = "wavepeek audit";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditRow {
+    /// Sample time, `--cycles` clock events after reset deassertion.
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    /// `--clock` cycle of `time`, counted from the clock's first edge in the dump.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Canonical path of the scope that declares the signal; rows are grouped by it.
+    pub scope: String,
+    pub name: String,
+    pub path: String,
+    /// Sampled value, `None` when the signal has no value yet.
+    pub value: Option<String>,
+    /// Bits that are not `0` or `1` at the sample time.
+    pub unknown_bits: u32,
+    /// First time after the sample with only `0`/`1` bits, `None` when that never happens.
+    pub first_known: Option<String>,
+}
+
+pub fn run(args: AuditArgs) -> Result<CommandResult, WavepeekError> {
+    let output_mode = crate::output_mode::OutputMode::from_json_flags(args.json, args.jsonl);
+    let max_entries = max_entries(&args.max)?;
+    let mut diagnostics = initial_diagnostics(&args.max);
+
+    let debug = DebugTrace::for_command(CommandName::Audit);
+    let waveform = open_shared_waveform_traced(args.waves.as_path(), &debug)?;
+
+    let scope = args.scope.as_str();
+    let signals = waveform
+        .borrow()
+        .signals_in_scope_recursive(scope, None)?
+        .into_iter()
+        .filter(|signal| signal.width.is_some())
+        .collect::<Vec<_>>();
+
+    let RawTimeWindow {
+        dump_time,
+        dump_end_raw,
+        from_raw,
+        to_raw,
+        clock,
+        ..
+    } = resolve_raw_window(
+        &waveform,
+        Some(scope),
+        Some(args.clock.as_str()),
+        HELP,
+        args.from.as_deref(),
+        args.to.as_deref(),
+        &debug,
+        &mut diagnostics,
+    )?;
+    debug.event("time.parse.done", || serde_json::json!({}));
+
+    let reset_raw = event_times(&waveform, scope, "--reset", &args.reset, from_raw, to_raw)?
+        .first()
+        .copied()
+        .ok_or_else(|| {
+            WavepeekError::Args(format!(
+                "--reset '{}' matched no events in the selected time range. See '{HELP} --help'.",
+                args.reset
+            ))
+        })?;
+    let time_format = TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks);
+    let reset_time = time_format.format(reset_raw)?;
+    let sample_raw = if args.cycles == 0 {
+        reset_raw
+    } else {
+        let clock_events =
+            event_times(&waveform, scope, "--clock", &args.clock, reset_raw, to_raw)?;
+        let after_reset = &clock_events[clock_events.partition_point(|&time| time <= reset_raw)..];
+        *after_reset.get(args.cycles - 1).ok_or_else(|| {
+            WavepeekError::Args(format!(
+                "--clock '{}' matched {} events after reset at {} in the selected time range, fewer than --cycles {}. See '{HELP} --help'.",
+                args.clock,
+                after_reset.len(),
+                reset_time,
+                args.cycles
+            ))
+        })?
+    };
+    let sample_time = time_format.format(sample_raw)?;
+    let sample_cycle = clock.as_ref().and_then(|clock| clock.cycle_at(sample_raw));
+    debug.event(
+        "audit.sample_time.done",
+        || serde_json::json!({"reset": reset_time, "sample": sample_time}),
+    );
+
+    let paths = signals
+        .iter()
+        .map(|signal| signal.path.clone())
+        .collect::<Vec<_>>();
+    let resolved = waveform.borrow().resolve_signals(&paths)?;
+    let sampled = waveform
+        .borrow_mut()
+        .sample_resolved_optional(&resolved, sample_raw)?;
+    let unknown = signals
+        .iter()
+        .zip(&resolved)
+        .zip(sampled)
+        .filter(|(_, sample)| sample.bits.as_deref().is_none_or(has_unknown_bits))
+        .collect::<Vec<_>>();
+    diagnostics.push(Diagnostic::info(format!(
+        "sampled {} signal(s) under '{scope}' at {sample_time}, {} --clock event(s) after reset at {reset_time}",
+        signals.len(),
+        args.cycles
+    )));
+
+    let unknown_resolved = unknown
+        .iter()
+        .map(|((_, resolved), _)| (*resolved).clone())
+        .collect::<Vec<_>>();
+    let histories =
+        waveform
+            .borrow_mut()
+            .signal_transitions(&unknown_resolved, sample_raw, dump_end_raw)?;
+    let mut rows = Vec::with_capacity(unknown.len());
+    for (((signal, resolved), sample), history) in unknown.into_iter().zip(&histories) {
+        let bits = sample.bits.as_deref();
+        rows.push(AuditRow {
+            time: sample_time.clone(),
+            tick: time_format.tick(sample_raw),
+            cycle: sample_cycle,
+            scope: declaring_scope(signal.path.as_str(), signal.name.as_str(), scope),
+            name: signal.name.clone(),
+            path: signal.path.clone(),
+            value: bits.map(|bits| format_verilog_literal(resolved.width, bits)),
+            unknown_bits: bits.map_or(resolved.width, |bits| {
+                bits.chars().filter(|bit| is_unknown_bit(*bit)).count() as u32
+            }),
+            first_known: first_known_time(history)
+                .map(|time| time_format.format(time))
+                .transpose()?,
+        });
+    }
+    rows.sort_by(|left, right| {
+        left.scope
+            .cmp(&right.scope)
+            .then_with(|| left.path.cmp(&right.path))
+    });
+    debug.event(
+        "audit.scan.done",
+        || serde_json::json!({"rows": rows.len()}),
+    );
+
+    if rows.is_empty() {
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::EmptyResult,
+            format!("no signals hold x or z bits at {sample_time}"),
+        ));
+    }
+    truncate_rows(&mut rows, max_entries, &mut diagnostics);
+
+    Ok(CommandResult {
+        command: CommandName::Audit,
+        output_mode,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: args.abs,
+        },
+        data: CommandData::Audit(rows),
+        diagnostics,
+    })
+}
+
+/// Match times of a named event; `*` is rejected because it has no signal to follow.
+fn event_times(
+    waveform: &SharedWaveform,
+    scope: &str,
+    flag: &str,
+    source: &str,
+    from_raw: u64,
+    to_raw: u64,
+) -> Result<Vec<u64>, WavepeekError> {
+    named_event_match_times(waveform, Some(scope), source, from_raw, to_raw)?.ok_or_else(|| {
+        WavepeekError::Args(format!(
+            "{flag} '{source}' must name its signal instead of using '*'. See '{HELP} --help'."
+        ))
+    })
+}
+
+fn is_unknown_bit(bit: char) -> bool {
+    !matches!(bit, '0' | '1')
+}
+
+fn has_unknown_bits(bits: &str) -> bool {
+    bits.chars().any(is_unknown_bit)
+}
+
+/// First change after the sample whose settled value has only `0`/`1` bits.
+fn first_known_time(history: &SignalTransitions) -> Option<u64> {
+    history
+        .changes
+        .iter()
+        .find(|change| {
+            change
+                .values
+                .last()
+                .is_some_and(|bits| !has_unknown_bits(bits))
+        })
+        .map(|change| change.time)
+}
+
+/// Scope part of `path`; signals listed directly under the audited scope fall back to it.
+fn declaring_scope(path: &str, name: &str, audited_scope: &str) -> String {
+    path.strip_suffix(name)
+        .and_then(|prefix| prefix.strip_suffix('.'))
+        .unwrap_or(audited_scope)
+        .to_string()
+}
@@ -44,6 +44,13 @@ pub struct AxiTransferPayload {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AxiTransfer {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Dump tick of `time`, which `--html` strips are centered on.
+    #[serde(skip)]
+    pub raw_time: u64,
     pub sample_time: String,
     pub profile: String,
     pub channel: String,
@@ -159,6 +166,9 @@ impl<S: AxiTransferSink + ?Sized> ExtractRowSink for GenericToAxiSink<'_, S> {
             .collect();
         self.sink.emit(AxiTransfer {
             time: row.time,
+            tick: row.tick,
+            cycle: row.cycle,
+            raw_time: row.raw_time,
             sample_time: row.sample_time,
             profile: self.context.profile.clone(),
             channel: row.source,
@@ -1326,7 +1336,12 @@ fn run_with_sink<S: AxiTransferSink + ?Sized>(
             from: args.from,
             to: args.to,
             scope: args.scope,
+            clock: args.clock,
+            sample_mode: args.sample_mode,
+            time_unit: args.time_unit,
+            ticks: args.ticks,
             max: args.max,
+            layout: None,
         },
         plan,
         waveform,
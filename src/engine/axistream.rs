@@ -50,6 +50,13 @@ pub struct AxiStreamTransferPayload {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AxiStreamTransfer {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Dump tick of `time`, which `--html` strips are centered on.
+    #[serde(skip)]
+    pub raw_time: u64,
     pub sample_time: String,
     pub profile: String,
     pub payload: Vec<AxiStreamTransferPayload>,
@@ -157,6 +164,9 @@ impl<S: AxiStreamTransferSink + ?Sized> ExtractRowSink for GenericToAxiStreamSin
             .collect();
         self.sink.emit(AxiStreamTransfer {
             time: row.time,
+            tick: row.tick,
+            cycle: row.cycle,
+            raw_time: row.raw_time,
             sample_time: row.sample_time,
             profile: self.context.profile.clone(),
             payload,
@@ -315,7 +325,12 @@ fn run_with_sink<S: AxiStreamTransferSink + ?Sized>(
             from: args.from,
             to: args.to,
             scope: args.scope,
+            clock: args.clock,
+            sample_mode: args.sample_mode,
+            time_unit: args.time_unit,
+            ticks: args.ticks,
             max: args.max,
+            layout: None,
         },
         plan,
         waveform,
@@ -0,0 +1,385 @@
+use serde::Serialize;
+
+use crate::cli::cdc::CdcArgs;
+use crate::debug_trace::DebugTrace;
+use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
+use crate::engine::expr_runtime::{
+    SharedWaveform, named_event_match_times, open_shared_waveform_traced,
+};
+use crate::engine::extract::{initial_diagnostics, max_entries, truncate_rows};
+use crate::engine::signal_pattern::{ExpandedSignal, resolve_signal_tokens};
+use crate::engine::time::{TimeFormat, duration_ticks};
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::value_format::format_verilog_literal;
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::waveform::SignalTransitions;
+
+const HELP: &str = "wavepeek cdc";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CdcHazardKind {
+    /// A crossing signal changed within `--window` of a destination edge.
+    Window,
+    /// A multi-bit crossing signal changed more than one bit between destination samples.
+    MultiBit,
+}
+
+impl std::fmt::Display for CdcHazardKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Window => f.write_str("window"),
+            Self::MultiBit => f.write_str("multi_bit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CdcHazard {
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    /// `--clock` cycle of `time`, `None` without `--clock` or before its first edge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    pub kind: CdcHazardKind,
+    #[serde(skip_serializing)]
+    pub display: String,
+    pub path: String,
+    /// Settled value before the change, `None` before the first recorded value.
+    pub previous: Option<String>,
+    pub value: String,
+    pub dst_edge: String,
+    /// Signed distance from `dst_edge` to a `window` change, e.g. `-200ps`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<String>,
+    /// Bits that differ between two destination samples of a `multi_bit` hazard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bits_changed: Option<u32>,
+    /// Latest `--src-clock` edge at or before the change that launched the value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_edge: Option<String>,
+}
+
+pub fn run(args: CdcArgs) -> Result<CommandResult, WavepeekError> {
+    let output_mode = crate::output_mode::OutputMode::from_json_flags(args.json, args.jsonl);
+    let max_entries = max_entries(&args.max)?;
+    let mut diagnostics = initial_diagnostics(&args.max);
+
+    let debug = DebugTrace::for_command(CommandName::Cdc);
+    let waveform = open_shared_waveform_traced(args.waves.as_path(), &debug)?;
+    let RawTimeWindow {
+        dump_time,
+        dump_start_raw,
+        dump_end_raw,
+        from_raw,
+        to_raw,
+        clock,
+        ..
+    } = resolve_raw_window(
+        &waveform,
+        args.scope.as_deref(),
+        args.clock.as_deref(),
+        HELP,
+        args.from.as_deref(),
+        args.to.as_deref(),
+        &debug,
+        &mut diagnostics,
+    )?;
+    let window_ticks = duration_ticks(args.window.as_str(), dump_time).map_err(|reason| {
+        WavepeekError::Args(format!(
+            "invalid --window '{}': {reason}. See '{HELP} --help'.",
+            args.window
+        ))
+    })?;
+    debug.event("time.parse.done", || serde_json::json!({}));
+
+    let signals = resolve_signal_tokens(
+        &waveform.borrow(),
+        args.scope.as_deref(),
+        &args.signals,
+        HELP,
+        &mut diagnostics,
+    )?;
+    let dst_edges = clock_edges(
+        &waveform,
+        args.scope.as_deref(),
+        "--dst-clock",
+        args.dst_clock.as_str(),
+        from_raw.saturating_sub(window_ticks).max(dump_start_raw),
+        to_raw.saturating_add(window_ticks).min(dump_end_raw),
+    )?;
+    if dst_edges.is_empty() {
+        return Err(WavepeekError::Args(format!(
+            "--dst-clock '{}' matched no edges in the selected time range. See '{HELP} --help'.",
+            args.dst_clock
+        )));
+    }
+    let src_edges = clock_edges(
+        &waveform,
+        args.scope.as_deref(),
+        "--src-clock",
+        args.src_clock.as_str(),
+        dump_start_raw,
+        to_raw,
+    )?;
+    debug.event(
+        "cdc.clocks.done",
+        || serde_json::json!({"dst_edges": dst_edges.len(), "src_edges": src_edges.len()}),
+    );
+
+    let paths = signals
+        .iter()
+        .map(|signal| signal.path.clone())
+        .collect::<Vec<_>>();
+    let resolved = waveform.borrow().resolve_signals(&paths)?;
+    let histories = waveform
+        .borrow_mut()
+        .signal_transitions(&resolved, dump_start_raw, to_raw)?;
+
+    let checker = HazardChecker {
+        dst_edges: &dst_edges,
+        src_edges: &src_edges,
+        window_ticks,
+        start_raw: dump_start_raw,
+        from_raw,
+        to_raw,
+        time_format: TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks),
+        clock: clock.as_ref(),
+    };
+    let mut found = Vec::new();
+    for ((signal, resolved), history) in signals.iter().zip(&resolved).zip(&histories) {
+        checker.window_hazards(signal, resolved.width, history, &mut found)?;
+        if resolved.width > 1 {
+            checker.multi_bit_hazards(signal, resolved.width, history, &mut found)?;
+        }
+    }
+    found.sort_by(|(left_time, left), (right_time, right)| {
+        left_time
+            .cmp(right_time)
+            .then_with(|| left.path.cmp(&right.path))
+            .then_with(|| left.kind.cmp(&right.kind))
+    });
+    let mut rows = found.into_iter().map(|(_, row)| row).collect::<Vec<_>>();
+    debug.event("cdc.check.done", || serde_json::json!({"rows": rows.len()}));
+
+    if rows.is_empty() {
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::EmptyResult,
+            "no CDC hazards found in selected time range",
+        ));
+    }
+    truncate_rows(&mut rows, max_entries, &mut diagnostics);
+
+    Ok(CommandResult {
+        command: CommandName::Cdc,
+        output_mode,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: args.abs,
+        },
+        data: CommandData::Cdc(rows),
+        diagnostics,
+    })
+}
+
+/// Edge times of a named clock event; `*` is rejected because it has no clock signal.
+fn clock_edges(
+    waveform: &SharedWaveform,
+    scope: Option<&str>,
+    flag: &str,
+    source: &str,
+    from_raw: u64,
+    to_raw: u64,
+) -> Result<Vec<u64>, WavepeekError> {
+    named_event_match_times(waveform, scope, source, from_raw, to_raw)?.ok_or_else(|| {
+        WavepeekError::Args(format!(
+            "{flag} '{source}' must name its clock signal instead of using '*'. See '{HELP} --help'."
+        ))
+    })
+}
+
+/// Per-signal hazard scan over destination edges collected around the selected range.
+struct HazardChecker<'a> {
+    dst_edges: &'a [u64],
+    src_edges: &'a [u64],
+    window_ticks: u64,
+    start_raw: u64,
+    from_raw: u64,
+    to_raw: u64,
+    time_format: TimeFormat,
+    clock: Option<&'a CycleClock>,
+}
+
+impl HazardChecker<'_> {
+    /// Settled changes inside the range whose nearest destination edge is within the window.
+    fn window_hazards(
+        &self,
+        signal: &ExpandedSignal,
+        width: u32,
+        history: &SignalTransitions,
+        found: &mut Vec<(u64, CdcHazard)>,
+    ) -> Result<(), WavepeekError> {
+        let mut previous = history.initial.as_deref();
+        for change in &history.changes {
+            let Some(value) = change.values.last().map(String::as_str) else {
+                continue;
+            };
+            let before = previous.replace(value);
+            if before == Some(value) || change.time < self.from_raw || change.time > self.to_raw {
+                continue;
+            }
+            let Some(edge) = nearest_edge(self.dst_edges, change.time) else {
+                continue;
+            };
+            let distance = change.time.abs_diff(edge);
+            if distance > self.window_ticks {
+                continue;
+            }
+            let sign = if change.time < edge { "-" } else { "" };
+            found.push((
+                change.time,
+                CdcHazard {
+                    time: self.time_format.format(change.time)?,
+                    tick: self.time_format.tick(change.time),
+                    cycle: self.clock.and_then(|clock| clock.cycle_at(change.time)),
+                    kind: CdcHazardKind::Window,
+                    display: signal.display.clone(),
+                    path: signal.path.clone(),
+                    previous: before.map(|bits| format_verilog_literal(width, bits)),
+                    value: format_verilog_literal(width, value),
+                    dst_edge: self.time_format.format(edge)?,
+                    offset: Some(format!("{sign}{}", self.time_format.format(distance)?)),
+                    bits_changed: None,
+                    src_edge: self.src_edge_at(change.time)?,
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Destination edges whose pre-edge sample differs from the previous one in several bits.
+    fn multi_bit_hazards(
+        &self,
+        signal: &ExpandedSignal,
+        width: u32,
+        history: &SignalTransitions,
+        found: &mut Vec<(u64, CdcHazard)>,
+    ) -> Result<(), WavepeekError> {
+        for pair in self.dst_edges.windows(2) {
+            let (previous_edge, edge) = (pair[0], pair[1]);
+            if edge < self.from_raw || edge > self.to_raw || previous_edge == 0 {
+                continue;
+            }
+            let (Some((_, before)), Some((changed_at, after))) = (
+                settled_at(history, self.start_raw, previous_edge - 1),
+                settled_at(history, self.start_raw, edge - 1),
+            ) else {
+                continue;
+            };
+            let bits_changed = differing_bits(before, after);
+            if bits_changed <= 1 || before.len() != after.len() {
+                continue;
+            }
+            found.push((
+                edge,
+                CdcHazard {
+                    time: self.time_format.format(edge)?,
+                    tick: self.time_format.tick(edge),
+                    cycle: self.clock.and_then(|clock| clock.cycle_at(edge)),
+                    kind: CdcHazardKind::MultiBit,
+                    display: signal.display.clone(),
+                    path: signal.path.clone(),
+                    previous: Some(format_verilog_literal(width, before)),
+                    value: format_verilog_literal(width, after),
+                    dst_edge: self.time_format.format(edge)?,
+                    offset: None,
+                    bits_changed: Some(bits_changed),
+                    src_edge: self.src_edge_at(changed_at)?,
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    fn src_edge_at(&self, raw_time: u64) -> Result<Option<String>, WavepeekError> {
+        let edges_at_or_before = self.src_edges.partition_point(|edge| *edge <= raw_time);
+        edges_at_or_before
+            .checked_sub(1)
+            .map(|index| self.time_format.format(self.src_edges[index]))
+            .transpose()
+    }
+}
+
+/// Closest edge to `raw_time`; on a tie the later edge wins, so a change is read as setup-side.
+fn nearest_edge(edges: &[u64], raw_time: u64) -> Option<u64> {
+    let index = edges.partition_point(|edge| *edge < raw_time);
+    let after = edges.get(index).copied();
+    let before = index.checked_sub(1).map(|index| edges[index]);
+    match (before, after) {
+        (Some(before), Some(after)) if raw_time - before < after - raw_time => Some(before),
+        (_, Some(after)) => Some(after),
+        (before, None) => before,
+    }
+}
+
+/// Settled value at `raw_time` plus the time it was recorded; `None` before the first value.
+///
+/// `start_raw` is the window start the history was loaded from, reported for its initial value.
+fn settled_at(history: &SignalTransitions, start_raw: u64, raw_time: u64) -> Option<(u64, &str)> {
+    let changes_at_or_before = history
+        .changes
+        .partition_point(|change| change.time <= raw_time);
+    match changes_at_or_before.checked_sub(1) {
+        Some(index) => {
+            let change = &history.changes[index];
+            change
+                .values
+                .last()
+                .map(|value| (change.time, value.as_str()))
+        }
+        None => history.initial.as_deref().map(|value| (start_raw, value)),
+    }
+}
+
+fn differing_bits(before: &str, after: &str) -> u32 {
+    let count = before
+        .chars()
+        .zip(after.chars())
+        .filter(|(left, right)| left != right)
+        .count();
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{differing_bits, nearest_edge, settled_at};
+    use crate::waveform::{SignalTransitions, ValueTransition};
+
+    #[test]
+    fn nearest_edge_prefers_the_later_edge_on_ties() {
+        let edges = [10, 20, 30];
+        assert_eq!(nearest_edge(&edges, 4), Some(10));
+        assert_eq!(nearest_edge(&edges, 14), Some(10));
+        assert_eq!(nearest_edge(&edges, 15), Some(20));
+        assert_eq!(nearest_edge(&edges, 30), Some(30));
+        assert_eq!(nearest_edge(&edges, 99), Some(30));
+        assert_eq!(nearest_edge(&[], 5), None);
+    }
+
+    #[test]
+    fn settled_at_reads_the_last_value_at_or_before_a_time() {
+        let history = SignalTransitions {
+            initial: Some("00".to_string()),
+            changes: vec![ValueTransition {
+                time: 10,
+                values: vec!["01".to_string(), "11".to_string()],
+            }],
+        };
+        assert_eq!(settled_at(&history, 2, 9), Some((2, "00")));
+        assert_eq!(settled_at(&history, 2, 10), Some((10, "11")));
+        assert_eq!(differing_bits("0011", "0101"), 2);
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 use serde::Serialize;
 
@@ -7,24 +8,37 @@ use crate::cli::limits::LimitArg;
 use crate::cli::sampling::SampleMode;
 use crate::debug_trace::DebugTrace;
 use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
 use crate::engine::expr_runtime::{
-    SharedWaveform, bind_waveform_event_expr, candidate_sources_for_handles,
-    event_candidate_handles, event_expr_contains_wildcard, event_expr_is_any_tracked_only,
-    event_expr_is_edge_only, event_expr_matches, open_shared_waveform,
+    SharedWaveform, bind_host_event_expr, candidate_sources_for_handles,
+    collect_event_candidate_times, event_candidate_handles, event_expr_contains_wildcard,
+    event_expr_has_time_terms, event_expr_is_any_tracked_only, event_expr_is_edge_only,
+    event_expr_is_sampled_only, event_expr_matches, open_shared_waveform,
 };
+use crate::engine::sample_point::{
+    edge_only_sample_mode_message, edge_sample_offset, sample_time_before,
+};
+use crate::engine::signal_layout::{SignalLayout, SignalLayouts};
+use crate::engine::signal_pattern::SignalPatternExpander;
+use crate::engine::signal_select::{BitSelect, resolve_selected_signal};
 use crate::engine::time::{
-    DumpTimeContext, ParsedTime, TimeValidationError, format_raw_timestamp,
+    DumpTimeContext, ParsedTime, TimeFormat, TimeValidationError, format_raw_timestamp,
     parse_dump_time_context, validate_time_token_to_raw,
 };
+use crate::engine::time_expr::resolve_time_window;
+use crate::engine::value::ValueFields;
 use crate::engine::value_format::format_verilog_literal;
+use crate::engine::view::{WaveData, WaveTrace};
 use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
 use crate::error::WavepeekError;
 use crate::expr::{
     BoundEventExpr, EventEvalFrame, ExprTypeKind, ExpressionHost, SampledValue, SignalHandle,
 };
+use crate::output::{MIN_WAVE_WIDTH, terminal_columns, wave_plot_columns};
 use crate::waveform::{
     ChangeCandidateCollectionMode, ExprResolvedSignal, ResolvedSignal, SampledSignalState,
-    SignalId, SignalOffsetData, Waveform, expr_host::WaveformExprHost,
+    SignalId, SignalOffsetData, Waveform,
+    expr_host::{PackedFieldSource, WaveformExprHost},
     should_emit_delta_and_update_baseline,
 };
 
@@ -34,7 +48,6 @@ const AUTO_FUSED_MIN_ESTIMATED_WORK: usize = 100_000;
 const AUTO_EDGE_ONLY_MIN_ESTIMATED_WORK: usize = 500_000;
 const AUTO_EDGE_FAST_MIN_ESTIMATED_WORK: usize = 2_000_000;
 const AUTO_FUSED_WIDE_SIGNAL_CUTOFF: usize = 32;
-const PRE_EDGE_REQUIRES_EDGE_ONLY_ON: &str = "--sample-mode pre-edge requires --on with only edge event terms (posedge, negedge, or edge); use --sample-mode native for wildcard or plain signal triggers";
 const AUTO_EDGE_ONLY_MIN_REQUESTED_SIGNALS: usize = 10;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,12 +62,24 @@ pub struct ChangeSignalValue {
     pub display: String,
     pub path: String,
     pub value: String,
+    /// Field values decoded from `value` by a `--layout` entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<ValueFields>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ChangeSnapshot {
     pub time: String,
+    /// Raw dump tick of `time`, so row timing is applied without re-parsing `time`.
+    #[serde(skip_serializing)]
+    pub raw_time: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
     pub sample_time: String,
+    #[serde(skip_serializing)]
+    pub sample_raw_time: u64,
     pub signals: Vec<ChangeSignalValue>,
 }
 
@@ -62,6 +87,8 @@ pub struct ChangeSnapshot {
 struct RequestedSignal {
     display: String,
     path: String,
+    select: Option<BitSelect>,
+    layout: Option<SignalLayout>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -135,6 +162,36 @@ impl<W: std::io::Write> ChangeSnapshotSink for JsonlChangeSink<'_, W> {
     }
 }
 
+/// Applies `--clock`, `--time-unit`, and `--ticks` to dump-unit rows, so engines stay unaware of them.
+struct RowTimingChangeSink<'a, S: ChangeSnapshotSink + ?Sized> {
+    inner: &'a mut S,
+    clock: Option<&'a CycleClock>,
+    time_format: TimeFormat,
+}
+
+impl<S: ChangeSnapshotSink + ?Sized> RowTimingChangeSink<'_, S> {
+    fn is_passthrough(&self) -> bool {
+        self.clock.is_none() && self.time_format.is_dump_default()
+    }
+}
+
+impl<S: ChangeSnapshotSink + ?Sized> ChangeSnapshotSink for RowTimingChangeSink<'_, S> {
+    fn start(&mut self) -> Result<(), WavepeekError> {
+        self.inner.start()
+    }
+
+    fn emit(&mut self, mut snapshot: ChangeSnapshot) -> Result<(), WavepeekError> {
+        if !self.is_passthrough() {
+            let raw = snapshot.raw_time;
+            snapshot.time = self.time_format.format(raw)?;
+            snapshot.tick = self.time_format.tick(raw);
+            snapshot.cycle = self.clock.and_then(|clock| clock.cycle_at(raw));
+            snapshot.sample_time = self.time_format.format(snapshot.sample_raw_time)?;
+        }
+        self.inner.emit(snapshot)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct RollingSignalState {
     offset: Option<SignalOffsetData>,
@@ -186,6 +243,10 @@ impl ExpressionHost for FastEventEvalHost<'_> {
     ) -> Result<bool, crate::expr::ExprDiagnostic> {
         self.base.event_occurred(handle, timestamp)
     }
+
+    fn is_dump_timestamp(&self, timestamp: u64) -> bool {
+        self.base.is_dump_timestamp(timestamp)
+    }
 }
 
 #[derive(Default)]
@@ -255,18 +316,108 @@ fn indexed_timestamps(waveform: &Waveform) -> Result<&[u64], WavepeekError> {
 
 pub fn run(args: ChangeArgs) -> Result<CommandResult, WavepeekError> {
     let output_mode = crate::output_mode::OutputMode::from_json_flags(args.json, args.jsonl);
+    let wave = if args.wave {
+        let width = args.width.unwrap_or_else(terminal_columns);
+        if width < MIN_WAVE_WIDTH {
+            return Err(WavepeekError::Args(format!(
+                "--width must be at least {MIN_WAVE_WIDTH} columns. See 'wavepeek change --help'."
+            )));
+        }
+        Some((width, args.ascii))
+    } else {
+        None
+    };
     let mut sink = CollectingChangeSink::default();
-    let outcome = run_with_sink(args, &mut sink)?;
+    let mut outcome = run_with_sink(args, &mut sink)?;
+
+    let data = match wave {
+        Some((width, ascii)) => CommandData::Wave(snapshots_to_wave(
+            sink.snapshots,
+            width,
+            ascii,
+            outcome.human_options.signals_abs,
+            &mut outcome.diagnostics,
+        )),
+        None => CommandData::Change(sink.snapshots),
+    };
 
     Ok(CommandResult {
         command: CommandName::Change,
         output_mode,
         human_options: outcome.human_options,
-        data: CommandData::Change(sink.snapshots),
+        data,
         diagnostics: outcome.diagnostics,
     })
 }
 
+/// Turns snapshot rows into event-indexed wave columns, dropping rows that do not fit `width`.
+fn snapshots_to_wave(
+    mut snapshots: Vec<ChangeSnapshot>,
+    width: usize,
+    ascii: bool,
+    abs: bool,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> WaveData {
+    let mut traces = snapshots
+        .first()
+        .map(|snapshot| {
+            snapshot
+                .signals
+                .iter()
+                .map(|signal| WaveTrace {
+                    display: signal.display.clone(),
+                    path: signal.path.clone(),
+                    width: literal_width(signal.value.as_str()),
+                    values: Vec::with_capacity(snapshots.len()),
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let labels = traces
+        .iter()
+        .map(|trace| {
+            if abs {
+                trace.path.as_str()
+            } else {
+                trace.display.as_str()
+            }
+        })
+        .collect::<Vec<_>>();
+    let columns = wave_plot_columns(&labels, width);
+    if snapshots.len() > columns {
+        snapshots.truncate(columns);
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::OutputTruncated,
+            format!(
+                "truncated --wave drawing to {columns} rows to fit {width} columns (use --width or narrow --from/--to)"
+            ),
+        ));
+    }
+
+    let mut times = Vec::with_capacity(snapshots.len());
+    for snapshot in snapshots {
+        times.push(snapshot.time);
+        for (trace, signal) in traces.iter_mut().zip(snapshot.signals) {
+            trace.values.push(Some(signal.value));
+        }
+    }
+
+    WaveData {
+        times,
+        traces,
+        width,
+        ascii,
+    }
+}
+
+fn literal_width(literal: &str) -> u32 {
+    literal
+        .split_once('\'')
+        .and_then(|(width, _)| width.parse().ok())
+        .unwrap_or(0)
+}
+
 pub fn run_jsonl<W: std::io::Write>(
     args: ChangeArgs,
     writer: &mut crate::output::JsonlWriter<W>,
@@ -319,9 +470,20 @@ fn run_with_sink<S: ChangeSnapshotSink + ?Sized>(
     let metadata = waveform.borrow().metadata()?;
     debug.event("metadata.load.done", || serde_json::json!({}));
 
+    let layouts = args
+        .layout
+        .as_deref()
+        .map(|path| SignalLayouts::load(path, "wavepeek change").map(Rc::new))
+        .transpose()?;
     let requested_signals = {
         let waveform_ref = waveform.borrow();
-        resolve_requested_signals(&waveform_ref, args.scope.as_deref(), &args)?
+        resolve_requested_signals(
+            &waveform_ref,
+            args.scope.as_deref(),
+            &args,
+            layouts.as_deref(),
+            &mut diagnostics,
+        )?
     };
     debug.event(
         "signal.list.done",
@@ -329,23 +491,42 @@ fn run_with_sink<S: ChangeSnapshotSink + ?Sized>(
     );
 
     let event_expr_source = args.on.as_str();
-    let (host, bound_event) =
-        bind_waveform_event_expr(waveform.clone(), args.scope.as_deref(), event_expr_source)?;
-    validate_sample_mode(args.sample_mode, &bound_event)?;
+    let host = WaveformExprHost::from_shared(waveform.clone())
+        .with_packed_fields(layouts.map(|layouts| layouts as Rc<dyn PackedFieldSource>));
+    let (host, bound_event) = bind_host_event_expr(host, args.scope.as_deref(), event_expr_source)?;
+    validate_sample_mode(&args.sample_mode, &bound_event)?;
     debug.event("expression.bind.done", || serde_json::json!({}));
 
     let dump_time = parse_dump_time_context(&metadata)?;
+    let sample_offset = edge_sample_offset(&args.sample_mode, dump_time, "wavepeek change")?;
     let dump_tick = dump_time.dump_tick;
     let dump_start_raw =
         u64::try_from(dump_time.dump_start_zs / dump_time.dump_tick_zs).map_err(|_| {
             WavepeekError::Internal("dump start timestamp exceeds supported range".to_string())
         })?;
 
-    let from_raw = match args.from.as_deref() {
+    let clock = CycleClock::load(
+        &waveform,
+        args.scope.as_deref(),
+        args.clock.as_deref(),
+        dump_time,
+        "wavepeek change",
+    )?;
+    let window = resolve_time_window(
+        &waveform,
+        args.scope.as_deref(),
+        clock.as_ref(),
+        dump_time,
+        "wavepeek change",
+        args.from.as_deref(),
+        args.to.as_deref(),
+        &mut diagnostics,
+    )?;
+    let from_raw = match window.from.as_deref() {
         Some(token) => parse_bound_time(token, "--from", dump_time, &metadata)?,
         None => dump_start_raw,
     };
-    let to_raw = match args.to.as_deref() {
+    let to_raw = match window.to.as_deref() {
         Some(token) => parse_bound_time(token, "--to", dump_time, &metadata)?,
         None => u64::try_from(dump_time.dump_end_zs / dump_time.dump_tick_zs).map_err(|_| {
             WavepeekError::Internal("dump end timestamp exceeds supported range".to_string())
@@ -407,14 +588,28 @@ fn run_with_sink<S: ChangeSnapshotSink + ?Sized>(
         candidate_sources.len(),
         requested_resolved.len(),
     );
-    let engine_mode = select_engine_mode(
-        args.tune_engine,
-        event_expr_is_any_tracked_only(&bound_event),
-        event_expr_is_edge_only(&bound_event),
-        requested_resolved.len(),
-        estimated_work,
-    );
-    let selected_engine_name = if args.sample_mode == SampleMode::PreEdge {
+    // The fused and edge-fast engines compare whole signal values at signal-change times, so bit
+    // selects and `every`/`at`/`timestamps` terms stay on baseline.
+    let engine_mode = if event_expr_has_time_terms(&bound_event)
+        || requested_signals
+            .iter()
+            .any(|signal| signal.select.is_some())
+    {
+        ChangeEngineMode::Baseline
+    } else {
+        select_engine_mode(
+            args.tune_engine,
+            event_expr_is_any_tracked_only(&bound_event),
+            event_expr_is_edge_only(&bound_event),
+            requested_resolved.len(),
+            estimated_work,
+        )
+    };
+    // Time terms need not land on signal changes, so native rows compare each trigger's sample
+    // with the previous trigger's, as edge-relative sampling does.
+    let trigger_sample_offset =
+        sample_offset.or(event_expr_has_time_terms(&bound_event).then_some(0));
+    let selected_engine_name = if trigger_sample_offset.is_some() {
         "pre-edge"
     } else {
         engine_mode.as_str()
@@ -432,8 +627,14 @@ fn run_with_sink<S: ChangeSnapshotSink + ?Sized>(
         "change.run.start",
         || serde_json::json!({"selected_engine": selected_engine_name}),
     );
+    let mut sink = RowTimingChangeSink {
+        inner: sink,
+        clock: clock.as_ref(),
+        time_format: TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks),
+    };
+    let sink = &mut sink;
     sink.start()?;
-    let stats = if args.sample_mode == SampleMode::PreEdge {
+    let stats = if let Some(sample_offset) = trigger_sample_offset {
         run_pre_edge_emit(
             &waveform,
             &host,
@@ -450,6 +651,7 @@ fn run_with_sink<S: ChangeSnapshotSink + ?Sized>(
             max_entries,
             candidate_mode,
             dump_start_raw,
+            sample_offset,
             sink,
         )?
     } else {
@@ -545,13 +747,13 @@ fn run_with_sink<S: ChangeSnapshotSink + ?Sized>(
 }
 
 fn validate_sample_mode(
-    sample_mode: SampleMode,
+    sample_mode: &SampleMode,
     bound_event: &BoundEventExpr,
 ) -> Result<(), WavepeekError> {
-    if sample_mode == SampleMode::PreEdge && !event_expr_is_edge_only(bound_event) {
-        return Err(WavepeekError::Args(
-            PRE_EDGE_REQUIRES_EDGE_ONLY_ON.to_string(),
-        ));
+    if *sample_mode != SampleMode::Native && !event_expr_is_sampled_only(bound_event) {
+        return Err(WavepeekError::Args(edge_only_sample_mode_message(
+            sample_mode,
+        )));
     }
     Ok(())
 }
@@ -572,12 +774,6 @@ fn candidate_mode_name(mode: ChangeCandidateCollectionMode) -> &'static str {
     }
 }
 
-fn pre_edge_sample_time(trigger_timestamp: u64, dump_start_raw: u64) -> Option<u64> {
-    trigger_timestamp
-        .checked_sub(1)
-        .filter(|query_time| *query_time >= dump_start_raw)
-}
-
 fn select_engine_mode(
     mode: TuneChangeEngineMode,
     any_tracked_only: bool,
@@ -692,14 +888,14 @@ fn run_baseline_emit<S: ChangeSnapshotSink + ?Sized>(
     let candidate_times = if let Some(precomputed_candidate_times) = precomputed_candidate_times {
         precomputed_candidate_times
     } else {
-        waveform
-            .borrow_mut()
-            .collect_expr_candidate_times_with_mode(
-                candidate_sources,
-                from_raw,
-                to_raw,
-                candidate_mode,
-            )?
+        collect_event_candidate_times(
+            waveform,
+            bound_event,
+            candidate_sources,
+            from_raw,
+            to_raw,
+            candidate_mode,
+        )?
     };
     let mut sample_cache = SampleCache::default();
     sample_cache.sample_requested_batch(waveform, requested_resolved, baseline_raw)?;
@@ -728,14 +924,8 @@ fn run_baseline_emit<S: ChangeSnapshotSink + ?Sized>(
             continue;
         }
 
-        let mut previous_values = previous_samples
-            .iter()
-            .map(|sample| sample.bits.clone())
-            .collect::<Vec<_>>();
-        let current_values = current_samples
-            .iter()
-            .map(|sample| sample.bits.clone())
-            .collect::<Vec<_>>();
+        let mut previous_values = compared_values(requested_signals, previous_samples.as_slice());
+        let current_values = compared_values(requested_signals, current_samples.as_slice());
 
         let should_emit =
             should_emit_delta_and_update_baseline(&mut previous_values, &current_values);
@@ -792,23 +982,21 @@ fn run_pre_edge_emit<S: ChangeSnapshotSink + ?Sized>(
     max_entries: Option<usize>,
     candidate_mode: ChangeCandidateCollectionMode,
     dump_start_raw: u64,
+    sample_offset: u64,
     sink: &mut S,
 ) -> Result<ChangeRunStats, WavepeekError> {
-    let candidate_times = waveform
-        .borrow_mut()
-        .collect_expr_candidate_times_with_mode(
-            candidate_sources,
-            from_raw,
-            to_raw,
-            candidate_mode,
-        )?;
+    let candidate_times = collect_event_candidate_times(
+        waveform,
+        bound_event,
+        candidate_sources,
+        from_raw,
+        to_raw,
+        candidate_mode,
+    )?;
     let mut sample_cache = SampleCache::default();
     let baseline_samples =
         sample_cache.sample_requested_batch(waveform, requested_resolved, baseline_raw)?;
-    let mut previous_values = baseline_samples
-        .iter()
-        .map(|sample| sample.bits.clone())
-        .collect::<Vec<_>>();
+    let mut previous_values = compared_values(requested_signals, baseline_samples.as_slice());
 
     let mut emitted = 0usize;
     let mut truncated = false;
@@ -827,16 +1015,13 @@ fn run_pre_edge_emit<S: ChangeSnapshotSink + ?Sized>(
             continue;
         }
 
-        let Some(sample_time) = pre_edge_sample_time(timestamp, dump_start_raw) else {
+        let Some(sample_time) = sample_time_before(timestamp, sample_offset, dump_start_raw) else {
             continue;
         };
 
         let current_samples =
             sample_cache.sample_requested_batch(waveform, requested_resolved, sample_time)?;
-        let current_values = current_samples
-            .iter()
-            .map(|sample| sample.bits.clone())
-            .collect::<Vec<_>>();
+        let current_values = compared_values(requested_signals, current_samples.as_slice());
         let should_emit =
             should_emit_delta_and_update_baseline(&mut previous_values, &current_values);
         if !should_emit {
@@ -1583,21 +1768,55 @@ fn build_snapshot(
                     requested.path
                 ))
             })?;
-            Ok(ChangeSignalValue {
-                display: requested.display.clone(),
-                path: requested.path.clone(),
-                value: format_verilog_literal(sampled.width, bits.as_str()),
+            Ok(match requested.select {
+                Some(select) => ChangeSignalValue {
+                    display: requested.display.clone(),
+                    path: format!("{}{}", requested.path, select.suffix()),
+                    value: format_verilog_literal(select.width(), select.slice(bits.as_str())),
+                    fields: None,
+                },
+                None => ChangeSignalValue {
+                    display: requested.display.clone(),
+                    path: requested.path.clone(),
+                    value: format_verilog_literal(sampled.width, bits.as_str()),
+                    fields: requested
+                        .layout
+                        .as_ref()
+                        .map(|layout| layout.decode(bits.as_str())),
+                },
             })
         })
         .collect::<Result<Vec<_>, WavepeekError>>()?;
 
     Ok(ChangeSnapshot {
         time: format_raw_timestamp(timestamp, dump_tick)?,
+        raw_time: timestamp,
+        tick: None,
+        cycle: None,
         sample_time: format_raw_timestamp(sample_timestamp, dump_tick)?,
+        sample_raw_time: sample_timestamp,
         signals,
     })
 }
 
+/// Values compared between samples; bit-selected signals only compare their slice.
+fn compared_values(
+    requested_signals: &[RequestedSignal],
+    samples: &[SampledSignalState],
+) -> Vec<Option<String>> {
+    requested_signals
+        .iter()
+        .zip(samples)
+        .map(|(requested, sample)| match requested.select {
+            Some(select) => sample
+                .bits
+                .as_deref()
+                .map(|bits| select.slice(bits).to_string()),
+            None => sample.bits.clone(),
+        })
+        .collect()
+}
+
 fn candidate_times_to_indices(
     timestamps: &[u64],
     candidate_times: &[u64],
@@ -1638,11 +1857,18 @@ fn resolve_requested_signals(
     waveform: &Waveform,
     scope: Option<&str>,
     args: &ChangeArgs,
+    layouts: Option<&SignalLayouts>,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<Vec<RequestedSignal>, WavepeekError> {
     if let Some(scope) = scope {
         waveform.signals_in_scope(scope)?;
     }
+    let layout_for = |path: &str| match layouts {
+        Some(layouts) => layouts.resolve(waveform, path),
+        None => Ok(None),
+    };
 
+    let mut patterns = SignalPatternExpander::new(waveform, scope, "--signals", "wavepeek change");
     let mut resolved = Vec::with_capacity(args.signals.len());
     for token in &args.signals {
         let display = token.trim();
@@ -1651,22 +1877,48 @@ fn resolve_requested_signals(
                 "signal names must not be empty. See 'wavepeek change --help'.".to_string(),
             ));
         }
+        if let Some(expanded) = patterns.expand(display, diagnostics)? {
+            for signal in expanded {
+                resolved.push(RequestedSignal {
+                    layout: layout_for(signal.path.as_str())?,
+                    display: signal.display,
+                    path: signal.path,
+                    select: None,
+                });
+            }
+            continue;
+        }
 
-        let path = resolve_token_to_path(display, scope)?;
+        let selected = resolve_selected_signal(waveform, display, "wavepeek change", |name| {
+            resolve_token_to_path(waveform, name, scope)
+        })?;
+        let layout = match selected.select {
+            Some(_) => None,
+            None => layout_for(selected.path.as_str())?,
+        };
         resolved.push(RequestedSignal {
             display: display.to_string(),
-            path,
+            path: selected.path,
+            select: selected.select,
+            layout,
         });
     }
 
     Ok(resolved)
 }
 
-fn resolve_token_to_path(token: &str, scope: Option<&str>) -> Result<String, WavepeekError> {
+fn resolve_token_to_path(
+    waveform: &Waveform,
+    token: &str,
+    scope: Option<&str>,
+) -> Result<String, WavepeekError> {
     let token = token.trim();
     match scope {
         Some(scope) => {
-            if token.contains('.') {
+            // Dotted scoped tokens are only accepted as fields of a struct or union.
+            if token.split_once('.').is_some_and(|(head, _)| {
+                !waveform.is_aggregate_scope(format!("{scope}.{head}").as_str())
+            }) {
                 return Err(WavepeekError::Signal(format!(
                     "signal '{token}' not found in dump"
                 )));
@@ -1709,8 +1961,10 @@ fn cached_event_sources(
     host: &WaveformExprHost,
     handles: &[SignalHandle],
 ) -> Result<Vec<(SignalHandle, ExprResolvedSignal)>, WavepeekError> {
+    // Packed-field handles read a slice of their signal, so they are sampled through the host.
     handles
         .iter()
+        .filter(|handle| !host.is_packed_field_handle(**handle))
         .map(|handle| Ok((*handle, host.resolved_signal_for_handle(*handle)?)))
         .collect()
 }
@@ -1840,13 +2094,18 @@ mod change_inline_derive_tests {
             display: "sig".to_string(),
             path: "top.sig".to_string(),
             value: "1'b1".to_string(),
+            fields: None,
         };
         assert_eq!(signal.clone(), signal);
         assert!(format!("{signal:?}").contains("top.sig"));
         assert!(serde_json::to_string(&signal).unwrap().contains("top.sig"));
         let snapshot = ChangeSnapshot {
             time: "1ns".to_string(),
+            raw_time: 1,
+            tick: None,
+            cycle: None,
             sample_time: "1ns".to_string(),
+            sample_raw_time: 1,
             signals: vec![signal],
         };
         assert_eq!(snapshot.clone(), snapshot);
@@ -1875,6 +2134,7 @@ mod tests {
     use crate::cli::change::{ChangeArgs, TuneChangeCandidateMode, TuneChangeEngineMode};
     use crate::cli::limits::LimitArg;
     use crate::cli::sampling::SampleMode;
+    use crate::cli::time_unit::OutputTimeUnit;
     use crate::engine::CommandData;
     use crate::engine::expr_runtime::bind_waveform_event_expr;
     use crate::error::WavepeekError;
@@ -2013,41 +2273,54 @@ mod tests {
 
     #[test]
     fn request_resolution_and_snapshot_helpers_exercise_validation() {
+        let fixture = write_fixture(TEST_VCD, "change-helper.vcd");
+        let waveform = Waveform::open(fixture.path()).expect("waveform should open");
         assert_eq!(
-            resolve_token_to_path("sig", Some("top")).expect("scoped token"),
+            resolve_token_to_path(&waveform, "sig", Some("top")).expect("scoped token"),
             "top.sig"
         );
         assert_eq!(
-            resolve_token_to_path("top.sig", None).expect("canonical token"),
+            resolve_token_to_path(&waveform, "top.sig", None).expect("canonical token"),
             "top.sig"
         );
         assert!(
-            resolve_token_to_path("top.sig", Some("top"))
+            resolve_token_to_path(&waveform, "top.sig", Some("top"))
                 .expect_err("dotted scoped token should fail")
                 .to_string()
                 .contains("signal 'top.sig' not found in dump")
         );
 
-        let fixture = write_fixture(TEST_VCD, "change-helper.vcd");
-        let waveform = Waveform::open(fixture.path()).expect("waveform should open");
         let args = ChangeArgs {
             waves: PathBuf::from(fixture.path()),
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
             signals: vec!["sig".to_string(), "msg".to_string()],
+            layout: None,
             on: "*".to_string(),
             sample_mode: SampleMode::Native,
             max: LimitArg::Numeric(5),
             abs: false,
             json: false,
             jsonl: false,
+            wave: false,
+            width: None,
+            ascii: false,
             tune_engine: TuneChangeEngineMode::Auto,
             tune_candidates: TuneChangeCandidateMode::Auto,
             tune_edge_fast_force: false,
         };
-        let resolved = resolve_requested_signals(&waveform, args.scope.as_deref(), &args)
-            .expect("signals should resolve");
+        let resolved = resolve_requested_signals(
+            &waveform,
+            args.scope.as_deref(),
+            &args,
+            None,
+            &mut Vec::new(),
+        )
+        .expect("signals should resolve");
         assert_eq!(resolved[0].display, "sig");
         assert_eq!(resolved[0].path, "top.sig");
 
@@ -2055,6 +2328,8 @@ mod tests {
             &[RequestedSignal {
                 display: "sig".to_string(),
                 path: "top.sig".to_string(),
+                select: None,
+                layout: None,
             }],
             &[SampledSignalState {
                 path: "top.sig".to_string(),
@@ -2077,6 +2352,8 @@ mod tests {
             &[RequestedSignal {
                 display: "sig".to_string(),
                 path: "top.sig".to_string(),
+                select: None,
+                layout: None,
             }],
             &[SampledSignalState {
                 path: "top.sig".to_string(),
@@ -2386,13 +2663,20 @@ mod tests {
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
             signals: vec!["sig".to_string()],
+            layout: None,
             on: "posedge sig".to_string(),
             sample_mode: SampleMode::Native,
             max: LimitArg::Unlimited,
             abs: false,
             json: true,
             jsonl: false,
+            wave: false,
+            width: None,
+            ascii: false,
             tune_engine: TuneChangeEngineMode::Auto,
             tune_candidates: TuneChangeCandidateMode::Auto,
             tune_edge_fast_force: false,
@@ -2417,13 +2701,20 @@ mod tests {
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
             signals: vec!["sig".to_string()],
+            layout: None,
             on: "*".to_string(),
             sample_mode: SampleMode::Native,
             max: LimitArg::Numeric(0),
             abs: false,
             json: false,
             jsonl: false,
+            wave: false,
+            width: None,
+            ascii: false,
             tune_engine: TuneChangeEngineMode::Auto,
             tune_candidates: TuneChangeCandidateMode::Auto,
             tune_edge_fast_force: false,
@@ -2440,13 +2731,20 @@ mod tests {
             from: Some("5ns".to_string()),
             to: Some("0ns".to_string()),
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
             signals: vec!["sig".to_string()],
+            layout: None,
             on: "posedge sig".to_string(),
             sample_mode: SampleMode::Native,
             max: LimitArg::Numeric(5),
             abs: false,
             json: false,
             jsonl: false,
+            wave: false,
+            width: None,
+            ascii: false,
             tune_engine: TuneChangeEngineMode::Auto,
             tune_candidates: TuneChangeCandidateMode::Auto,
             tune_edge_fast_force: false,
@@ -2466,13 +2764,20 @@ mod tests {
                 from: None,
                 to: None,
                 scope: Some("top".to_string()),
+                time_unit: OutputTimeUnit::Dump,
+                ticks: false,
+                clock: None,
                 signals: vec!["sig".to_string()],
+                layout: None,
                 on: "posedge sig".to_string(),
                 sample_mode: SampleMode::Native,
                 max: LimitArg::Unlimited,
                 abs: false,
                 json: false,
                 jsonl: true,
+                wave: false,
+                width: None,
+                ascii: false,
                 tune_engine: TuneChangeEngineMode::Baseline,
                 tune_candidates: TuneChangeCandidateMode::Auto,
                 tune_edge_fast_force: false,
@@ -2493,13 +2798,20 @@ mod tests {
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
             signals: vec!["sig".to_string()],
+            layout: None,
             on: "negedge sig".to_string(),
             sample_mode: SampleMode::Native,
             max: LimitArg::Numeric(5),
             abs: false,
             json: false,
             jsonl: false,
+            wave: false,
+            width: None,
+            ascii: false,
             tune_engine: TuneChangeEngineMode::Baseline,
             tune_candidates: TuneChangeCandidateMode::Auto,
             tune_edge_fast_force: false,
@@ -2525,13 +2837,20 @@ mod tests {
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
             signals: vec!["sig".to_string()],
+            layout: None,
             on: "*".to_string(),
             sample_mode: SampleMode::Native,
             max: LimitArg::Numeric(1),
             abs: false,
             json: false,
             jsonl: false,
+            wave: false,
+            width: None,
+            ascii: false,
             tune_engine: TuneChangeEngineMode::Baseline,
             tune_candidates: TuneChangeCandidateMode::Auto,
             tune_edge_fast_force: false,
@@ -2580,6 +2899,8 @@ mod tests {
         let requested_signals = vec![RequestedSignal {
             display: "sig".to_string(),
             path: "top.sig".to_string(),
+            select: None,
+            layout: None,
         }];
         let requested_resolved = waveform
             .borrow()
@@ -0,0 +1,588 @@
+use serde::Serialize;
+
+use crate::cli::clock::ClockArgs;
+use crate::debug_trace::DebugTrace;
+use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
+use crate::engine::expr_runtime::{open_waveform_traced, with_shared_waveform};
+use crate::engine::extract::{initial_diagnostics, max_entries};
+use crate::engine::time::{ParsedTime, TimeFormat};
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::value_format::format_verilog_literal;
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+
+const HELP: &str = "wavepeek clock";
+
+/// Periods whose median sets the reference period of a frequency segment.
+const REFERENCE_PERIODS: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClockData {
+    #[serde(skip_serializing)]
+    pub display: String,
+    pub path: String,
+    pub rising_edges: usize,
+    pub falling_edges: usize,
+    /// Period statistics, `None` with fewer than two rising edges.
+    pub period: Option<ClockPeriod>,
+    /// Frequency of the nominal period.
+    pub frequency: Option<String>,
+    /// Duty-cycle percentages over periods that contain a falling edge.
+    pub duty_cycle: Option<ClockDutyCycle>,
+    pub gated: Vec<ClockGate>,
+    pub frequency_changes: Vec<ClockFrequencyChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClockPeriod {
+    /// Median rising-to-rising period outside gated segments.
+    pub nominal: String,
+    pub min: String,
+    pub max: String,
+    pub mean: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClockDutyCycle {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClockGate {
+    /// Edge after which the clock held `level`.
+    pub start: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    /// `--clock` cycle of `start`, `None` without `--clock` or before its first edge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Edge where the clock resumed toggling, `None` when it stays stopped through `--to`.
+    pub end: Option<String>,
+    pub duration: String,
+    /// Level the clock held while stopped.
+    pub level: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClockFrequencyChange {
+    /// Rising edge that starts the first period at the new frequency.
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    /// `--clock` cycle of `time`, `None` without `--clock` or before its first edge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    pub previous_period: String,
+    pub period: String,
+    pub previous_frequency: String,
+    pub frequency: String,
+}
+
+pub fn run(args: ClockArgs) -> Result<CommandResult, WavepeekError> {
+    let output_mode = crate::output_mode::OutputMode::from_json_flags(args.json, args.jsonl);
+    let max_entries = max_entries(&args.max)?;
+    if args.gate_periods == 0 {
+        return Err(WavepeekError::Args(format!(
+            "--gate-periods must be greater than 0. See '{HELP} --help'."
+        )));
+    }
+
+    let mut diagnostics = initial_diagnostics(&args.max);
+
+    let debug = DebugTrace::for_command(CommandName::Clock);
+    let waveform = open_waveform_traced(args.waves.as_path(), &debug)?;
+
+    let display = args.signal.trim();
+    if display.is_empty() {
+        return Err(WavepeekError::Args(format!(
+            "--signal must not be empty. See '{HELP} --help'."
+        )));
+    }
+    if let Some(scope) = args.scope.as_deref() {
+        waveform.signals_in_scope(scope)?;
+    }
+    let path = match args.scope.as_deref() {
+        Some(scope) => format!("{scope}.{display}"),
+        None => display.to_string(),
+    };
+
+    let (
+        mut waveform,
+        RawTimeWindow {
+            dump_time,
+            dump_start_raw,
+            from_raw,
+            to_raw,
+            clock,
+            ..
+        },
+    ) = with_shared_waveform(waveform, |waveform| {
+        resolve_raw_window(
+            waveform,
+            args.scope.as_deref(),
+            args.clock.as_deref(),
+            HELP,
+            args.from.as_deref(),
+            args.to.as_deref(),
+            &debug,
+            &mut diagnostics,
+        )
+    })?;
+    debug.event("time.parse.done", || serde_json::json!({}));
+
+    let resolved = waveform.resolve_signals(std::slice::from_ref(&path))?;
+    if resolved[0].width != 1 {
+        return Err(WavepeekError::Args(format!(
+            "--signal '{display}' must be a 1-bit signal, but it is {} bits wide. See '{HELP} --help'.",
+            resolved[0].width
+        )));
+    }
+
+    let mut edges = ClockEdges::default();
+    let mut previous = match from_raw.checked_sub(1) {
+        Some(before) if from_raw > dump_start_raw => waveform
+            .sample_resolved_optional(&resolved, before)?[0]
+            .bits
+            .take(),
+        _ => None,
+    };
+    for time in waveform.collect_change_times(&resolved, from_raw, to_raw)? {
+        let value = waveform.sample_resolved_optional(&resolved, time)?[0]
+            .bits
+            .take();
+        // The first recorded value is an initialization, not an edge.
+        if let (Some(before), Some(after)) = (previous.as_deref(), value.as_deref())
+            && before != after
+        {
+            match after {
+                "1" => edges.rising.push(time),
+                "0" => edges.falling.push(time),
+                _ => {}
+            }
+        }
+        previous = value;
+    }
+    debug.event(
+        "clock.edges.done",
+        || serde_json::json!({"rising": edges.rising.len(), "falling": edges.falling.len()}),
+    );
+
+    let analysis = edges.analyze(args.gate_periods, args.tolerance, to_raw);
+    let format = ClockFormat {
+        time_format: TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks),
+        dump_tick: dump_time.dump_tick,
+        dump_tick_zs: dump_time.dump_tick_zs,
+        clock: clock.as_ref(),
+    };
+    let mut data = ClockData {
+        display: display.to_string(),
+        path,
+        rising_edges: edges.rising.len(),
+        falling_edges: edges.falling.len(),
+        period: None,
+        frequency: None,
+        duty_cycle: None,
+        gated: analysis
+            .gates
+            .iter()
+            .map(|gate| format.gate(gate))
+            .collect::<Result<_, _>>()?,
+        frequency_changes: analysis
+            .changes
+            .iter()
+            .map(|change| format.frequency_change(change))
+            .collect::<Result<_, _>>()?,
+    };
+
+    let mut periods = analysis.periods.clone();
+    periods.sort_unstable();
+    if let (Some(&min), Some(&max)) = (periods.first(), periods.last()) {
+        let nominal = periods[(periods.len() - 1) / 2];
+        let total = periods
+            .iter()
+            .map(|&period| u128::from(period))
+            .sum::<u128>();
+        data.period = Some(ClockPeriod {
+            nominal: format.time_format.format(nominal)?,
+            min: format.time_format.format(min)?,
+            max: format.time_format.format(max)?,
+            mean: format.mean(total, periods.len()),
+        });
+        data.frequency = Some(format.frequency(nominal));
+    } else {
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::EmptyResult,
+            "fewer than two ungated rising edges in selected time range; period statistics are unavailable",
+        ));
+    }
+    if let Some(duty) = analysis.duty {
+        data.duty_cycle = Some(ClockDutyCycle {
+            min: round_percent(duty.min),
+            max: round_percent(duty.max),
+            mean: round_percent(percent(duty.high_ticks, duty.period_ticks)),
+        });
+    }
+    debug.event("clock.analyze.done", || {
+        serde_json::json!({
+            "gated": data.gated.len(),
+            "frequency_changes": data.frequency_changes.len(),
+        })
+    });
+
+    if let Some(max_entries) = max_entries
+        && (data.gated.len() > max_entries || data.frequency_changes.len() > max_entries)
+    {
+        data.gated.truncate(max_entries);
+        data.frequency_changes.truncate(max_entries);
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::OutputTruncated,
+            format!("truncated output to {max_entries} entries (use --max to increase limit)"),
+        ));
+    }
+
+    Ok(CommandResult {
+        command: CommandName::Clock,
+        output_mode,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: args.abs,
+        },
+        data: CommandData::Clock(data),
+        diagnostics,
+    })
+}
+
+/// Settled clock edges in time order.
+#[derive(Debug, Default)]
+struct ClockEdges {
+    rising: Vec<u64>,
+    falling: Vec<u64>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct ClockAnalysis {
+    /// Ungated rising-to-rising periods in raw ticks.
+    periods: Vec<u64>,
+    duty: Option<DutyStats>,
+    gates: Vec<GateSpan>,
+    changes: Vec<FrequencyStep>,
+}
+
+#[derive(Debug, PartialEq)]
+struct DutyStats {
+    min: f64,
+    max: f64,
+    high_ticks: u128,
+    period_ticks: u128,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct GateSpan {
+    start: u64,
+    end: Option<u64>,
+    duration: u64,
+    level: &'static str,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct FrequencyStep {
+    time: u64,
+    previous_period: u64,
+    period: u64,
+}
+
+impl ClockEdges {
+    /// Splits rising-to-rising periods into gated gaps and frequency segments.
+    ///
+    /// Each period is judged against its frequency segment's reference period, so a slower
+    /// clock after a DVFS step is not mistaken for gating. References are the median of up to
+    /// `REFERENCE_PERIODS` periods starting at the segment, and a period outside `--tolerance`
+    /// only starts a new segment when that median is too, so one gated or jittery period
+    /// cannot set a reference.
+    fn analyze(&self, gate_periods: u64, tolerance: u64, to_raw: u64) -> ClockAnalysis {
+        let periods = self
+            .rising
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect::<Vec<_>>();
+        let outside_tolerance = |period: u64, reference: u64| {
+            u128::from(period.abs_diff(reference)) * 100
+                > u128::from(tolerance) * u128::from(reference)
+        };
+
+        let mut analysis = ClockAnalysis::default();
+        let mut reference = None;
+        for (index, &period) in periods.iter().enumerate() {
+            let (start, end) = (self.rising[index], self.rising[index + 1]);
+            let segment_period = *reference.get_or_insert_with(|| median_period(&periods[index..]));
+            if u128::from(period) > u128::from(gate_periods) * u128::from(segment_period) {
+                analysis.gates.push(self.gate_after(start, end, Some(end)));
+                continue;
+            }
+            if outside_tolerance(period, segment_period) {
+                let next_period = median_period(&periods[index..]);
+                if outside_tolerance(next_period, segment_period) {
+                    analysis.changes.push(FrequencyStep {
+                        time: start,
+                        previous_period: segment_period,
+                        period: next_period,
+                    });
+                    reference = Some(next_period);
+                }
+            }
+            analysis.periods.push(period);
+            self.record_duty(start, end, &mut analysis.duty);
+        }
+
+        if let (Some(segment_period), Some(&last_rise)) = (reference, self.rising.last())
+            && u128::from(to_raw - last_rise)
+                > u128::from(gate_periods) * u128::from(segment_period)
+        {
+            analysis
+                .gates
+                .push(self.gate_after(last_rise, to_raw, None));
+        }
+        analysis
+    }
+
+    /// Gate span inside the gated period that rises at `start` and lasts until `until`, which
+    /// is the resuming rising edge or `--to`.
+    ///
+    /// The first falling edge after `start` splits the period into a high and a low stretch.
+    /// A clock stopped low holds from that fall to `until`; a clock stopped high holds from
+    /// `start` until that fall, where it resumed toggling. Through `--to`, any fall means the
+    /// clock stopped low.
+    fn gate_after(&self, start: u64, until: u64, end: Option<u64>) -> GateSpan {
+        let first_fall = self.falling.partition_point(|&fall| fall <= start);
+        let fall = self
+            .falling
+            .get(first_fall)
+            .copied()
+            .filter(|&fall| fall < until);
+        let (start, stop, level) = match fall {
+            Some(fall) if end.is_none() || until - fall >= fall - start => (fall, until, "0"),
+            Some(fall) => (start, fall, "1"),
+            None => (start, until, "1"),
+        };
+        GateSpan {
+            start,
+            end: end.map(|_| stop),
+            duration: stop - start,
+            level,
+        }
+    }
+
+    fn record_duty(&self, start: u64, end: u64, duty: &mut Option<DutyStats>) {
+        let first_fall = self.falling.partition_point(|&fall| fall <= start);
+        let Some(&fall) = self.falling.get(first_fall).filter(|&&fall| fall < end) else {
+            return;
+        };
+        let (high, period) = (fall - start, end - start);
+        let cycle = percent(u128::from(high), u128::from(period));
+        let stats = duty.get_or_insert(DutyStats {
+            min: cycle,
+            max: cycle,
+            high_ticks: 0,
+            period_ticks: 0,
+        });
+        stats.min = stats.min.min(cycle);
+        stats.max = stats.max.max(cycle);
+        stats.high_ticks += u128::from(high);
+        stats.period_ticks += u128::from(period);
+    }
+}
+
+/// Lower median of the first `REFERENCE_PERIODS` of `periods`, which must not be empty.
+fn median_period(periods: &[u64]) -> u64 {
+    let mut window = periods[..periods.len().min(REFERENCE_PERIODS)].to_vec();
+    window.sort_unstable();
+    window[(window.len() - 1) / 2]
+}
+
+fn percent(part: u128, whole: u128) -> f64 {
+    part as f64 * 100.0 / whole as f64
+}
+
+fn round_percent(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// Renders raw tick spans as `--time-unit` times and frequencies.
+#[derive(Debug, Clone, Copy)]
+struct ClockFormat<'a> {
+    time_format: TimeFormat,
+    dump_tick: ParsedTime,
+    dump_tick_zs: u128,
+    clock: Option<&'a CycleClock>,
+}
+
+impl ClockFormat<'_> {
+    fn gate(self, gate: &GateSpan) -> Result<ClockGate, WavepeekError> {
+        Ok(ClockGate {
+            start: self.time_format.format(gate.start)?,
+            tick: self.time_format.tick(gate.start),
+            cycle: self.clock.and_then(|clock| clock.cycle_at(gate.start)),
+            end: gate
+                .end
+                .map(|end| self.time_format.format(end))
+                .transpose()?,
+            duration: self.time_format.format(gate.duration)?,
+            level: format_verilog_literal(1, gate.level),
+        })
+    }
+
+    fn frequency_change(
+        self,
+        change: &FrequencyStep,
+    ) -> Result<ClockFrequencyChange, WavepeekError> {
+        Ok(ClockFrequencyChange {
+            time: self.time_format.format(change.time)?,
+            tick: self.time_format.tick(change.time),
+            cycle: self.clock.and_then(|clock| clock.cycle_at(change.time)),
+            previous_period: self.time_format.format(change.previous_period)?,
+            period: self.time_format.format(change.period)?,
+            previous_frequency: self.frequency(change.previous_period),
+            frequency: self.frequency(change.period),
+        })
+    }
+
+    /// Mean of `count` periods totalling `total` ticks, rounded to 0.001 of the dump unit.
+    fn mean(self, total: u128, count: usize) -> String {
+        let count = count as u128;
+        let thousandths = (total * u128::from(self.dump_tick.value) * 2000 + count) / (2 * count);
+        // Thousandths of a `zs` dump unit truncate to whole zeptoseconds.
+        let unit_zs = self.dump_tick_zs / u128::from(self.dump_tick.value);
+        let mean_zs = thousandths * unit_zs / 1000;
+        self.time_format.format_zeptoseconds(mean_zs)
+    }
+
+    /// Frequency of a period in raw ticks, in the largest unit that keeps it at least 1.
+    fn frequency(self, period: u64) -> String {
+        let hertz = 1e21 / (period as f64 * self.dump_tick_zs as f64);
+        let (scale, unit) = [(1e9, "GHz"), (1e6, "MHz"), (1e3, "kHz")]
+            .into_iter()
+            .find(|(scale, _)| hertz >= *scale)
+            .unwrap_or((1.0, "Hz"));
+        let value = format!("{:.3}", hertz / scale);
+        format!(
+            "{}{unit}",
+            value.trim_end_matches('0').trim_end_matches('.')
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClockEdges, FrequencyStep, GateSpan};
+
+    fn edges(rising: &[u64], falling: &[u64]) -> ClockEdges {
+        ClockEdges {
+            rising: rising.to_vec(),
+            falling: falling.to_vec(),
+        }
+    }
+
+    #[test]
+    fn analyze_splits_gated_gaps_from_frequency_steps() {
+        let clock = edges(&[0, 10, 20, 60, 70, 90, 110], &[5, 15, 25, 65, 80, 100]);
+        let analysis = clock.analyze(2, 5, 120);
+
+        assert_eq!(analysis.periods, vec![10, 10, 10, 20, 20]);
+        assert_eq!(
+            analysis.gates,
+            vec![GateSpan {
+                start: 25,
+                end: Some(60),
+                duration: 35,
+                level: "0",
+            }]
+        );
+        assert_eq!(
+            analysis.changes,
+            vec![FrequencyStep {
+                time: 70,
+                previous_period: 10,
+                period: 20,
+            }]
+        );
+    }
+
+    #[test]
+    fn analyze_reports_gate_in_the_first_period() {
+        let clock = edges(&[0, 50, 60, 70, 80], &[5, 55, 65, 75]);
+        let analysis = clock.analyze(2, 5, 85);
+
+        assert_eq!(analysis.periods, vec![10, 10, 10]);
+        assert_eq!(
+            analysis.gates,
+            vec![GateSpan {
+                start: 5,
+                end: Some(50),
+                duration: 45,
+                level: "0",
+            }]
+        );
+        assert!(analysis.changes.is_empty());
+    }
+
+    #[test]
+    fn analyze_ignores_single_jittery_periods() {
+        // 10-tick clock with one 12-tick period, then a step to 20 ticks whose first period is 23.
+        let clock = edges(
+            &[0, 10, 22, 32, 42, 52, 75, 95, 115, 135, 155],
+            &[5, 15, 27, 37, 47, 62, 85, 105, 125, 145],
+        );
+        let analysis = clock.analyze(4, 10, 160);
+
+        assert_eq!(
+            analysis.changes,
+            vec![FrequencyStep {
+                time: 52,
+                previous_period: 10,
+                period: 20,
+            }]
+        );
+        assert!(analysis.gates.is_empty());
+        assert_eq!(analysis.periods.len(), 10);
+    }
+
+    #[test]
+    fn analyze_reports_clock_stopped_high() {
+        // Rises at 30, holds high until 135, and toggles again from 140.
+        let clock = edges(&[0, 10, 20, 30, 140, 150, 160], &[5, 15, 25, 135, 145, 155]);
+        let analysis = clock.analyze(2, 5, 165);
+
+        assert_eq!(analysis.periods, vec![10, 10, 10, 10, 10]);
+        assert_eq!(
+            analysis.gates,
+            vec![GateSpan {
+                start: 30,
+                end: Some(135),
+                duration: 105,
+                level: "1",
+            }]
+        );
+        assert!(analysis.changes.is_empty());
+    }
+
+    #[test]
+    fn analyze_reports_clock_stopped_through_range_end() {
+        let clock = edges(&[0, 10, 20], &[5, 15]);
+        let analysis = clock.analyze(2, 5, 60);
+
+        assert_eq!(
+            analysis.gates,
+            vec![GateSpan {
+                start: 20,
+                end: None,
+                duration: 40,
+                level: "1",
+            }]
+        );
+        let duty = analysis.duty.expect("duty cycle");
+        assert_eq!((duty.min, duty.max), (50.0, 50.0));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::engine::expr_runtime::{SharedWaveform, named_event_match_times};
+use crate::engine::time::DumpTimeContext;
+use crate::error::WavepeekError;
+
+/// Time unit suffix that counts `--clock` edges instead of dump time.
+pub(crate) const CYCLE_UNIT: &str = "cyc";
+
+/// Reference clock from `--clock`; cycle `N` starts at the `N`-th matching edge, counted from 1
+/// like `nth(N, <event>)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CycleClock {
+    source: String,
+    edges: Vec<u64>,
+}
+
+impl CycleClock {
+    /// Collects every `--clock` edge in the dump; `None` without `--clock`.
+    pub(crate) fn load(
+        waveform: &SharedWaveform,
+        scope: Option<&str>,
+        source: Option<&str>,
+        dump_time: DumpTimeContext,
+        help_command: &str,
+    ) -> Result<Option<Self>, WavepeekError> {
+        let Some(source) = source else {
+            return Ok(None);
+        };
+        let raw = |zs: u128| u64::try_from(zs / dump_time.dump_tick_zs).unwrap_or(u64::MAX);
+        let edges = named_event_match_times(
+            waveform,
+            scope,
+            source,
+            raw(dump_time.dump_start_zs),
+            raw(dump_time.dump_end_zs),
+        )?
+        .ok_or_else(|| {
+            WavepeekError::Args(format!(
+                "--clock '{source}' must name its clock signal instead of using '*'. See '{help_command} --help'."
+            ))
+        })?;
+        if edges.is_empty() {
+            return Err(WavepeekError::Args(format!(
+                "--clock '{source}' matched no edges in the dump. See '{help_command} --help'."
+            )));
+        }
+        Ok(Some(Self {
+            source: source.to_string(),
+            edges,
+        }))
+    }
+
+    pub(crate) fn source(&self) -> &str {
+        self.source.as_str()
+    }
+
+    pub(crate) fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Cycle containing `raw`, or `None` before the first edge.
+    pub(crate) fn cycle_at(&self, raw: u64) -> Option<u64> {
+        let edges_at_or_before = self.edges.partition_point(|edge| *edge <= raw);
+        u64::try_from(edges_at_or_before)
+            .ok()
+            .filter(|cycle| *cycle > 0)
+    }
+
+    /// Time of the edge that starts `cycle`, or `None` for cycle 0 and past the last edge.
+    pub(crate) fn edge(&self, cycle: u64) -> Option<u64> {
+        usize::try_from(cycle)
+            .ok()
+            .and_then(|cycle| cycle.checked_sub(1))
+            .and_then(|index| self.edges.get(index))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CycleClock;
+
+    #[test]
+    fn cycles_start_at_each_edge_and_count_from_one() {
+        let clock = CycleClock {
+            source: "posedge clk".to_string(),
+            edges: vec![5, 15, 25],
+        };
+        assert_eq!(clock.cycle_at(4), None);
+        assert_eq!(clock.cycle_at(5), Some(1));
+        assert_eq!(clock.cycle_at(14), Some(1));
+        assert_eq!(clock.cycle_at(25), Some(3));
+        assert_eq!(clock.cycle_at(100), Some(3));
+        assert_eq!(clock.edge(0), None);
+        assert_eq!(clock.edge(2), Some(15));
+        assert_eq!(clock.edge(4), None);
+    }
+}
@@ -0,0 +1,564 @@
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::cli::export::{GtkwArgs, Radix, SaveFileArgs, SurferArgs};
+use crate::debug_trace::DebugTrace;
+use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::expr_runtime::{open_waveform_traced, with_shared_waveform};
+use crate::engine::extract::parse_bound_time;
+use crate::engine::time::ParsedTime;
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::waveform::Waveform;
+
+/// GTKWave stores exactly 26 named markers (`A`-`Z`) in a save file.
+const GTKW_NAMED_MARKERS: usize = 26;
+/// Viewport width in pixels that the GTKWave zoom factor is computed against.
+const GTKW_VIEWPORT_PIXELS: f64 = 800.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SaveFileFormat {
+    Gtkw,
+    Surfer,
+}
+
+impl SaveFileFormat {
+    const fn command(self) -> CommandName {
+        match self {
+            Self::Gtkw => CommandName::ExportGtkw,
+            Self::Surfer => CommandName::ExportSurfer,
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Gtkw => "GTKWave save file",
+            Self::Surfer => "Surfer command file",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PlannedSignal {
+    path: String,
+    scope: String,
+    width: u32,
+    range: Option<(i64, i64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SaveFilePlan {
+    dump_path: String,
+    dump_tick: ParsedTime,
+    signals: Vec<PlannedSignal>,
+    from_raw: u64,
+    to_raw: u64,
+    cursor_raw: Option<u64>,
+    marker_raws: Vec<u64>,
+    group_by_scope: bool,
+    radix: Radix,
+}
+
+pub fn run_gtkw(args: GtkwArgs) -> Result<CommandResult, WavepeekError> {
+    run_save_file(
+        args.save,
+        args.from.as_deref(),
+        args.to.as_deref(),
+        SaveFileFormat::Gtkw,
+    )
+}
+
+/// Surfer commands cannot set the visible time range, so the window always spans the dump.
+pub fn run_surfer(args: SurferArgs) -> Result<CommandResult, WavepeekError> {
+    run_save_file(args.save, None, None, SaveFileFormat::Surfer)
+}
+
+fn run_save_file(
+    args: SaveFileArgs,
+    from: Option<&str>,
+    to: Option<&str>,
+    format: SaveFileFormat,
+) -> Result<CommandResult, WavepeekError> {
+    let command = format.command();
+    let debug = DebugTrace::for_command(command);
+    let waveform = open_waveform_traced(args.waves.as_path(), &debug)?;
+
+    let mut diagnostics = Vec::new();
+    let help_command = format!("wavepeek {}", command.as_str());
+    let (waveform, window) = with_shared_waveform(waveform, |waveform| {
+        resolve_raw_window(
+            waveform,
+            args.scope.as_deref(),
+            None,
+            help_command.as_str(),
+            from,
+            to,
+            &debug,
+            &mut diagnostics,
+        )
+    })?;
+    let plan = build_plan(
+        &args,
+        &window,
+        &waveform,
+        help_command.as_str(),
+        command,
+        &mut diagnostics,
+    )?;
+    debug.event("export.plan.done", || {
+        serde_json::json!({
+            "signals": plan.signals.len(),
+            "markers": plan.marker_raws.len(),
+        })
+    });
+
+    let contents = match format {
+        SaveFileFormat::Gtkw => render_gtkw(&plan, args.out.as_deref())?,
+        SaveFileFormat::Surfer => render_surfer(&plan),
+    };
+
+    let text = match args.out.as_deref() {
+        Some(out) => {
+            std::fs::write(out, contents.as_bytes()).map_err(|error| {
+                WavepeekError::File(format!("cannot write '{}': {error}", out.display()))
+            })?;
+            format!(
+                "wrote {} with {} signal(s) to {}",
+                format.label(),
+                plan.signals.len(),
+                out.display()
+            )
+        }
+        None => contents.trim_end_matches('\n').to_string(),
+    };
+
+    Ok(CommandResult {
+        command,
+        output_mode: crate::output_mode::OutputMode::Human,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: false,
+        },
+        data: CommandData::Text(text),
+        diagnostics,
+    })
+}
+
+fn build_plan(
+    args: &SaveFileArgs,
+    window: &RawTimeWindow,
+    waveform: &Waveform,
+    help: &str,
+    command: CommandName,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<SaveFilePlan, WavepeekError> {
+    let metadata = &window.metadata;
+    let dump_time = window.dump_time;
+    if let Some(scope) = args.scope.as_deref() {
+        waveform.signals_in_scope(scope)?;
+    }
+
+    let mut canonical_paths = Vec::with_capacity(args.signals.len());
+    for token in &args.signals {
+        let name = token.trim();
+        if name.is_empty() {
+            return Err(WavepeekError::Args(format!(
+                "signal names must not be empty. See '{help} --help'."
+            )));
+        }
+        canonical_paths.push(match args.scope.as_deref() {
+            Some(scope) => format!("{scope}.{name}"),
+            None => name.to_string(),
+        });
+    }
+    let resolved = waveform.resolve_signals(&canonical_paths)?;
+    let ranges = waveform.declared_index_ranges(&canonical_paths)?;
+    let signals = resolved
+        .into_iter()
+        .zip(ranges)
+        .map(|(signal, range)| PlannedSignal {
+            scope: parent_scope(signal.path.as_str()).to_string(),
+            path: signal.path,
+            width: signal.width,
+            range,
+        })
+        .collect::<Vec<_>>();
+
+    let cursor_raw = args
+        .cursor
+        .as_deref()
+        .map(|token| parse_bound_time(token, "--cursor", dump_time, metadata, help))
+        .transpose()?;
+
+    let mut marker_tokens = args
+        .markers
+        .iter()
+        .map(|token| (token.trim().to_string(), "--markers"))
+        .collect::<Vec<_>>();
+    if let Some(path) = args.markers_from.as_deref() {
+        let times = read_marker_times(path, help)?;
+        if times.is_empty() {
+            diagnostics.push(Diagnostic::warning(
+                WarningDiagnosticCode::EmptyResult,
+                format!("no row times found in '{}'", path.display()),
+            ));
+        }
+        marker_tokens.extend(times.into_iter().map(|time| (time, "--markers-from")));
+    }
+
+    let mut seen = HashSet::new();
+    let mut marker_raws = Vec::with_capacity(marker_tokens.len());
+    for (token, arg_name) in &marker_tokens {
+        let raw = parse_bound_time(token, arg_name, dump_time, metadata, help)?;
+        if seen.insert(raw) {
+            marker_raws.push(raw);
+        }
+    }
+    if command == CommandName::ExportGtkw && marker_raws.len() > GTKW_NAMED_MARKERS {
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::OutputTruncated,
+            format!(
+                "kept the first {GTKW_NAMED_MARKERS} of {} markers; GTKWave save files hold named markers A-Z only",
+                marker_raws.len()
+            ),
+        ));
+        marker_raws.truncate(GTKW_NAMED_MARKERS);
+    }
+
+    Ok(SaveFilePlan {
+        dump_path: absolute_path(args.waves.as_path()),
+        dump_tick: dump_time.dump_tick,
+        signals,
+        from_raw: window.from_raw,
+        to_raw: window.to_raw,
+        cursor_raw,
+        marker_raws,
+        group_by_scope: args.group_by_scope,
+        radix: args.radix,
+    })
+}
+
+fn render_gtkw(plan: &SaveFilePlan, out: Option<&Path>) -> Result<String, WavepeekError> {
+    let tick = plan.dump_tick.value;
+    // GTKWave counts time in `time_unit` base units, so a `10ps` dump scales raw ticks by 10.
+    let scaled = |raw: u64| {
+        raw.checked_mul(tick).ok_or_else(|| {
+            WavepeekError::Internal(format!(
+                "time of {raw} dump ticks overflows GTKWave save file time"
+            ))
+        })
+    };
+    let mut text = String::new();
+    text.push_str("[*]\n");
+    let _ = writeln!(text, "[*] wavepeek {}", env!("CARGO_PKG_VERSION"));
+    text.push_str("[*]\n");
+    let _ = writeln!(text, "[dumpfile] \"{}\"", plan.dump_path);
+    if let Some(out) = out {
+        let _ = writeln!(text, "[savefile] \"{}\"", absolute_path(out));
+    }
+    let _ = writeln!(text, "[timestart] {}", scaled(plan.from_raw)?);
+
+    let span = scaled(plan.to_raw - plan.from_raw)? as f64;
+    let zoom = if span > 0.0 {
+        -(span / GTKW_VIEWPORT_PIXELS).log2()
+    } else {
+        0.0
+    };
+    let cursor = match plan.cursor_raw {
+        Some(raw) => scaled(raw)?.to_string(),
+        None => "-1".to_string(),
+    };
+    let _ = write!(text, "*{zoom:.6} {cursor}");
+    for index in 0..GTKW_NAMED_MARKERS {
+        match plan.marker_raws.get(index) {
+            Some(raw) => {
+                let _ = write!(text, " {}", scaled(*raw)?);
+            }
+            None => text.push_str(" -1"),
+        }
+    }
+    text.push('\n');
+
+    let mut current_flags = None;
+    let mut push_flags = |text: &mut String, flags: &str| {
+        if current_flags.as_deref() != Some(flags) {
+            let _ = writeln!(text, "@{flags}");
+            current_flags = Some(flags.to_string());
+        }
+    };
+    for group in signal_groups(plan) {
+        if let Some(scope) = group.scope {
+            push_flags(&mut text, "800200");
+            let _ = writeln!(text, "-{scope}");
+        }
+        for signal in group.signals {
+            push_flags(&mut text, gtkw_flags(signal.width, plan.radix));
+            let _ = writeln!(text, "{}", gtkw_signal_name(signal));
+        }
+        if let Some(scope) = group.scope {
+            push_flags(&mut text, "1000200");
+            let _ = writeln!(text, "-{scope}");
+        }
+    }
+    text.push_str("[pattern_trace] 1\n[pattern_trace] 0\n");
+    Ok(text)
+}
+
+fn render_surfer(plan: &SaveFilePlan) -> String {
+    let mut text = String::new();
+    let mut item_index = 0usize;
+    for group in signal_groups(plan) {
+        if let Some(scope) = group.scope {
+            let _ = writeln!(text, "divider_add {scope}");
+            item_index += 1;
+        }
+        for signal in group.signals {
+            let _ = writeln!(text, "variable_add {}", signal.path);
+            if signal.width > 1 && plan.radix != Radix::Hex {
+                let _ = writeln!(text, "item_focus {}", surfer_item_id(item_index));
+                let _ = writeln!(text, "item_set_format {}", surfer_format(plan.radix));
+                text.push_str("item_unfocus\n");
+            }
+            item_index += 1;
+        }
+    }
+    for (index, raw) in plan.marker_raws.iter().enumerate() {
+        let _ = writeln!(text, "marker_set {} {raw}", index + 1);
+    }
+    if let Some(cursor) = plan.cursor_raw {
+        let _ = writeln!(text, "cursor_set {cursor}");
+    }
+    text
+}
+
+struct SignalGroup<'a> {
+    scope: Option<&'a str>,
+    signals: Vec<&'a PlannedSignal>,
+}
+
+/// Splits signals into runs sharing a parent scope; one ungrouped run without `--group-by-scope`.
+fn signal_groups(plan: &SaveFilePlan) -> Vec<SignalGroup<'_>> {
+    if !plan.group_by_scope {
+        return vec![SignalGroup {
+            scope: None,
+            signals: plan.signals.iter().collect(),
+        }];
+    }
+
+    let mut groups: Vec<SignalGroup<'_>> = Vec::new();
+    for signal in &plan.signals {
+        match groups.last_mut() {
+            Some(group) if group.scope == Some(signal.scope.as_str()) => {
+                group.signals.push(signal);
+            }
+            _ => groups.push(SignalGroup {
+                scope: Some(signal.scope.as_str()),
+                signals: vec![signal],
+            }),
+        }
+    }
+    groups
+}
+
+fn gtkw_flags(width: u32, radix: Radix) -> &'static str {
+    if width <= 1 {
+        return "28";
+    }
+    match radix {
+        Radix::Hex => "22",
+        Radix::Bin => "28",
+        Radix::Dec => "24",
+        Radix::Signed => "424",
+    }
+}
+
+fn gtkw_signal_name(signal: &PlannedSignal) -> String {
+    match signal.range {
+        Some((msb, lsb)) if signal.width > 1 || msb != lsb => {
+            format!("{}[{msb}:{lsb}]", signal.path)
+        }
+        None if signal.width > 1 => format!("{}[{}:0]", signal.path, signal.width - 1),
+        _ => signal.path.clone(),
+    }
+}
+
+const fn surfer_format(radix: Radix) -> &'static str {
+    match radix {
+        Radix::Hex => "Hexadecimal",
+        Radix::Bin => "Binary",
+        Radix::Dec => "Unsigned",
+        Radix::Signed => "Signed",
+    }
+}
+
+/// Surfer addresses displayed items by letter: `a`..`z`, then `aa`, `ab`, ...
+fn surfer_item_id(index: usize) -> String {
+    let mut letters = Vec::new();
+    let mut remaining = index + 1;
+    while remaining > 0 {
+        remaining -= 1;
+        letters.push(char::from(b'a' + (remaining % 26) as u8));
+        remaining /= 26;
+    }
+    letters.iter().rev().collect()
+}
+
+fn parent_scope(path: &str) -> &str {
+    path.rsplit_once('.').map_or("", |(scope, _)| scope)
+}
+
+fn absolute_path(path: &Path) -> String {
+    std::fs::canonicalize(path)
+        .or_else(|_| std::env::current_dir().map(|dir| dir.join(path)))
+        .unwrap_or_else(|_| PathBuf::from(path))
+        .display()
+        .to_string()
+}
+
+/// Collects `time` fields from saved `--json` row payloads or `--jsonl` item records.
+fn read_marker_times(path: &Path, help: &str) -> Result<Vec<String>, WavepeekError> {
+    let contents = std::fs::read_to_string(path).map_err(|error| {
+        WavepeekError::File(format!(
+            "failed to read markers file '{}': {error}",
+            path.display()
+        ))
+    })?;
+
+    let mut times = Vec::new();
+    if let Ok(envelope) = serde_json::from_str::<serde_json::Value>(&contents) {
+        if let Some(data) = envelope.get("data") {
+            collect_row_times(data, &mut times);
+            return Ok(times);
+        }
+        return Err(invalid_markers_file(path, help));
+    }
+
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let record = serde_json::from_str::<serde_json::Value>(line)
+            .map_err(|_| invalid_markers_file(path, help))?;
+        if record.get("type").and_then(serde_json::Value::as_str) == Some("item")
+            && let Some(item) = record.get("item")
+        {
+            collect_row_times(item, &mut times);
+        }
+    }
+    Ok(times)
+}
+
+fn collect_row_times(value: &serde_json::Value, times: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_row_times(item, times);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            if let Some(time) = fields.get("time").and_then(serde_json::Value::as_str) {
+                times.push(time.to_string());
+                return;
+            }
+            for field in fields.values() {
+                collect_row_times(field, times);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn invalid_markers_file(path: &Path, help: &str) -> WavepeekError {
+    WavepeekError::Args(format!(
+        "markers file '{}' is not a wavepeek --json or --jsonl result. See '{help} --help'.",
+        path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PlannedSignal, SaveFilePlan, gtkw_signal_name, read_marker_times, render_gtkw,
+        surfer_item_id,
+    };
+    use crate::cli::export::Radix;
+    use crate::engine::time::{ParsedTime, TimeUnit};
+
+    use std::fs;
+
+    use tempfile::NamedTempFile;
+
+    fn signal(width: u32, range: Option<(i64, i64)>) -> PlannedSignal {
+        PlannedSignal {
+            path: "top.data".to_string(),
+            scope: "top".to_string(),
+            width,
+            range,
+        }
+    }
+
+    #[test]
+    fn gtkw_names_carry_declared_or_synthesized_ranges() {
+        assert_eq!(gtkw_signal_name(&signal(1, None)), "top.data");
+        assert_eq!(gtkw_signal_name(&signal(8, Some((7, 0)))), "top.data[7:0]");
+        assert_eq!(gtkw_signal_name(&signal(4, Some((0, 3)))), "top.data[0:3]");
+        assert_eq!(gtkw_signal_name(&signal(1, Some((5, 5)))), "top.data");
+        assert_eq!(gtkw_signal_name(&signal(16, None)), "top.data[15:0]");
+    }
+
+    #[test]
+    fn gtkw_times_scale_by_dump_tick_without_overflowing() {
+        let mut plan = SaveFilePlan {
+            dump_path: "/tmp/dump.vcd".to_string(),
+            dump_tick: ParsedTime {
+                value: 10,
+                unit: TimeUnit::Ps,
+            },
+            signals: vec![signal(1, None)],
+            from_raw: 2,
+            to_raw: 9,
+            cursor_raw: Some(3),
+            marker_raws: vec![4],
+            group_by_scope: false,
+            radix: Radix::Hex,
+        };
+        let text = render_gtkw(&plan, None).expect("save file should render");
+        assert!(text.contains("[timestart] 20\n"));
+        assert!(text.contains(" 30 40 -1"));
+
+        plan.cursor_raw = Some(u64::MAX / 2);
+        assert!(render_gtkw(&plan, None).is_err());
+    }
+
+    #[test]
+    fn surfer_item_ids_follow_spreadsheet_letters() {
+        assert_eq!(surfer_item_id(0), "a");
+        assert_eq!(surfer_item_id(25), "z");
+        assert_eq!(surfer_item_id(26), "aa");
+        assert_eq!(surfer_item_id(27), "ab");
+    }
+
+    #[test]
+    fn marker_times_are_read_from_json_and_jsonl_results() {
+        let json = NamedTempFile::with_suffix(".json").expect("fixture should create");
+        fs::write(
+            json.path(),
+            r#"{"command":"property","data":[{"time":"5ns","kind":"match"},{"time":"9ns","kind":"match"}],"diagnostics":[]}"#,
+        )
+        .expect("fixture should write");
+        assert_eq!(
+            read_marker_times(json.path(), "export gtkw").expect("json should parse"),
+            vec!["5ns".to_string(), "9ns".to_string()]
+        );
+
+        let jsonl = NamedTempFile::with_suffix(".jsonl").expect("fixture should create");
+        fs::write(
+            jsonl.path(),
+            "{\"type\":\"begin\",\"command\":\"change\"}\n{\"type\":\"item\",\"item\":{\"time\":\"3ns\",\"signals\":[]}}\n{\"type\":\"end\"}\n",
+        )
+        .expect("fixture should write");
+        assert_eq!(
+            read_marker_times(jsonl.path(), "export gtkw").expect("jsonl should parse"),
+            vec!["3ns".to_string()]
+        );
+    }
+}
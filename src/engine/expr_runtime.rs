@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use std::path::Path;
 use std::rc::Rc;
 
+use crate::debug_trace::DebugTrace;
 use crate::error::WavepeekError;
 use crate::expr::sema::{
     BoundEventKind, BoundInsideItem, BoundLogicalKind, BoundLogicalNode, BoundSelection,
@@ -12,7 +13,9 @@ use crate::expr::{
     ExpressionHost, SampledValue, SignalHandle, Span, bind_event_expr_ast, bind_logical_expr_ast,
     eval_logical_expr_at, event_matches_at, parse_event_expr_ast, parse_logical_expr_ast,
 };
-use crate::waveform::{ExprResolvedSignal, Waveform, expr_host::WaveformExprHost};
+use crate::waveform::{
+    ChangeCandidateCollectionMode, ExprResolvedSignal, Waveform, expr_host::WaveformExprHost,
+};
 
 pub(crate) type SharedWaveform = Rc<RefCell<Waveform>>;
 
@@ -20,6 +23,43 @@ pub(crate) fn open_shared_waveform(path: &Path) -> Result<SharedWaveform, Wavepe
     Ok(Rc::new(RefCell::new(Waveform::open(path)?)))
 }
 
+/// Opens `path` between the `backend.open.start`/`backend.open.done` debug events.
+pub(crate) fn open_waveform_traced(
+    path: &Path,
+    debug: &DebugTrace,
+) -> Result<Waveform, WavepeekError> {
+    debug.event("backend.open.start", || serde_json::json!({}));
+    let waveform = Waveform::open(path)?;
+    debug.event("backend.open.done", || {
+        serde_json::json!({
+            "backend": waveform.backend_name(),
+            "format": waveform.format_name(),
+        })
+    });
+    Ok(waveform)
+}
+
+/// [`open_waveform_traced`] as a shared handle.
+pub(crate) fn open_shared_waveform_traced(
+    path: &Path,
+    debug: &DebugTrace,
+) -> Result<SharedWaveform, WavepeekError> {
+    Ok(Rc::new(RefCell::new(open_waveform_traced(path, debug)?)))
+}
+
+/// Lends an owned waveform to `f` as a shared handle and takes it back afterwards.
+pub(crate) fn with_shared_waveform<T>(
+    waveform: Waveform,
+    f: impl FnOnce(&SharedWaveform) -> Result<T, WavepeekError>,
+) -> Result<(Waveform, T), WavepeekError> {
+    let shared = Rc::new(RefCell::new(waveform));
+    let value = f(&shared)?;
+    let waveform = Rc::try_unwrap(shared)
+        .map_err(|_| WavepeekError::Internal("waveform is still shared".to_string()))?
+        .into_inner();
+    Ok((waveform, value))
+}
+
 pub(crate) struct ScopedExprHost<'a> {
     inner: &'a dyn ExpressionHost,
     scope: Option<&'a str>,
@@ -34,7 +74,18 @@ impl<'a> ScopedExprHost<'a> {
 impl ExpressionHost for ScopedExprHost<'_> {
     fn resolve_signal(&self, name: &str) -> Result<SignalHandle, ExprDiagnostic> {
         let resolved_name = match self.scope {
-            Some(_) if name.contains('.') => return Err(unknown_signal_diagnostic(name)),
+            // Dotted names under a scope are only field references into a struct, union, or
+            // packed signal layout.
+            Some(scope)
+                if name.split_once('.').is_some_and(|(head, _)| {
+                    !self.inner.is_aggregate(format!("{scope}.{head}").as_str())
+                        && !self
+                            .inner
+                            .is_packed_field(format!("{scope}.{name}").as_str())
+                }) =>
+            {
+                return Err(unknown_signal_diagnostic(name));
+            }
             Some(scope) => format!("{scope}.{name}"),
             None => name.to_string(),
         };
@@ -56,6 +107,30 @@ impl ExpressionHost for ScopedExprHost<'_> {
     fn event_occurred(&self, handle: SignalHandle, timestamp: u64) -> Result<bool, ExprDiagnostic> {
         self.inner.event_occurred(handle, timestamp)
     }
+
+    fn is_aggregate(&self, name: &str) -> bool {
+        match self.scope {
+            Some(scope) => self.inner.is_aggregate(format!("{scope}.{name}").as_str()),
+            None => self.inner.is_aggregate(name),
+        }
+    }
+
+    fn is_packed_field(&self, name: &str) -> bool {
+        match self.scope {
+            Some(scope) => self
+                .inner
+                .is_packed_field(format!("{scope}.{name}").as_str()),
+            None => self.inner.is_packed_field(name),
+        }
+    }
+
+    fn time_ticks(&self, token: &str) -> Result<u64, String> {
+        self.inner.time_ticks(token)
+    }
+
+    fn is_dump_timestamp(&self, timestamp: u64) -> bool {
+        self.inner.is_dump_timestamp(timestamp)
+    }
 }
 
 pub(crate) fn bind_waveform_event_expr(
@@ -63,7 +138,14 @@ pub(crate) fn bind_waveform_event_expr(
     scope: Option<&str>,
     source: &str,
 ) -> Result<(WaveformExprHost, BoundEventExpr), WavepeekError> {
-    let host = WaveformExprHost::from_shared(waveform);
+    bind_host_event_expr(WaveformExprHost::from_shared(waveform), scope, source)
+}
+
+pub(crate) fn bind_host_event_expr(
+    host: WaveformExprHost,
+    scope: Option<&str>,
+    source: &str,
+) -> Result<(WaveformExprHost, BoundEventExpr), WavepeekError> {
     let scoped = ScopedExprHost::new(&host, scope);
     let ast =
         parse_event_expr_ast(source).map_err(|diagnostic| expr_diagnostic(source, diagnostic))?;
@@ -107,6 +189,126 @@ pub(crate) fn event_expr_matches(
     event_matches_at(expr, host, frame).map_err(|diagnostic| expr_diagnostic(source, diagnostic))
 }
 
+/// Upper bound on the times one `every` term may add to a query window.
+const MAX_PERIODIC_EVENT_TIMES: u64 = 10_000_000;
+
+/// Candidate times of `sources` in `[from_raw, to_raw]` merged with the times selected by
+/// `every`, `at`, and `timestamps` terms of `expr`.
+pub(crate) fn collect_event_candidate_times(
+    waveform: &SharedWaveform,
+    expr: &BoundEventExpr,
+    sources: &[ExprResolvedSignal],
+    from_raw: u64,
+    to_raw: u64,
+    mode: ChangeCandidateCollectionMode,
+) -> Result<Vec<u64>, WavepeekError> {
+    let mut times = waveform
+        .borrow_mut()
+        .collect_expr_candidate_times_with_mode(sources, from_raw, to_raw, mode)?;
+    if !event_expr_has_time_terms(expr) {
+        return Ok(times);
+    }
+
+    for term in &expr.terms {
+        match &term.event {
+            BoundEventKind::Every { period, phase } => {
+                let Some(first) = first_periodic_time(*period, *phase, from_raw) else {
+                    continue;
+                };
+                if first > to_raw {
+                    continue;
+                }
+                if (to_raw - first) / period >= MAX_PERIODIC_EVENT_TIMES {
+                    return Err(WavepeekError::Args(format!(
+                        "periodic event term selects more than {MAX_PERIODIC_EVENT_TIMES} times in the query window; use a longer period or a narrower --from/--to range"
+                    )));
+                }
+                let mut time = first;
+                while time <= to_raw {
+                    times.push(time);
+                    let Some(next) = time.checked_add(*period) else {
+                        break;
+                    };
+                    time = next;
+                }
+            }
+            BoundEventKind::At(explicit) => times.extend(
+                explicit
+                    .iter()
+                    .copied()
+                    .filter(|time| (from_raw..=to_raw).contains(time)),
+            ),
+            BoundEventKind::Timestamps => {
+                let waveform = waveform.borrow();
+                let dump_times = waveform.indexed_timestamps().ok_or_else(|| {
+                    WavepeekError::Args(format!(
+                        "event term 'timestamps' is not supported for {} dumps; use '*' or named signal events instead",
+                        waveform.format_name()
+                    ))
+                })?;
+                times.extend(
+                    dump_times
+                        .iter()
+                        .copied()
+                        .filter(|time| (from_raw..=to_raw).contains(time)),
+                );
+            }
+            _ => {}
+        }
+    }
+    times.sort_unstable();
+    times.dedup();
+    Ok(times)
+}
+
+/// First grid time `phase + k * period` at or after `from_raw`, if representable.
+fn first_periodic_time(period: u64, phase: u64, from_raw: u64) -> Option<u64> {
+    if from_raw <= phase {
+        return Some(phase);
+    }
+    let steps = (from_raw - phase).div_ceil(period);
+    steps.checked_mul(period)?.checked_add(phase)
+}
+
+/// Times within `[from_raw, to_raw]` where the event `source` matches.
+///
+/// Returns `None` for events using the `*` wildcard, which name no signals to collect edges from.
+pub(crate) fn named_event_match_times(
+    waveform: &SharedWaveform,
+    scope: Option<&str>,
+    source: &str,
+    from_raw: u64,
+    to_raw: u64,
+) -> Result<Option<Vec<u64>>, WavepeekError> {
+    let (host, bound) = bind_waveform_event_expr(waveform.clone(), scope, source)?;
+    if event_expr_contains_wildcard(&bound) {
+        return Ok(None);
+    }
+    let sources = candidate_sources_for_handles(&host, &event_candidate_handles(&bound))?;
+    let candidate_times = collect_event_candidate_times(
+        waveform,
+        &bound,
+        sources.as_slice(),
+        from_raw,
+        to_raw,
+        ChangeCandidateCollectionMode::Auto,
+    )?;
+
+    let mut times = Vec::new();
+    for timestamp in candidate_times {
+        let previous_timestamp = waveform.borrow().previous_sample_time(timestamp);
+        let frame = EventEvalFrame {
+            timestamp,
+            previous_timestamp,
+            tracked_signals: &[],
+        };
+        if event_expr_matches(source, &bound, &host, &frame)? {
+            times.push(timestamp);
+        }
+    }
+    Ok(Some(times))
+}
+
 pub(crate) fn expr_diagnostic(source: &str, diagnostic: ExprDiagnostic) -> WavepeekError {
     WavepeekError::Expr(diagnostic.render(source))
 }
@@ -138,7 +340,10 @@ pub(crate) fn event_candidate_handles(expr: &BoundEventExpr) -> Vec<SignalHandle
     let mut seen = HashSet::new();
     for term in &expr.terms {
         let handle = match term.event {
-            BoundEventKind::AnyTracked => None,
+            BoundEventKind::AnyTracked
+            | BoundEventKind::Every { .. }
+            | BoundEventKind::At(_)
+            | BoundEventKind::Timestamps => None,
             BoundEventKind::Named(handle)
             | BoundEventKind::Posedge(handle)
             | BoundEventKind::Negedge(handle)
@@ -159,6 +364,16 @@ pub(crate) fn event_expr_contains_wildcard(expr: &BoundEventExpr) -> bool {
         .any(|term| matches!(term.event, BoundEventKind::AnyTracked))
 }
 
+/// Whether `expr` has `every`, `at`, or `timestamps` terms, whose times need not be signal changes.
+pub(crate) fn event_expr_has_time_terms(expr: &BoundEventExpr) -> bool {
+    expr.terms.iter().any(|term| {
+        matches!(
+            term.event,
+            BoundEventKind::Every { .. } | BoundEventKind::At(_) | BoundEventKind::Timestamps
+        )
+    })
+}
+
 pub(crate) fn event_expr_is_any_tracked_only(expr: &BoundEventExpr) -> bool {
     !expr.terms.is_empty()
         && expr
@@ -177,6 +392,23 @@ pub(crate) fn event_expr_is_edge_only(expr: &BoundEventExpr) -> bool {
         })
 }
 
+/// Whether every term of `expr` selects edges or `every`/`at`/`timestamps` times, which can be
+/// sampled relative to the trigger.
+pub(crate) fn event_expr_is_sampled_only(expr: &BoundEventExpr) -> bool {
+    !expr.terms.is_empty()
+        && expr.terms.iter().all(|term| {
+            matches!(
+                term.event,
+                BoundEventKind::Posedge(_)
+                    | BoundEventKind::Negedge(_)
+                    | BoundEventKind::Edge(_)
+                    | BoundEventKind::Every { .. }
+                    | BoundEventKind::At(_)
+                    | BoundEventKind::Timestamps
+            )
+        })
+}
+
 pub(crate) fn candidate_sources_for_handles(
     host: &WaveformExprHost,
     handles: &[SignalHandle],
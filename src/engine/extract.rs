@@ -8,26 +8,35 @@ use serde::{Deserialize, Serialize};
 
 use crate::cli::extract::GenericArgs;
 use crate::cli::limits::LimitArg;
+use crate::cli::sampling::SampleMode;
+use crate::cli::time_unit::OutputTimeUnit;
 use crate::contract::schema::{GENERIC_INPUT_SCHEMA_URLS, is_supported_generic_input_schema_url};
 use crate::debug_trace::DebugTrace;
 use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
 use crate::engine::expr_runtime::{
-    SharedWaveform, bind_waveform_event_expr, bind_waveform_logical_expr,
+    SharedWaveform, bind_host_event_expr, bind_waveform_logical_expr,
     candidate_sources_for_handles, eval_bound_logical_truth, event_candidate_handles,
     event_expr_is_edge_only, event_expr_matches, event_iff_handles, open_shared_waveform,
     referenced_signal_handles,
 };
+use crate::engine::sample_point::{required_edge_sample_offset, sample_time_before};
+use crate::engine::signal_layout::{SignalLayout, SignalLayouts};
+use crate::engine::signal_pattern::SignalPatternExpander;
 use crate::engine::time::{
-    DumpTimeContext, TimeValidationError, format_raw_timestamp, parse_dump_time_context,
-    validate_time_token_to_raw,
+    DumpTimeContext, TimeFormat, TimeValidationError, format_raw_timestamp,
+    parse_dump_time_context, validate_time_token_to_raw,
 };
+use crate::engine::time_expr::resolve_time_window;
+use crate::engine::value::ValueFields;
 use crate::engine::value_format::format_verilog_literal;
 use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
 use crate::error::WavepeekError;
 use crate::expr::{BoundEventExpr, BoundLogicalExpr, EventEvalFrame};
 use crate::waveform::{
     ChangeCandidateCollectionMode, ExprResolvedSignal, ResolvedSignal, SampledSignalState,
-    SignalId, expr_host::WaveformExprHost,
+    SignalId,
+    expr_host::{PackedFieldSource, WaveformExprHost},
 };
 
 const DEFAULT_SOURCE_NAME: &str = "transfer";
@@ -40,11 +49,21 @@ pub struct ExtractPayloadValue {
     pub display: String,
     pub path: String,
     pub value: String,
+    /// Field values decoded from `value` by a `--layout` entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<ValueFields>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ExtractGenericRow {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Dump tick of `time`, which `--html` strips are centered on.
+    #[serde(skip)]
+    pub raw_time: u64,
     pub sample_time: String,
     pub source: String,
     pub payload: Vec<ExtractPayloadValue>,
@@ -104,6 +123,7 @@ struct PayloadSignal {
     display: String,
     path: String,
     resolved: ResolvedSignal,
+    layout: Option<SignalLayout>,
 }
 
 #[derive(Debug)]
@@ -140,7 +160,12 @@ pub(crate) struct ExtractRunArgs {
     pub(crate) from: Option<String>,
     pub(crate) to: Option<String>,
     pub(crate) scope: Option<String>,
+    pub(crate) clock: Option<String>,
+    pub(crate) sample_mode: SampleMode,
+    pub(crate) time_unit: OutputTimeUnit,
+    pub(crate) ticks: bool,
     pub(crate) max: LimitArg,
+    pub(crate) layout: Option<PathBuf>,
 }
 
 struct ExtractEmitContext<'a> {
@@ -149,7 +174,9 @@ struct ExtractEmitContext<'a> {
     event_groups: &'a [EventGroup],
     dump_start_raw: u64,
     dump_end_raw: u64,
-    dump_tick: crate::engine::time::ParsedTime,
+    sample_offset: u64,
+    time_format: TimeFormat,
+    clock: Option<&'a CycleClock>,
     max_entries: Option<usize>,
     profile_timing: bool,
 }
@@ -280,7 +307,12 @@ fn run_with_sink<S: ExtractRowSink + ?Sized>(
             from: args.from,
             to: args.to,
             scope: args.scope,
+            clock: args.clock,
+            sample_mode: args.sample_mode,
+            time_unit: args.time_unit,
+            ticks: args.ticks,
             max: args.max,
+            layout: args.layout,
         },
         plan,
         sink,
@@ -336,7 +368,9 @@ fn run_open_plan_with_sink<S: ExtractRowSink + ?Sized>(
     let metadata = waveform.borrow().metadata()?;
     debug.event("metadata.load.done", || serde_json::json!({}));
     let dump_time = parse_dump_time_context(&metadata)?;
-    let dump_tick = dump_time.dump_tick;
+    let time_format = TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks);
+    let sample_offset =
+        required_edge_sample_offset(&args.sample_mode, dump_time, args.help_command)?;
     let dump_start_raw =
         u64::try_from(dump_time.dump_start_zs / dump_time.dump_tick_zs).map_err(|_| {
             WavepeekError::Internal("dump start timestamp exceeds supported range".to_string())
@@ -346,11 +380,28 @@ fn run_open_plan_with_sink<S: ExtractRowSink + ?Sized>(
             WavepeekError::Internal("dump end timestamp exceeds supported range".to_string())
         })?;
 
-    let from_raw = match args.from.as_deref() {
+    let clock = CycleClock::load(
+        &waveform,
+        args.scope.as_deref(),
+        args.clock.as_deref(),
+        dump_time,
+        args.help_command,
+    )?;
+    let window = resolve_time_window(
+        &waveform,
+        args.scope.as_deref(),
+        clock.as_ref(),
+        dump_time,
+        args.help_command,
+        args.from.as_deref(),
+        args.to.as_deref(),
+        &mut diagnostics,
+    )?;
+    let from_raw = match window.from.as_deref() {
         Some(token) => parse_bound_time(token, "--from", dump_time, &metadata, args.help_command)?,
         None => dump_start_raw,
     };
-    let to_raw = match args.to.as_deref() {
+    let to_raw = match window.to.as_deref() {
         Some(token) => parse_bound_time(token, "--to", dump_time, &metadata, args.help_command)?,
         None => dump_end_raw,
     };
@@ -383,7 +434,18 @@ fn run_open_plan_with_sink<S: ExtractRowSink + ?Sized>(
         })
     });
 
-    let bound_sources = bind_extract_sources(&waveform, args.scope.as_deref(), plan.sources)?;
+    let layouts = args
+        .layout
+        .as_deref()
+        .map(|path| SignalLayouts::load(path, args.help_command).map(Rc::new))
+        .transpose()?;
+    let bound_sources = bind_extract_sources(
+        &waveform,
+        args.scope.as_deref(),
+        plan.sources,
+        layouts.as_ref(),
+        &mut diagnostics,
+    )?;
     let event_groups = build_event_groups(&bound_sources)?;
     let event_group_candidate_sources = event_groups
         .iter()
@@ -417,9 +479,8 @@ fn run_open_plan_with_sink<S: ExtractRowSink + ?Sized>(
 
     if max_entries.is_none() {
         let preload_from_raw = from_raw
-            .checked_sub(1)
-            .filter(|value| *value >= dump_start_raw)
-            .unwrap_or(from_raw);
+            .saturating_sub(sample_offset.max(1))
+            .max(dump_start_raw);
         preload_extract_value_changes(
             &waveform,
             &bound_sources,
@@ -457,7 +518,9 @@ fn run_open_plan_with_sink<S: ExtractRowSink + ?Sized>(
         event_groups: &event_groups,
         dump_start_raw,
         dump_end_raw,
-        dump_tick,
+        sample_offset,
+        time_format,
+        clock: clock.as_ref(),
         max_entries,
         profile_timing: debug.is_enabled(),
     };
@@ -521,6 +584,23 @@ pub(crate) fn max_entries(max: &LimitArg) -> Result<Option<usize>, WavepeekError
     }
 }
 
+/// Cuts `rows` to `max_entries` and warns when anything was dropped.
+pub(crate) fn truncate_rows<T>(
+    rows: &mut Vec<T>,
+    max_entries: Option<usize>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let Some(max_entries) = max_entries
+        && rows.len() > max_entries
+    {
+        rows.truncate(max_entries);
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::OutputTruncated,
+            format!("truncated output to {max_entries} entries (use --max to increase limit)"),
+        ));
+    }
+}
+
 fn build_plan(args: &GenericArgs) -> Result<ExtractPlan, WavepeekError> {
     if let Some(path) = args.source.as_ref() {
         if args.name.is_some() || args.on.is_some() || args.when.is_some() || args.payload.is_some()
@@ -664,6 +744,8 @@ fn bind_extract_sources(
     waveform: &SharedWaveform,
     scope: Option<&str>,
     sources: Vec<ExtractSource>,
+    layouts: Option<&Rc<SignalLayouts>>,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<Vec<BoundExtractSource>, WavepeekError> {
     if let Some(scope) = scope {
         waveform.borrow().signals_in_scope(scope)?;
@@ -671,8 +753,10 @@ fn bind_extract_sources(
 
     let mut bound_sources = Vec::with_capacity(sources.len());
     for source in sources {
-        let (host, bound_event) =
-            bind_waveform_event_expr(waveform.clone(), scope, source.on.as_str())?;
+        let host = WaveformExprHost::from_shared(waveform.clone()).with_packed_fields(
+            layouts.map(|layouts| Rc::clone(layouts) as Rc<dyn PackedFieldSource>),
+        );
+        let (host, bound_event) = bind_host_event_expr(host, scope, source.on.as_str())?;
         if !event_expr_is_edge_only(&bound_event) {
             return Err(WavepeekError::Args(EDGE_ONLY_ON_MESSAGE.to_string()));
         }
@@ -687,7 +771,13 @@ fn bind_extract_sources(
         waveform
             .borrow()
             .validate_expr_values_supported(eval_sources.as_slice())?;
-        let payload = resolve_payload_signals(waveform, scope, source.payload.as_slice())?;
+        let payload = resolve_payload_signals(
+            waveform,
+            scope,
+            source.payload.as_slice(),
+            layouts.map(Rc::as_ref),
+            diagnostics,
+        )?;
         bound_sources.push(BoundExtractSource {
             declaration_index: source.declaration_index,
             name: source.name,
@@ -707,10 +797,24 @@ fn resolve_payload_signals(
     waveform: &SharedWaveform,
     scope: Option<&str>,
     payload: &[String],
+    layouts: Option<&SignalLayouts>,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<Vec<PayloadSignal>, WavepeekError> {
+    let waveform_ref = waveform.borrow();
+    let mut patterns =
+        SignalPatternExpander::new(&waveform_ref, scope, "payload", "wavepeek extract generic");
     let mut display_names = Vec::with_capacity(payload.len());
     let mut canonical_paths = Vec::with_capacity(payload.len());
+    let mut expanded_any = false;
     for token in payload {
+        if let Some(expanded) = patterns.expand(token, diagnostics)? {
+            for signal in expanded {
+                display_names.push(signal.display);
+                canonical_paths.push(signal.path);
+            }
+            expanded_any = true;
+            continue;
+        }
         if scope.is_some() && token.contains('.') {
             return Err(WavepeekError::Args(format!(
                 "payload signal '{token}' must be relative when --scope is set. See 'wavepeek extract generic --help'."
@@ -723,6 +827,10 @@ fn resolve_payload_signals(
         display_names.push(token.clone());
         canonical_paths.push(path);
     }
+    drop(waveform_ref);
+    if expanded_any {
+        require_unique_payloads(&display_names)?;
+    }
 
     let expr_resolved = waveform
         .borrow()
@@ -733,15 +841,22 @@ fn resolve_payload_signals(
     let resolved = waveform
         .borrow()
         .resolve_signals(canonical_paths.as_slice())?;
-    Ok(display_names
+    display_names
         .into_iter()
         .zip(resolved)
-        .map(|(display, resolved)| PayloadSignal {
-            display,
-            path: resolved.path.clone(),
-            resolved,
+        .map(|(display, resolved)| {
+            let layout = match layouts {
+                Some(layouts) => layouts.resolve(&waveform.borrow(), resolved.path.as_str())?,
+                None => None,
+            };
+            Ok(PayloadSignal {
+                display,
+                path: resolved.path.clone(),
+                resolved,
+                layout,
+            })
         })
-        .collect())
+        .collect()
 }
 
 fn build_event_groups(sources: &[BoundExtractSource]) -> Result<Vec<EventGroup>, WavepeekError> {
@@ -997,9 +1112,9 @@ fn emit_rows<S: ExtractRowSink + ?Sized>(
             });
         }
 
-        let Some(sample_timestamp) = timestamp
-            .checked_sub(1)
-            .filter(|value| *value >= context.dump_start_raw && *value <= context.dump_end_raw)
+        let Some(sample_timestamp) =
+            sample_time_before(timestamp, context.sample_offset, context.dump_start_raw)
+                .filter(|value| *value <= context.dump_end_raw)
         else {
             skipped_no_sample_time += matched_source_indices.len();
             continue;
@@ -1033,7 +1148,8 @@ fn emit_rows<S: ExtractRowSink + ?Sized>(
                 source,
                 timestamp,
                 sample_timestamp,
-                context.dump_tick,
+                context.time_format,
+                context.clock,
                 context.waveform,
             )?;
             sink.emit(row)?;
@@ -1066,7 +1182,8 @@ fn build_row(
     source: &BoundExtractSource,
     timestamp: u64,
     sample_timestamp: u64,
-    dump_tick: crate::engine::time::ParsedTime,
+    time_format: TimeFormat,
+    clock: Option<&CycleClock>,
     waveform: &SharedWaveform,
 ) -> Result<ExtractGenericRow, WavepeekError> {
     let resolved = source
@@ -1085,8 +1202,11 @@ fn build_row(
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(ExtractGenericRow {
-        time: format_raw_timestamp(timestamp, dump_tick)?,
-        sample_time: format_raw_timestamp(sample_timestamp, dump_tick)?,
+        time: time_format.format(timestamp)?,
+        tick: time_format.tick(timestamp),
+        cycle: clock.and_then(|clock| clock.cycle_at(timestamp)),
+        raw_time: timestamp,
+        sample_time: time_format.format(sample_timestamp)?,
         source: source.name.clone(),
         payload,
     })
@@ -1106,6 +1226,10 @@ fn build_payload_value(
         display: requested.display.clone(),
         path: requested.path.clone(),
         value: format_verilog_literal(sampled.width, bits.as_str()),
+        fields: requested
+            .layout
+            .as_ref()
+            .map(|layout| layout.decode(bits.as_str())),
     })
 }
 
@@ -1149,6 +1273,8 @@ mod tests {
     use super::{build_plan, normalize_payload, require_unique_payloads};
     use crate::cli::extract::GenericArgs;
     use crate::cli::limits::LimitArg;
+    use crate::cli::sampling::SampleMode;
+    use crate::cli::time_unit::OutputTimeUnit;
 
     #[test]
     fn cli_plan_defaults_source_name_and_payload_order() {
@@ -1158,14 +1284,20 @@ mod tests {
             from: None,
             to: None,
             scope: None,
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
+            sample_mode: SampleMode::PreEdge,
             name: None,
             on: Some("posedge clk".to_string()),
             when: Some("valid".to_string()),
             payload: Some(vec!["data".to_string(), "last".to_string()]),
+            layout: None,
             max: LimitArg::Numeric(50),
             abs: false,
             json: false,
             jsonl: false,
+            html: None,
         })
         .expect("plan should build");
         assert_eq!(plan.sources[0].name, "transfer");
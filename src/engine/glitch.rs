@@ -0,0 +1,287 @@
+use serde::Serialize;
+
+use crate::cli::glitch::GlitchArgs;
+use crate::debug_trace::DebugTrace;
+use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
+use crate::engine::expr_runtime::{open_waveform_traced, with_shared_waveform};
+use crate::engine::extract::{initial_diagnostics, max_entries, truncate_rows};
+use crate::engine::signal_pattern::{ExpandedSignal, resolve_signal_tokens};
+use crate::engine::time::{DumpTimeContext, TimeFormat, duration_ticks};
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::value_format::format_verilog_literal;
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::waveform::SignalTransitions;
+
+const HELP: &str = "wavepeek glitch";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlitchKind {
+    /// A settled value held for less than `--min-width`.
+    Pulse,
+    /// A value the dump recorded and replaced within one timestamp.
+    ZeroWidth,
+}
+
+impl std::fmt::Display for GlitchKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pulse => f.write_str("pulse"),
+            Self::ZeroWidth => f.write_str("zero_width"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GlitchRow {
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    /// `--clock` cycle of `time`, `None` without `--clock` or before its first edge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    pub kind: GlitchKind,
+    #[serde(skip_serializing)]
+    pub display: String,
+    pub path: String,
+    /// Value before the glitch, `None` when it starts the signal's recorded history.
+    pub previous: Option<String>,
+    /// Value held during the glitch.
+    pub value: String,
+    /// Value that replaced the glitch value.
+    pub next: String,
+    pub width: String,
+}
+
+pub fn run(args: GlitchArgs) -> Result<CommandResult, WavepeekError> {
+    let output_mode = crate::output_mode::OutputMode::from_json_flags(args.json, args.jsonl);
+    let max_entries = max_entries(&args.max)?;
+    let mut diagnostics = initial_diagnostics(&args.max);
+
+    let debug = DebugTrace::for_command(CommandName::Glitch);
+    let waveform = open_waveform_traced(args.waves.as_path(), &debug)?;
+    let signals = resolve_signal_tokens(
+        &waveform,
+        args.scope.as_deref(),
+        &args.signals,
+        HELP,
+        &mut diagnostics,
+    )?;
+    let (
+        mut waveform,
+        RawTimeWindow {
+            dump_time,
+            dump_start_raw,
+            dump_end_raw,
+            from_raw,
+            to_raw,
+            clock,
+            ..
+        },
+    ) = with_shared_waveform(waveform, |waveform| {
+        resolve_raw_window(
+            waveform,
+            args.scope.as_deref(),
+            args.clock.as_deref(),
+            HELP,
+            args.from.as_deref(),
+            args.to.as_deref(),
+            &debug,
+            &mut diagnostics,
+        )
+    })?;
+    let min_width = args
+        .min_width
+        .as_deref()
+        .map(|token| parse_min_width(token, dump_time))
+        .transpose()?;
+    debug.event("time.parse.done", || serde_json::json!({}));
+
+    let paths = signals
+        .iter()
+        .map(|signal| signal.path.clone())
+        .collect::<Vec<_>>();
+    let resolved = waveform.resolve_signals(&paths)?;
+    // A pulse starting by `--to` is shorter than `--min-width` only if it also ends before this.
+    let history_end_raw = to_raw
+        .saturating_add(min_width.unwrap_or(0))
+        .min(dump_end_raw);
+    let histories = waveform.signal_transitions(&resolved, dump_start_raw, history_end_raw)?;
+
+    let scan = GlitchScan {
+        start_raw: dump_start_raw,
+        from_raw,
+        to_raw,
+        min_width,
+        time_format: TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks),
+        clock: clock.as_ref(),
+    };
+    let mut found = Vec::new();
+    for ((signal, resolved), history) in signals.iter().zip(&resolved).zip(&histories) {
+        scan.signal_glitches(signal, resolved.width, history, &mut found)?;
+    }
+    found.sort_by(|(left_time, left), (right_time, right)| {
+        left_time
+            .cmp(right_time)
+            .then_with(|| left.path.cmp(&right.path))
+            .then_with(|| left.kind.cmp(&right.kind))
+    });
+    let mut rows = found.into_iter().map(|(_, row)| row).collect::<Vec<_>>();
+    debug.event(
+        "glitch.scan.done",
+        || serde_json::json!({"rows": rows.len()}),
+    );
+
+    if rows.is_empty() {
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::EmptyResult,
+            "no glitches found in selected time range",
+        ));
+    }
+    truncate_rows(&mut rows, max_entries, &mut diagnostics);
+
+    Ok(CommandResult {
+        command: CommandName::Glitch,
+        output_mode,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: args.abs,
+        },
+        data: CommandData::Glitch(rows),
+        diagnostics,
+    })
+}
+
+/// Glitch scan settings shared by every signal.
+struct GlitchScan<'a> {
+    start_raw: u64,
+    from_raw: u64,
+    to_raw: u64,
+    min_width: Option<u64>,
+    time_format: TimeFormat,
+    clock: Option<&'a CycleClock>,
+}
+
+impl GlitchScan<'_> {
+    fn signal_glitches(
+        &self,
+        signal: &ExpandedSignal,
+        width: u32,
+        history: &SignalTransitions,
+        found: &mut Vec<(u64, GlitchRow)>,
+    ) -> Result<(), WavepeekError> {
+        let mut previous = history.initial.as_deref();
+        let mut settled = previous
+            .map(|value| (self.start_raw, value))
+            .into_iter()
+            .collect::<Vec<_>>();
+        for change in &history.changes {
+            // Consecutive equal writes are one value; the first entry is the value before.
+            let mut sequence = vec![previous];
+            for value in &change.values {
+                if sequence.last() != Some(&Some(value.as_str())) {
+                    sequence.push(Some(value.as_str()));
+                }
+            }
+            if self.in_range(change.time) {
+                for step in sequence.windows(3) {
+                    let (Some(value), Some(next)) = (step[1], step[2]) else {
+                        continue;
+                    };
+                    found.push((
+                        change.time,
+                        self.row(
+                            signal,
+                            width,
+                            GlitchKind::ZeroWidth,
+                            change.time,
+                            0,
+                            step[0],
+                            value,
+                            next,
+                        )?,
+                    ));
+                }
+            }
+
+            let Some(&Some(value)) = sequence.last() else {
+                continue;
+            };
+            if previous != Some(value) {
+                settled.push((change.time, value));
+                previous = Some(value);
+            }
+        }
+
+        let Some(min_width) = self.min_width else {
+            return Ok(());
+        };
+        for step in settled.windows(3) {
+            let [(_, before), (time, value), (end, next)] = [step[0], step[1], step[2]];
+            let pulse_width = end - time;
+            if pulse_width >= min_width || !self.in_range(time) {
+                continue;
+            }
+            found.push((
+                time,
+                self.row(
+                    signal,
+                    width,
+                    GlitchKind::Pulse,
+                    time,
+                    pulse_width,
+                    Some(before),
+                    value,
+                    next,
+                )?,
+            ));
+        }
+        Ok(())
+    }
+
+    fn in_range(&self, raw_time: u64) -> bool {
+        (self.from_raw..=self.to_raw).contains(&raw_time)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn row(
+        &self,
+        signal: &ExpandedSignal,
+        width: u32,
+        kind: GlitchKind,
+        raw_time: u64,
+        pulse_width: u64,
+        previous: Option<&str>,
+        value: &str,
+        next: &str,
+    ) -> Result<GlitchRow, WavepeekError> {
+        Ok(GlitchRow {
+            time: self.time_format.format(raw_time)?,
+            tick: self.time_format.tick(raw_time),
+            cycle: self.clock.and_then(|clock| clock.cycle_at(raw_time)),
+            kind,
+            display: signal.display.clone(),
+            path: signal.path.clone(),
+            previous: previous.map(|bits| format_verilog_literal(width, bits)),
+            value: format_verilog_literal(width, value),
+            next: format_verilog_literal(width, next),
+            width: self.time_format.format(pulse_width)?,
+        })
+    }
+}
+
+fn parse_min_width(token: &str, dump_time: DumpTimeContext) -> Result<u64, WavepeekError> {
+    let ticks = duration_ticks(token, dump_time).map_err(|reason| {
+        WavepeekError::Args(format!(
+            "invalid --min-width '{token}': {reason}. See '{HELP} --help'."
+        ))
+    })?;
+    if ticks == 0 {
+        return Err(WavepeekError::Args(format!(
+            "--min-width must be greater than zero. See '{HELP} --help'."
+        )));
+    }
+    Ok(ticks)
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::error::WavepeekError;
+
+/// Where a listed entry stands when the listing is compared against a second dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CompareStatus {
+    OnlyA,
+    OnlyB,
+    Changed,
+}
+
+impl CompareStatus {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::OnlyA => "only-a",
+            Self::OnlyB => "only-b",
+            Self::Changed => "changed",
+        }
+    }
+}
+
+/// Comparison result attached to an entry of a `--compare` listing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompareInfo {
+    pub status: CompareStatus,
+    /// Kind recorded in dump B, present for `changed` entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Width recorded in dump B, present for `changed` entries that have one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+}
+
+impl CompareInfo {
+    const fn only(status: CompareStatus) -> Self {
+        Self {
+            status,
+            kind: None,
+            width: None,
+        }
+    }
+}
+
+/// One `--remap-prefix A=B` rule mapping dump-A paths onto dump-B paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixRemap {
+    a: String,
+    b: String,
+}
+
+impl PrefixRemap {
+    pub fn parse(command: &str, raw: &str) -> Result<Self, WavepeekError> {
+        match raw.split_once('=') {
+            Some((a, b)) if !a.trim().is_empty() && !b.trim().is_empty() => Ok(Self {
+                a: a.trim().to_string(),
+                b: b.trim().to_string(),
+            }),
+            _ => Err(WavepeekError::Args(format!(
+                "invalid --remap-prefix '{raw}': expected A_PREFIX=B_PREFIX. See 'wavepeek {command} --help'."
+            ))),
+        }
+    }
+}
+
+/// Rewrites a dump-A path into dump-B names using the first matching rule.
+pub fn a_to_b(remaps: &[PrefixRemap], path: &str) -> String {
+    rewrite(remaps.iter().map(|remap| (&remap.a, &remap.b)), path)
+}
+
+/// Rewrites a dump-B path into dump-A names using the first matching rule.
+pub fn b_to_a(remaps: &[PrefixRemap], path: &str) -> String {
+    rewrite(remaps.iter().map(|remap| (&remap.b, &remap.a)), path)
+}
+
+fn rewrite<'a>(rules: impl Iterator<Item = (&'a String, &'a String)>, path: &str) -> String {
+    for (from, to) in rules {
+        if path == from {
+            return to.clone();
+        }
+        if let Some(rest) = path
+            .strip_prefix(from.as_str())
+            .and_then(|rest| rest.strip_prefix('.'))
+        {
+            return format!("{to}.{rest}");
+        }
+    }
+    path.to_string()
+}
+
+/// Pairs entries of two listings by path, mapping dump-B paths back to dump-A names first.
+///
+/// Entries present on both sides with equal `(kind, width)` are dropped. Dump-A entries come
+/// first in their listing order, followed by entries found only in dump B.
+pub fn compare_listings<T>(
+    a: Vec<T>,
+    b: Vec<T>,
+    remaps: &[PrefixRemap],
+    path: impl Fn(&T) -> &str,
+    shape: impl Fn(&T) -> (&str, Option<u32>),
+) -> Vec<(T, CompareInfo)> {
+    let mut b_by_path = HashMap::with_capacity(b.len());
+    for (index, entry) in b.iter().enumerate() {
+        b_by_path
+            .entry(b_to_a(remaps, path(entry)))
+            .or_insert(index);
+    }
+
+    let mut matched = vec![false; b.len()];
+    let mut out = Vec::new();
+    for entry in a {
+        let Some(&index) = b_by_path.get(path(&entry)) else {
+            out.push((entry, CompareInfo::only(CompareStatus::OnlyA)));
+            continue;
+        };
+        matched[index] = true;
+        let (b_kind, b_width) = shape(&b[index]);
+        if shape(&entry) != (b_kind, b_width) {
+            let info = CompareInfo {
+                status: CompareStatus::Changed,
+                kind: Some(b_kind.to_string()),
+                width: b_width,
+            };
+            out.push((entry, info));
+        }
+    }
+    out.extend(
+        b.into_iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(entry, _)| (entry, CompareInfo::only(CompareStatus::OnlyB))),
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompareStatus, PrefixRemap, a_to_b, b_to_a, compare_listings};
+
+    #[test]
+    fn remap_rewrites_whole_segments_only() {
+        let remaps = vec![PrefixRemap::parse("scope", "tb_a.dut=tb_b.dut").expect("valid remap")];
+        assert_eq!(a_to_b(&remaps, "tb_a.dut"), "tb_b.dut");
+        assert_eq!(a_to_b(&remaps, "tb_a.dut.clk"), "tb_b.dut.clk");
+        assert_eq!(a_to_b(&remaps, "tb_a.dut2.clk"), "tb_a.dut2.clk");
+        assert_eq!(b_to_a(&remaps, "tb_b.dut.core"), "tb_a.dut.core");
+        assert!(PrefixRemap::parse("scope", "tb_a").is_err());
+        assert!(PrefixRemap::parse("scope", "=tb_b").is_err());
+    }
+
+    #[test]
+    fn compare_listings_reports_one_sided_and_changed_entries() {
+        let a = vec![("top.clk", "wire", Some(1)), ("top.data", "wire", Some(8))];
+        let b = vec![
+            ("tb.new", "reg", Some(1)),
+            ("tb.data", "reg", Some(16)),
+            ("tb.clk", "wire", Some(1)),
+        ];
+        let remaps = vec![PrefixRemap::parse("signal", "top=tb").expect("valid remap")];
+        let diff = compare_listings(a, b, &remaps, |entry| entry.0, |entry| (entry.1, entry.2));
+        let summary = diff
+            .iter()
+            .map(|(entry, info)| (entry.0, info.status, info.kind.as_deref(), info.width))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("top.data", CompareStatus::Changed, Some("reg"), Some(16)),
+                ("tb.new", CompareStatus::OnlyB, None, None),
+            ]
+        );
+    }
+}
@@ -1,21 +1,54 @@
+use std::collections::HashSet;
+
 use crate::cli::info::InfoArgs;
 use crate::debug_trace::DebugTrace;
+use crate::engine::time::parse_dump_time_context;
 use crate::engine::{CommandData, CommandName, CommandResult};
 use crate::error::WavepeekError;
-use crate::waveform::Waveform;
+use crate::waveform::{SignalEntry, Waveform};
 use serde::Serialize;
 
+/// Signals resolved and loaded per backend call while counting activity.
+const ACTIVITY_BATCH: usize = 256;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct InfoData {
     pub time_unit: String,
     pub time_start: String,
     pub time_end: String,
+    pub format: String,
+    pub backend: String,
+    pub scope_count: usize,
+    pub signal_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub simulator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timescale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity: Option<Vec<InfoActivity>>,
+}
+
+/// Value-change count of one net over the whole dump.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InfoActivity {
+    pub path: String,
+    pub changes: u64,
 }
 
 pub fn run(args: InfoArgs) -> Result<CommandResult, WavepeekError> {
+    if args.activity == Some(0) {
+        return Err(WavepeekError::Args(
+            "--activity must be greater than 0. See 'wavepeek info --help'.".to_string(),
+        ));
+    }
+
     let debug = DebugTrace::for_command(CommandName::Info);
     debug.event("backend.open.start", || serde_json::json!({}));
-    let waveform = Waveform::open(args.waves.as_path())?;
+    let mut waveform = Waveform::open(args.waves.as_path())?;
     debug.event("backend.open.done", || {
         serde_json::json!({
             "backend": waveform.backend_name(),
@@ -23,8 +56,35 @@ pub fn run(args: InfoArgs) -> Result<CommandResult, WavepeekError> {
         })
     });
     let metadata = waveform.metadata()?;
+    let header = waveform.header();
     debug.event("metadata.load.done", || serde_json::json!({}));
 
+    let scopes = waveform.scopes_depth_first(None)?;
+    let mut signals = Vec::new();
+    for scope in &scopes {
+        signals.extend(waveform.signals_in_scope(scope.path.as_str())?);
+    }
+    debug.event(
+        "info.hierarchy.done",
+        || serde_json::json!({"scopes": scopes.len(), "signals": signals.len()}),
+    );
+
+    let activity = match args.activity {
+        Some(limit) => {
+            let dump_time = parse_dump_time_context(&metadata)?;
+            let start_raw = raw_bound(dump_time.dump_start_zs / dump_time.dump_tick_zs)?;
+            let end_raw = raw_bound(dump_time.dump_end_zs / dump_time.dump_tick_zs)?;
+            Some(most_active(
+                &mut waveform,
+                &signals,
+                start_raw,
+                end_raw,
+                limit,
+            )?)
+        }
+        None => None,
+    };
+
     Ok(CommandResult {
         command: CommandName::Info,
         output_mode: crate::output_mode::OutputMode::from_json_flags(args.json, args.jsonl),
@@ -33,7 +93,69 @@ pub fn run(args: InfoArgs) -> Result<CommandResult, WavepeekError> {
             time_unit: metadata.time_unit,
             time_start: metadata.time_start,
             time_end: metadata.time_end,
+            format: waveform.format_name().to_string(),
+            backend: waveform.backend_name().to_string(),
+            scope_count: scopes.len(),
+            signal_count: signals.len(),
+            timestamp_count: waveform.indexed_timestamps().map(<[u64]>::len),
+            date: header.date,
+            simulator: header.simulator,
+            timescale: header.timescale,
+            activity,
         }),
         diagnostics: Vec::new(),
     })
 }
+
+fn raw_bound(raw: u128) -> Result<u64, WavepeekError> {
+    u64::try_from(raw)
+        .map_err(|_| WavepeekError::Internal("dump timestamp exceeds supported range".to_string()))
+}
+
+/// Counts value changes per net, including zero-delay glitches, listing each net once under its first path.
+fn most_active(
+    waveform: &mut Waveform,
+    signals: &[SignalEntry],
+    start_raw: u64,
+    end_raw: u64,
+    limit: usize,
+) -> Result<Vec<InfoActivity>, WavepeekError> {
+    let paths = signals
+        .iter()
+        .filter(|signal| signal.width.is_some() && signal.kind != "event")
+        .map(|signal| signal.path.clone())
+        .collect::<Vec<_>>();
+
+    let mut seen = HashSet::new();
+    let mut activity = Vec::new();
+    for batch in paths.chunks(ACTIVITY_BATCH) {
+        let resolved = waveform
+            .resolve_signals(batch)?
+            .into_iter()
+            .filter(|signal| seen.insert(signal.id))
+            .collect::<Vec<_>>();
+        let histories = waveform.signal_transitions(&resolved, start_raw, end_raw)?;
+        for (signal, history) in resolved.into_iter().zip(histories) {
+            let mut previous = history.initial;
+            let mut changes = 0;
+            for value in history.changes.into_iter().flat_map(|change| change.values) {
+                if previous.as_ref() != Some(&value) {
+                    changes += 1;
+                    previous = Some(value);
+                }
+            }
+            activity.push(InfoActivity {
+                path: signal.path,
+                changes,
+            });
+        }
+    }
+
+    activity.sort_by(|lhs, rhs| {
+        rhs.changes
+            .cmp(&lhs.changes)
+            .then_with(|| lhs.path.cmp(&rhs.path))
+    });
+    activity.truncate(limit);
+    Ok(activity)
+}
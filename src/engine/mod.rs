@@ -1,22 +1,39 @@
 pub mod ahb;
 pub mod apb;
 pub mod atb;
+pub mod audit;
 pub mod axi;
 pub mod axistream;
+pub mod cdc;
 pub mod change;
+pub mod clock;
+mod cycle_clock;
 pub mod docs;
+pub mod export;
 mod expr_runtime;
 pub mod extract;
+pub mod glitch;
+pub mod hierarchy_diff;
 pub mod info;
 pub mod property;
+pub mod report;
+pub mod saif;
+mod sample_point;
 pub mod schema;
 pub mod scope;
 pub mod signal;
+mod signal_layout;
 mod signal_mapping;
+mod signal_pattern;
+pub(crate) mod signal_select;
 pub mod skill;
+pub mod sqlite;
 pub mod time;
+mod time_expr;
+pub mod timing;
 pub mod value;
 mod value_format;
+pub mod view;
 
 use serde::Serialize;
 
@@ -35,12 +52,22 @@ pub enum Command {
     Value(cli::value::ValueArgs),
     Change(cli::change::ChangeArgs),
     Property(cli::property::PropertyArgs),
+    Cdc(cli::cdc::CdcArgs),
+    Timing(cli::timing::TimingArgs),
+    Glitch(cli::glitch::GlitchArgs),
+    Audit(cli::audit::AuditArgs),
+    Clock(cli::clock::ClockArgs),
+    View(cli::view::ViewArgs),
+    Saif(cli::saif::SaifArgs),
     ExtractAhb(cli::extract::AhbArgs),
     ExtractApb(cli::extract::ApbArgs),
     ExtractAtb(cli::extract::AtbArgs),
     ExtractAxi(cli::extract::AxiArgs),
     ExtractAxiStream(cli::extract::AxiStreamArgs),
     ExtractGeneric(cli::extract::GenericArgs),
+    ExportGtkw(cli::export::GtkwArgs),
+    ExportSurfer(cli::export::SurferArgs),
+    ExportSqlite(cli::export::SqliteArgs),
     Docs(cli::docs::DocsArgs),
     Skill(cli::skill::SkillArgs),
 }
@@ -55,12 +82,22 @@ pub enum CommandName {
     Value,
     Change,
     Property,
+    Cdc,
+    Timing,
+    Glitch,
+    Audit,
+    Clock,
+    View,
+    Saif,
     ExtractAhb,
     ExtractApb,
     ExtractAtb,
     ExtractAxi,
     ExtractAxiStream,
     ExtractGeneric,
+    ExportGtkw,
+    ExportSurfer,
+    ExportSqlite,
     Docs,
     DocsTopics,
     DocsShow,
@@ -79,17 +116,41 @@ impl Command {
             Self::Value(_) => CommandName::Value,
             Self::Change(_) => CommandName::Change,
             Self::Property(_) => CommandName::Property,
+            Self::Cdc(_) => CommandName::Cdc,
+            Self::Timing(_) => CommandName::Timing,
+            Self::Glitch(_) => CommandName::Glitch,
+            Self::Audit(_) => CommandName::Audit,
+            Self::Clock(_) => CommandName::Clock,
+            Self::View(_) => CommandName::View,
+            Self::Saif(_) => CommandName::Saif,
             Self::ExtractAhb(_) => CommandName::ExtractAhb,
             Self::ExtractApb(_) => CommandName::ExtractApb,
             Self::ExtractAtb(_) => CommandName::ExtractAtb,
             Self::ExtractAxi(_) => CommandName::ExtractAxi,
             Self::ExtractAxiStream(_) => CommandName::ExtractAxiStream,
             Self::ExtractGeneric(_) => CommandName::ExtractGeneric,
+            Self::ExportGtkw(_) => CommandName::ExportGtkw,
+            Self::ExportSurfer(_) => CommandName::ExportSurfer,
+            Self::ExportSqlite(_) => CommandName::ExportSqlite,
             Self::Docs(_) => CommandName::Docs,
             Self::Skill(_) => CommandName::Skill,
         }
     }
 
+    /// Target file of `--html`, for commands that support HTML reports.
+    pub fn html_path(&self) -> Option<&std::path::Path> {
+        match self {
+            Self::Property(args) => args.html.as_deref(),
+            Self::ExtractAhb(args) => args.html.as_deref(),
+            Self::ExtractApb(args) => args.html.as_deref(),
+            Self::ExtractAtb(args) => args.html.as_deref(),
+            Self::ExtractAxi(args) => args.html.as_deref(),
+            Self::ExtractAxiStream(args) => args.html.as_deref(),
+            Self::ExtractGeneric(args) => args.html.as_deref(),
+            _ => None,
+        }
+    }
+
     pub const fn output_mode(&self) -> OutputMode {
         match self {
             Self::Schema(_) => OutputMode::Human,
@@ -99,13 +160,24 @@ impl Command {
             Self::Value(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::Change(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::Property(args) => OutputMode::from_json_flags(args.json, args.jsonl),
+            Self::Cdc(args) => OutputMode::from_json_flags(args.json, args.jsonl),
+            Self::Timing(args) => OutputMode::from_json_flags(args.json, args.jsonl),
+            Self::Glitch(args) => OutputMode::from_json_flags(args.json, args.jsonl),
+            Self::Audit(args) => OutputMode::from_json_flags(args.json, args.jsonl),
+            Self::Clock(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::ExtractAhb(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::ExtractApb(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::ExtractAtb(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::ExtractAxi(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::ExtractAxiStream(args) => OutputMode::from_json_flags(args.json, args.jsonl),
             Self::ExtractGeneric(args) => OutputMode::from_json_flags(args.json, args.jsonl),
-            Self::Docs(_) | Self::Skill(_) => OutputMode::Human,
+            Self::View(_)
+            | Self::Saif(_)
+            | Self::ExportGtkw(_)
+            | Self::ExportSurfer(_)
+            | Self::ExportSqlite(_)
+            | Self::Docs(_)
+            | Self::Skill(_) => OutputMode::Human,
         }
     }
 }
@@ -120,12 +192,22 @@ impl CommandName {
             Self::Value => "value",
             Self::Change => "change",
             Self::Property => "property",
+            Self::Cdc => "cdc",
+            Self::Timing => "timing",
+            Self::Glitch => "glitch",
+            Self::Audit => "audit",
+            Self::Clock => "clock",
+            Self::View => "view",
+            Self::Saif => "saif",
             Self::ExtractAhb => "extract ahb",
             Self::ExtractApb => "extract apb",
             Self::ExtractAtb => "extract atb",
             Self::ExtractAxi => "extract axi",
             Self::ExtractAxiStream => "extract axistream",
             Self::ExtractGeneric => "extract generic",
+            Self::ExportGtkw => "export gtkw",
+            Self::ExportSurfer => "export surfer",
+            Self::ExportSqlite => "export sqlite",
             Self::Docs => "docs",
             Self::DocsTopics => "docs topics",
             Self::DocsShow => "docs show",
@@ -172,6 +254,12 @@ pub enum CommandData {
     Value(value::ValueData),
     Change(Vec<change::ChangeSnapshot>),
     Property(Vec<property::PropertyCaptureRow>),
+    Cdc(Vec<cdc::CdcHazard>),
+    Timing(Vec<timing::TimingViolation>),
+    Glitch(Vec<glitch::GlitchRow>),
+    Audit(Vec<audit::AuditRow>),
+    Clock(clock::ClockData),
+    Wave(view::WaveData),
     ExtractAhb(ahb::AhbData),
     ExtractApb(apb::ApbData),
     ExtractAtb(atb::AtbData),
@@ -203,12 +291,22 @@ pub fn run(command: Command) -> Result<CommandResult, WavepeekError> {
         Command::Value(args) => value::run(args),
         Command::Change(args) => change::run(args),
         Command::Property(args) => property::run(args),
+        Command::Cdc(args) => cdc::run(args),
+        Command::Timing(args) => timing::run(args),
+        Command::Glitch(args) => glitch::run(args),
+        Command::Audit(args) => audit::run(args),
+        Command::Clock(args) => clock::run(args),
+        Command::View(args) => view::run(args),
+        Command::Saif(args) => saif::run(args),
         Command::ExtractAhb(args) => ahb::run(args),
         Command::ExtractApb(args) => apb::run(args),
         Command::ExtractAtb(args) => atb::run(args),
         Command::ExtractAxi(args) => axi::run(args),
         Command::ExtractAxiStream(args) => axistream::run(args),
         Command::ExtractGeneric(args) => extract::run(args),
+        Command::ExportGtkw(args) => export::run_gtkw(args),
+        Command::ExportSurfer(args) => export::run_surfer(args),
+        Command::ExportSqlite(args) => sqlite::run(args),
         Command::Docs(args) => docs::run(args),
         Command::Skill(args) => skill::run(args),
     }
@@ -227,11 +325,29 @@ pub fn run_jsonl<W: std::io::Write>(
         Command::ExtractAxi(args) => axi::run_jsonl(args, writer),
         Command::ExtractAxiStream(args) => axistream::run_jsonl(args, writer),
         Command::ExtractGeneric(args) => extract::run_jsonl(args, writer),
-        Command::Info(_) | Command::Scope(_) | Command::Signal(_) | Command::Value(_) => {
+        Command::Info(_)
+        | Command::Scope(_)
+        | Command::Signal(_)
+        | Command::Value(_)
+        | Command::Cdc(_)
+        | Command::Timing(_)
+        | Command::Glitch(_)
+        | Command::Audit(_)
+        | Command::Clock(_) => {
             let result = run(command)?;
             output::write_jsonl_result(result, writer)
         }
-        Command::Schema(_) | Command::Docs(_) | Command::Skill(_) => Err(WavepeekError::Args(
+        Command::View(_) => Err(WavepeekError::Args(
+            "--jsonl is not available for view; use change --jsonl for machine-readable rows"
+                .to_string(),
+        )),
+        Command::Schema(_)
+        | Command::Saif(_)
+        | Command::ExportGtkw(_)
+        | Command::ExportSurfer(_)
+        | Command::ExportSqlite(_)
+        | Command::Docs(_)
+        | Command::Skill(_) => Err(WavepeekError::Args(
             "--jsonl is available only for waveform commands".to_string(),
         )),
     }
@@ -250,12 +366,22 @@ mod tests {
         assert_eq!(CommandName::Value.as_str(), "value");
         assert_eq!(CommandName::Change.as_str(), "change");
         assert_eq!(CommandName::Property.as_str(), "property");
+        assert_eq!(CommandName::Cdc.as_str(), "cdc");
+        assert_eq!(CommandName::Timing.as_str(), "timing");
+        assert_eq!(CommandName::Glitch.as_str(), "glitch");
+        assert_eq!(CommandName::Audit.as_str(), "audit");
+        assert_eq!(CommandName::Clock.as_str(), "clock");
+        assert_eq!(CommandName::View.as_str(), "view");
+        assert_eq!(CommandName::Saif.as_str(), "saif");
         assert_eq!(CommandName::ExtractAhb.as_str(), "extract ahb");
         assert_eq!(CommandName::ExtractApb.as_str(), "extract apb");
         assert_eq!(CommandName::ExtractAtb.as_str(), "extract atb");
         assert_eq!(CommandName::ExtractAxi.as_str(), "extract axi");
         assert_eq!(CommandName::ExtractAxiStream.as_str(), "extract axistream");
         assert_eq!(CommandName::ExtractGeneric.as_str(), "extract generic");
+        assert_eq!(CommandName::ExportGtkw.as_str(), "export gtkw");
+        assert_eq!(CommandName::ExportSurfer.as_str(), "export surfer");
+        assert_eq!(CommandName::ExportSqlite.as_str(), "export sqlite");
         assert_eq!(CommandName::Docs.as_str(), "docs");
         assert_eq!(CommandName::DocsTopics.as_str(), "docs topics");
         assert_eq!(CommandName::DocsShow.as_str(), "docs show");
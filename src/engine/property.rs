@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use serde::Serialize;
 
 use crate::cli::limits::LimitArg;
@@ -5,24 +7,29 @@ use crate::cli::property::{CaptureMode, PropertyArgs};
 use crate::cli::sampling::SampleMode;
 use crate::debug_trace::DebugTrace;
 use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
 use crate::engine::expr_runtime::{
-    bind_waveform_event_expr, bind_waveform_logical_expr, candidate_sources_for_handles,
-    eval_bound_logical_truth, event_candidate_handles, event_expr_contains_wildcard,
-    event_expr_is_any_tracked_only, event_expr_is_edge_only, event_expr_matches,
-    open_shared_waveform, referenced_signal_handles,
+    bind_host_event_expr, bind_waveform_logical_expr, candidate_sources_for_handles,
+    collect_event_candidate_times, eval_bound_logical_truth, event_candidate_handles,
+    event_expr_contains_wildcard, event_expr_is_any_tracked_only, event_expr_is_sampled_only,
+    event_expr_matches, open_shared_waveform, referenced_signal_handles,
+};
+use crate::engine::sample_point::{
+    edge_only_sample_mode_message, edge_sample_offset, sample_time_before,
 };
+use crate::engine::signal_layout::SignalLayouts;
 use crate::engine::time::{
-    DumpTimeContext, TimeValidationError, format_raw_timestamp, parse_dump_time_context,
-    validate_time_token_to_raw,
+    DumpTimeContext, TimeFormat, TimeValidationError, format_raw_timestamp,
+    parse_dump_time_context, validate_time_token_to_raw,
 };
+use crate::engine::time_expr::resolve_time_window;
 use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
 use crate::error::WavepeekError;
 use crate::expr::EventEvalFrame;
 use crate::waveform::ChangeCandidateCollectionMode;
 #[cfg(test)]
 use crate::waveform::Waveform;
-
-const PRE_EDGE_REQUIRES_EDGE_ONLY_ON: &str = "--sample-mode pre-edge requires --on with only edge event terms (posedge, negedge, or edge); use --sample-mode native for wildcard or plain signal triggers";
+use crate::waveform::expr_host::{PackedFieldSource, WaveformExprHost};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -45,6 +52,13 @@ impl std::fmt::Display for PropertyResultKind {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct PropertyCaptureRow {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    /// Dump tick of `time`, which `--html` strips are centered on.
+    #[serde(skip)]
+    pub raw_time: u64,
     pub sample_time: String,
     pub kind: PropertyResultKind,
 }
@@ -163,17 +177,34 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
     let metadata = waveform.borrow().metadata()?;
     debug.event("metadata.load.done", || serde_json::json!({}));
     let dump_time = parse_dump_time_context(&metadata)?;
-    let dump_tick = dump_time.dump_tick;
+    let time_format = TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks);
     let dump_start_raw =
         u64::try_from(dump_time.dump_start_zs / dump_time.dump_tick_zs).map_err(|_| {
             WavepeekError::Internal("dump start timestamp exceeds supported range".to_string())
         })?;
 
-    let from_raw = match args.from.as_deref() {
+    let clock = CycleClock::load(
+        &waveform,
+        args.scope.as_deref(),
+        args.clock.as_deref(),
+        dump_time,
+        "wavepeek property",
+    )?;
+    let window = resolve_time_window(
+        &waveform,
+        args.scope.as_deref(),
+        clock.as_ref(),
+        dump_time,
+        "wavepeek property",
+        args.from.as_deref(),
+        args.to.as_deref(),
+        &mut diagnostics,
+    )?;
+    let from_raw = match window.from.as_deref() {
         Some(token) => parse_bound_time(token, "--from", dump_time, &metadata)?,
         None => dump_start_raw,
     };
-    let to_raw = match args.to.as_deref() {
+    let to_raw = match window.to.as_deref() {
         Some(token) => parse_bound_time(token, "--to", dump_time, &metadata)?,
         None => u64::try_from(dump_time.dump_end_zs / dump_time.dump_tick_zs).map_err(|_| {
             WavepeekError::Internal("dump end timestamp exceeds supported range".to_string())
@@ -187,10 +218,17 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
     }
     debug.event("time.parse.done", || serde_json::json!({}));
 
+    let layouts = args
+        .layout
+        .as_deref()
+        .map(|path| SignalLayouts::load(path, "wavepeek property").map(Rc::new))
+        .transpose()?;
     let event_expr_source = args.on.as_str();
-    let (host, bound_event) =
-        bind_waveform_event_expr(waveform.clone(), args.scope.as_deref(), event_expr_source)?;
-    validate_sample_mode(args.sample_mode, &bound_event)?;
+    let host = WaveformExprHost::from_shared(waveform.clone())
+        .with_packed_fields(layouts.map(|layouts| layouts as Rc<dyn PackedFieldSource>));
+    let (host, bound_event) = bind_host_event_expr(host, args.scope.as_deref(), event_expr_source)?;
+    validate_sample_mode(&args.sample_mode, &bound_event)?;
+    let sample_offset = edge_sample_offset(&args.sample_mode, dump_time, "wavepeek property")?;
     let bound_eval = bind_waveform_logical_expr(&host, args.scope.as_deref(), args.eval.as_str())?;
     debug.event("expression.bind.done", || serde_json::json!({}));
     let eval_signal_handles = referenced_signal_handles(&bound_eval);
@@ -229,14 +267,14 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
     });
 
     sink.start()?;
-    let candidate_times = waveform
-        .borrow_mut()
-        .collect_expr_candidate_times_with_mode(
-            candidate_sources.as_slice(),
-            from_raw,
-            to_raw,
-            ChangeCandidateCollectionMode::Auto,
-        )?;
+    let candidate_times = collect_event_candidate_times(
+        &waveform,
+        &bound_event,
+        candidate_sources.as_slice(),
+        from_raw,
+        to_raw,
+        ChangeCandidateCollectionMode::Auto,
+    )?;
     debug.event(
         "candidate.collect.done",
         || serde_json::json!({"times": candidate_times.len()}),
@@ -248,9 +286,8 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
 
     if max_entries.is_none() {
         let preload_from_raw = from_raw
-            .checked_sub(1)
-            .filter(|value| *value >= dump_start_raw)
-            .unwrap_or(from_raw);
+            .saturating_sub(sample_offset.unwrap_or(1).max(1))
+            .max(dump_start_raw);
         let mut preload_sources = candidate_sources.clone();
         preload_sources.extend(eval_sources.iter().cloned());
         let mut seen_preload = std::collections::HashSet::new();
@@ -300,13 +337,15 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
             args.capture,
             CaptureMode::Switch | CaptureMode::Assert | CaptureMode::Deassert
         ) && timestamp == from_raw
-            && args.sample_mode == SampleMode::PreEdge
+            && sample_offset.is_some()
         {
             continue;
         }
 
-        let decision_timestamp =
-            value_sample_time_for_mode(args.sample_mode, timestamp, dump_start_raw);
+        let decision_timestamp = match sample_offset {
+            Some(offset) => sample_time_before(timestamp, offset, dump_start_raw),
+            None => Some(timestamp),
+        };
         let Some(decision_timestamp) = decision_timestamp else {
             continue;
         };
@@ -319,8 +358,11 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
                     && !emit_property_row(
                         sink,
                         PropertyCaptureRow {
-                            time: format_raw_timestamp(timestamp, dump_tick)?,
-                            sample_time: format_raw_timestamp(decision_timestamp, dump_tick)?,
+                            time: time_format.format(timestamp)?,
+                            tick: time_format.tick(timestamp),
+                            cycle: clock.as_ref().and_then(|clock| clock.cycle_at(timestamp)),
+                            raw_time: timestamp,
+                            sample_time: time_format.format(decision_timestamp)?,
                             kind: PropertyResultKind::Match,
                         },
                         max_entries,
@@ -333,7 +375,7 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
             }
             CaptureMode::Switch | CaptureMode::Assert | CaptureMode::Deassert => {
                 if timestamp == from_raw {
-                    if args.sample_mode == SampleMode::Native {
+                    if sample_offset.is_none() {
                         previous_state = Some(decision);
                     }
                     continue;
@@ -357,8 +399,11 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
                 if !emit_property_row(
                     sink,
                     PropertyCaptureRow {
-                        time: format_raw_timestamp(timestamp, dump_tick)?,
-                        sample_time: format_raw_timestamp(decision_timestamp, dump_tick)?,
+                        time: time_format.format(timestamp)?,
+                        tick: time_format.tick(timestamp),
+                        cycle: clock.as_ref().and_then(|clock| clock.cycle_at(timestamp)),
+                        raw_time: timestamp,
+                        sample_time: time_format.format(decision_timestamp)?,
                         kind,
                     },
                     max_entries,
@@ -398,30 +443,17 @@ fn run_with_sink<S: PropertyRowSink + ?Sized>(
 }
 
 fn validate_sample_mode(
-    sample_mode: SampleMode,
+    sample_mode: &SampleMode,
     bound_event: &crate::expr::BoundEventExpr,
 ) -> Result<(), WavepeekError> {
-    if sample_mode == SampleMode::PreEdge && !event_expr_is_edge_only(bound_event) {
-        return Err(WavepeekError::Args(
-            PRE_EDGE_REQUIRES_EDGE_ONLY_ON.to_string(),
-        ));
+    if *sample_mode != SampleMode::Native && !event_expr_is_sampled_only(bound_event) {
+        return Err(WavepeekError::Args(edge_only_sample_mode_message(
+            sample_mode,
+        )));
     }
     Ok(())
 }
 
-fn value_sample_time_for_mode(
-    sample_mode: SampleMode,
-    trigger_timestamp: u64,
-    dump_start_raw: u64,
-) -> Option<u64> {
-    match sample_mode {
-        SampleMode::Native => Some(trigger_timestamp),
-        SampleMode::PreEdge => trigger_timestamp
-            .checked_sub(1)
-            .filter(|query_time| *query_time >= dump_start_raw),
-    }
-}
-
 fn capture_allows_kind(capture: CaptureMode, kind: PropertyResultKind) -> bool {
     match capture {
         CaptureMode::Match | CaptureMode::Switch => true,
@@ -512,6 +544,7 @@ mod tests {
     use crate::cli::limits::LimitArg;
     use crate::cli::property::{CaptureMode, PropertyArgs};
     use crate::cli::sampling::SampleMode;
+    use crate::cli::time_unit::OutputTimeUnit;
     use crate::engine::CommandData;
     use crate::engine::time::{ParsedTime, TimeUnit, as_zeptoseconds};
     use crate::error::WavepeekError;
@@ -664,6 +697,10 @@ mod tests {
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
+            layout: None,
             on: "posedge sig".to_string(),
             sample_mode: SampleMode::Native,
             eval: "sig".to_string(),
@@ -671,6 +708,7 @@ mod tests {
             max: LimitArg::Unlimited,
             json: false,
             jsonl: false,
+            html: None,
         })
         .expect("match capture should succeed");
         let CommandData::Property(rows) = matched.data else {
@@ -685,6 +723,10 @@ mod tests {
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
+            layout: None,
             on: "*".to_string(),
             sample_mode: SampleMode::Native,
             eval: "sig".to_string(),
@@ -692,6 +734,7 @@ mod tests {
             max: LimitArg::Unlimited,
             json: true,
             jsonl: false,
+            html: None,
         })
         .expect("switch capture should succeed");
         let CommandData::Property(rows) = switched.data else {
@@ -708,6 +751,10 @@ mod tests {
             from: Some("0ns".to_string()),
             to: Some("10ns".to_string()),
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
+            layout: None,
             on: "*".to_string(),
             sample_mode: SampleMode::Native,
             eval: "sig".to_string(),
@@ -715,6 +762,7 @@ mod tests {
             max: LimitArg::Unlimited,
             json: false,
             jsonl: false,
+            html: None,
         })
         .expect("assert capture should succeed");
         let CommandData::Property(rows) = assert_only.data else {
@@ -728,6 +776,10 @@ mod tests {
             from: Some("10ns".to_string()),
             to: Some("0ns".to_string()),
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
+            layout: None,
             on: "posedge sig".to_string(),
             sample_mode: SampleMode::Native,
             eval: "sig".to_string(),
@@ -735,6 +787,7 @@ mod tests {
             max: LimitArg::Unlimited,
             json: false,
             jsonl: false,
+            html: None,
         })
         .expect_err("reversed time bounds should fail");
         assert!(
@@ -755,6 +808,10 @@ mod tests {
                 from: None,
                 to: None,
                 scope: Some("top".to_string()),
+                time_unit: OutputTimeUnit::Dump,
+                ticks: false,
+                clock: None,
+                layout: None,
                 on: "posedge sig".to_string(),
                 sample_mode: SampleMode::Native,
                 eval: "sig".to_string(),
@@ -762,6 +819,7 @@ mod tests {
                 max: LimitArg::Unlimited,
                 json: false,
                 jsonl: true,
+                html: None,
             },
             &mut sink,
         )
@@ -779,6 +837,10 @@ mod tests {
             from: None,
             to: None,
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
+            layout: None,
             on: "*".to_string(),
             sample_mode: SampleMode::Native,
             eval: "1'b1".to_string(),
@@ -786,6 +848,7 @@ mod tests {
             max: LimitArg::Unlimited,
             json: false,
             jsonl: false,
+            html: None,
         })
         .expect_err("signal-free wildcard trigger should fail");
         assert!(
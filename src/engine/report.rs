@@ -0,0 +1,308 @@
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::cli::time_unit::OutputTimeUnit;
+use crate::diagnostic::Diagnostic;
+use crate::engine::expr_runtime::{
+    bind_host_event_expr, bind_waveform_logical_expr, candidate_sources_for_handles,
+    event_candidate_handles, event_iff_handles, open_shared_waveform, referenced_signal_handles,
+};
+use crate::engine::signal_layout::SignalLayouts;
+use crate::engine::signal_pattern::ExpandedSignal;
+use crate::engine::time::{TimeFormat, parse_dump_time_context};
+use crate::engine::view::{WaveData, column_times, sample_wave};
+use crate::engine::{Command, CommandData, CommandResult};
+use crate::error::WavepeekError;
+use crate::waveform::expr_host::{PackedFieldSource, WaveformExprHost};
+
+/// Columns sampled per row strip.
+const STRIP_COLUMNS: usize = 48;
+/// Rows that get a strip; later rows are listed without one to keep reports small.
+const MAX_STRIP_ROWS: usize = 200;
+/// Signals drawn per strip.
+const MAX_STRIP_SIGNALS: usize = 24;
+/// A strip spans this many row-to-row gaps on each side of the row time.
+const STRIP_GAP_SPAN: u64 = 4;
+
+/// Waveform context drawn under one report row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportStrip {
+    pub wave: WaveData,
+    /// Column closest to the row time.
+    pub marker: usize,
+}
+
+/// Everything `--html` adds on top of the command result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlReport {
+    pub path: PathBuf,
+    pub waves: String,
+    /// One entry per result row, in row order.
+    pub strips: Vec<Option<ReportStrip>>,
+}
+
+struct PropertyExprs {
+    scope: Option<String>,
+    layout: Option<PathBuf>,
+    on: String,
+    eval: String,
+}
+
+/// Runs an `--html` capable command and samples a waveform strip around each result row.
+pub fn run(command: Command) -> Result<(CommandResult, HtmlReport), WavepeekError> {
+    let path = command.html_path().map(Path::to_path_buf).ok_or_else(|| {
+        WavepeekError::Internal(format!(
+            "--html is not available for {}",
+            command.name().as_str()
+        ))
+    })?;
+    let (waves, exprs, time_unit) = report_inputs(&command);
+
+    let mut result = super::run(command)?;
+    let raw_times = row_raw_times(&result.data);
+    let strips = if raw_times.is_empty() {
+        Vec::new()
+    } else {
+        sample_strips(
+            waves.as_path(),
+            exprs.as_ref(),
+            time_unit,
+            &result.data,
+            &raw_times,
+            &mut result.diagnostics,
+        )?
+    };
+
+    Ok((
+        result,
+        HtmlReport {
+            path,
+            waves: waves.display().to_string(),
+            strips,
+        },
+    ))
+}
+
+fn report_inputs(command: &Command) -> (PathBuf, Option<PropertyExprs>, OutputTimeUnit) {
+    match command {
+        Command::Property(args) => (
+            args.waves.clone(),
+            Some(PropertyExprs {
+                scope: args.scope.clone(),
+                layout: args.layout.clone(),
+                on: args.on.clone(),
+                eval: args.eval.clone(),
+            }),
+            args.time_unit,
+        ),
+        Command::ExtractAhb(args) => (args.waves.clone(), None, args.time_unit),
+        Command::ExtractApb(args) => (args.waves.clone(), None, args.time_unit),
+        Command::ExtractAtb(args) => (args.waves.clone(), None, args.time_unit),
+        Command::ExtractAxi(args) => (args.waves.clone(), None, args.time_unit),
+        Command::ExtractAxiStream(args) => (args.waves.clone(), None, args.time_unit),
+        Command::ExtractGeneric(args) => (args.waves.clone(), None, args.time_unit),
+        _ => (PathBuf::new(), None, OutputTimeUnit::Dump),
+    }
+}
+
+fn row_raw_times(data: &CommandData) -> Vec<u64> {
+    match data {
+        CommandData::Property(rows) => rows.iter().map(|row| row.raw_time).collect(),
+        CommandData::ExtractAhb(data) => data.events.iter().map(|row| row.raw_time).collect(),
+        CommandData::ExtractApb(data) => data.events.iter().map(|row| row.raw_time).collect(),
+        CommandData::ExtractAtb(data) => data.events.iter().map(|row| row.raw_time).collect(),
+        CommandData::ExtractAxi(data) => data.transfers.iter().map(|row| row.raw_time).collect(),
+        CommandData::ExtractAxiStream(data) => {
+            data.transfers.iter().map(|row| row.raw_time).collect()
+        }
+        CommandData::ExtractGeneric(data) => data.rows.iter().map(|row| row.raw_time).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn sample_strips(
+    waves: &Path,
+    exprs: Option<&PropertyExprs>,
+    time_unit: OutputTimeUnit,
+    data: &CommandData,
+    raw_times: &[u64],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<Option<ReportStrip>>, WavepeekError> {
+    let waveform = open_shared_waveform(waves)?;
+    let mut signals = match exprs {
+        Some(exprs) => property_signals(waveform.clone(), exprs)?,
+        None => extract_signals(data),
+    };
+    let mut waveform = waveform.borrow_mut();
+    signals.retain(|signal| {
+        waveform
+            .resolve_signals(std::slice::from_ref(&signal.path))
+            .is_ok()
+    });
+    if signals.len() > MAX_STRIP_SIGNALS {
+        diagnostics.push(Diagnostic::info(format!(
+            "HTML waveform strips show the first {MAX_STRIP_SIGNALS} of {} signals",
+            signals.len()
+        )));
+        signals.truncate(MAX_STRIP_SIGNALS);
+    }
+    if signals.is_empty() {
+        return Ok(vec![None; raw_times.len()]);
+    }
+
+    let metadata = waveform.metadata()?;
+    let dump_time = parse_dump_time_context(&metadata)?;
+    let dump_start_raw =
+        u64::try_from(dump_time.dump_start_zs / dump_time.dump_tick_zs).map_err(|_| {
+            WavepeekError::Internal("dump start timestamp exceeds supported range".to_string())
+        })?;
+    let dump_end_raw =
+        u64::try_from(dump_time.dump_end_zs / dump_time.dump_tick_zs).map_err(|_| {
+            WavepeekError::Internal("dump end timestamp exceeds supported range".to_string())
+        })?;
+    let half_span = strip_half_span(raw_times, dump_end_raw - dump_start_raw);
+    // Strip times follow the rows' `--time-unit`.
+    let time_format = TimeFormat::new(dump_time.dump_tick, time_unit, false);
+
+    if raw_times.len() > MAX_STRIP_ROWS {
+        diagnostics.push(Diagnostic::info(format!(
+            "HTML waveform strips drawn for the first {MAX_STRIP_ROWS} of {} rows",
+            raw_times.len()
+        )));
+    }
+
+    let mut strips = Vec::with_capacity(raw_times.len());
+    for (index, &row_raw) in raw_times.iter().enumerate() {
+        if index >= MAX_STRIP_ROWS {
+            strips.push(None);
+            continue;
+        }
+        let from_raw = row_raw.saturating_sub(half_span).max(dump_start_raw);
+        let to_raw = row_raw.saturating_add(half_span).min(dump_end_raw);
+        let columns = column_times(from_raw, to_raw, STRIP_COLUMNS);
+        let marker = columns
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, column)| column.abs_diff(row_raw))
+            .map_or(0, |(column, _)| column);
+        let (times, traces) = sample_wave(&mut waveform, &signals, &columns, time_format)?;
+        strips.push(Some(ReportStrip {
+            wave: WaveData {
+                times,
+                traces,
+                width: STRIP_COLUMNS,
+                ascii: false,
+            },
+            marker,
+        }));
+    }
+    Ok(strips)
+}
+
+/// Half-width of each strip: a few of the smallest row-to-row gaps, or a slice of the dump.
+fn strip_half_span(raw_times: &[u64], dump_span: u64) -> u64 {
+    let mut sorted = raw_times.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let min_gap = sorted
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .min()
+        .unwrap_or(dump_span / 8);
+    min_gap.saturating_mul(STRIP_GAP_SPAN).max(1)
+}
+
+fn property_signals(
+    waveform: crate::engine::expr_runtime::SharedWaveform,
+    exprs: &PropertyExprs,
+) -> Result<Vec<ExpandedSignal>, WavepeekError> {
+    let scope = exprs.scope.as_deref();
+    let layouts = exprs
+        .layout
+        .as_deref()
+        .map(|path| SignalLayouts::load(path, "wavepeek property").map(Rc::new))
+        .transpose()?;
+    let host = WaveformExprHost::from_shared(waveform)
+        .with_packed_fields(layouts.map(|layouts| layouts as Rc<dyn PackedFieldSource>));
+    let (host, bound_event) = bind_host_event_expr(host, scope, exprs.on.as_str())?;
+    let bound_eval = bind_waveform_logical_expr(&host, scope, exprs.eval.as_str())?;
+
+    let mut handles = event_candidate_handles(&bound_event);
+    handles.extend(event_iff_handles(&bound_event));
+    handles.extend(referenced_signal_handles(&bound_eval));
+    let sources = candidate_sources_for_handles(&host, &handles)?;
+
+    Ok(sources
+        .into_iter()
+        .map(|source| {
+            let display = scope
+                .and_then(|scope| source.path.strip_prefix(scope))
+                .and_then(|relative| relative.strip_prefix('.'))
+                .unwrap_or(source.path.as_str())
+                .to_string();
+            ExpandedSignal {
+                display,
+                path: source.path,
+            }
+        })
+        .collect())
+}
+
+fn extract_signals(data: &CommandData) -> Vec<ExpandedSignal> {
+    let pairs: Vec<(&str, &str)> = match data {
+        CommandData::ExtractAhb(data) => data
+            .mappings
+            .iter()
+            .map(|mapping| (mapping.standard.as_str(), mapping.path.as_str()))
+            .collect(),
+        CommandData::ExtractApb(data) => data
+            .mappings
+            .iter()
+            .map(|mapping| (mapping.standard.as_str(), mapping.path.as_str()))
+            .collect(),
+        CommandData::ExtractAtb(data) => data
+            .mappings
+            .iter()
+            .map(|mapping| (mapping.standard.as_str(), mapping.path.as_str()))
+            .collect(),
+        CommandData::ExtractAxi(data) => data
+            .mappings
+            .iter()
+            .map(|mapping| (mapping.standard.as_str(), mapping.path.as_str()))
+            .collect(),
+        CommandData::ExtractAxiStream(data) => data
+            .mappings
+            .iter()
+            .map(|mapping| (mapping.standard.as_str(), mapping.path.as_str()))
+            .collect(),
+        CommandData::ExtractGeneric(data) => data
+            .rows
+            .iter()
+            .flat_map(|row| row.payload.iter())
+            .map(|payload| (payload.display.as_str(), payload.path.as_str()))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    pairs
+        .into_iter()
+        .filter(|(_, path)| seen.insert(*path))
+        .map(|(display, path)| ExpandedSignal {
+            display: display.to_string(),
+            path: path.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_half_span;
+
+    #[test]
+    fn strip_half_span_uses_smallest_row_gap() {
+        assert_eq!(strip_half_span(&[10, 30, 40, 40], 1000), 40);
+        assert_eq!(strip_half_span(&[10], 800), 400);
+        assert_eq!(strip_half_span(&[10], 0), 1);
+    }
+}
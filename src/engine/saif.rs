@@ -0,0 +1,426 @@
+use std::fmt::Write as _;
+
+use crate::cli::saif::SaifArgs;
+use crate::debug_trace::DebugTrace;
+use crate::engine::expr_runtime::{open_waveform_traced, with_shared_waveform};
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::waveform::{ScopeEntry, SignalTransitions, Waveform, WaveformMetadata};
+
+/// Signals resolved and loaded per backend call, bounding transient memory on wide scopes.
+const SIGNAL_BATCH: usize = 256;
+
+/// Time and transition totals for one net bit over the activity window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct BitActivity {
+    t0: u64,
+    t1: u64,
+    tx: u64,
+    tz: u64,
+    tc: u64,
+    ig: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BitState {
+    Zero,
+    One,
+    Unknown,
+    HighZ,
+}
+
+impl BitState {
+    const fn from_char(ch: char) -> Self {
+        match ch {
+            '0' => Self::Zero,
+            '1' => Self::One,
+            'z' | 'Z' => Self::HighZ,
+            _ => Self::Unknown,
+        }
+    }
+
+    const fn is_binary(self) -> bool {
+        matches!(self, Self::Zero | Self::One)
+    }
+}
+
+impl BitActivity {
+    fn hold(&mut self, state: BitState, duration: u64) {
+        match state {
+            BitState::Zero => self.t0 += duration,
+            BitState::One => self.t1 += duration,
+            BitState::Unknown => self.tx += duration,
+            BitState::HighZ => self.tz += duration,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SaifNet {
+    name: String,
+    bits: Vec<BitActivity>,
+    /// Declared index per entry of `bits`; `None` for scalar nets.
+    indices: Vec<Option<i64>>,
+}
+
+pub fn run(args: SaifArgs) -> Result<CommandResult, WavepeekError> {
+    let debug = DebugTrace::for_command(CommandName::Saif);
+    let waveform = open_waveform_traced(args.waves.as_path(), &debug)?;
+
+    let mut diagnostics = Vec::new();
+    let (
+        mut waveform,
+        RawTimeWindow {
+            metadata,
+            from_raw,
+            to_raw,
+            ..
+        },
+    ) = with_shared_waveform(waveform, |waveform| {
+        resolve_raw_window(
+            waveform,
+            None,
+            None,
+            "wavepeek saif",
+            args.from.as_deref(),
+            args.to.as_deref(),
+            &debug,
+            &mut diagnostics,
+        )
+    })?;
+
+    let scopes = selected_scopes(&waveform, args.scope.as_deref())?;
+    debug.event(
+        "scope.collect.done",
+        || serde_json::json!({"scopes": scopes.len()}),
+    );
+
+    let mut instances = Vec::with_capacity(scopes.len());
+    let mut net_count = 0usize;
+    for scope in &scopes {
+        let nets = scope_nets(&mut waveform, scope.path.as_str(), from_raw, to_raw)?;
+        net_count += nets.len();
+        instances.push(nets);
+    }
+    debug.event(
+        "saif.activity.done",
+        || serde_json::json!({"nets": net_count}),
+    );
+
+    let root_depth = scopes.first().map_or(0, |scope| scope.depth);
+    let mut text = String::new();
+    write_header(&mut text, &metadata, to_raw - from_raw);
+    let mut open_depths: Vec<usize> = Vec::new();
+    for (scope, nets) in scopes.iter().zip(&instances) {
+        while open_depths
+            .last()
+            .is_some_and(|depth| *depth >= scope.depth)
+        {
+            open_depths.pop();
+            close_instance(&mut text, open_depths.len());
+        }
+        let indent = "  ".repeat(open_depths.len());
+        let name = if scope.depth == root_depth {
+            scope
+                .path
+                .split('.')
+                .map(escape_identifier)
+                .collect::<Vec<_>>()
+                .join("/")
+        } else {
+            escape_identifier(scope.path.rsplit('.').next().unwrap_or_default())
+        };
+        let _ = writeln!(text, "{indent}(INSTANCE {name}");
+        write_nets(&mut text, &indent, nets);
+        open_depths.push(scope.depth);
+    }
+    while open_depths.pop().is_some() {
+        close_instance(&mut text, open_depths.len());
+    }
+    text.push_str(")\n");
+
+    let text = match args.out.as_deref() {
+        Some(out) => {
+            std::fs::write(out, text.as_bytes()).map_err(|error| {
+                WavepeekError::File(format!("cannot write '{}': {error}", out.display()))
+            })?;
+            format!(
+                "wrote SAIF file with {net_count} net(s) in {} instance(s) to {}",
+                scopes.len(),
+                out.display()
+            )
+        }
+        None => text.trim_end_matches('\n').to_string(),
+    };
+
+    Ok(CommandResult {
+        command: CommandName::Saif,
+        output_mode: crate::output_mode::OutputMode::Human,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: false,
+        },
+        data: CommandData::Text(text),
+        diagnostics,
+    })
+}
+
+/// Depth-first scopes of the selected subtree, or of the whole dump without `--scope`.
+pub(crate) fn selected_scopes(
+    waveform: &Waveform,
+    scope: Option<&str>,
+) -> Result<Vec<ScopeEntry>, WavepeekError> {
+    let scopes = waveform.scopes_depth_first(None)?;
+    let Some(root) = scope else {
+        return Ok(scopes);
+    };
+
+    let prefix = format!("{root}.");
+    let selected = scopes
+        .into_iter()
+        .filter(|entry| entry.path == root || entry.path.starts_with(prefix.as_str()))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err(WavepeekError::Scope(format!(
+            "scope '{root}' not found in dump"
+        )));
+    }
+    Ok(selected)
+}
+
+fn scope_nets(
+    waveform: &mut Waveform,
+    scope_path: &str,
+    from_raw: u64,
+    to_raw: u64,
+) -> Result<Vec<SaifNet>, WavepeekError> {
+    let entries = waveform
+        .signals_in_scope(scope_path)?
+        .into_iter()
+        .filter(|entry| entry.width.is_some() && entry.kind != "event")
+        .collect::<Vec<_>>();
+
+    let mut nets = Vec::with_capacity(entries.len());
+    for batch in entries.chunks(SIGNAL_BATCH) {
+        let paths = batch
+            .iter()
+            .map(|entry| entry.path.clone())
+            .collect::<Vec<_>>();
+        let resolved = waveform.resolve_signals(&paths)?;
+        let ranges = waveform.declared_index_ranges(&paths)?;
+        let transitions = waveform.signal_transitions(&resolved, from_raw, to_raw)?;
+
+        for (((entry, signal), range), history) in
+            batch.iter().zip(&resolved).zip(ranges).zip(&transitions)
+        {
+            let width = signal.width as usize;
+            nets.push(SaifNet {
+                name: escape_identifier(entry.name.as_str()),
+                bits: bit_activity(history, width, from_raw, to_raw),
+                indices: bit_indices(width, range),
+            });
+        }
+    }
+    Ok(nets)
+}
+
+/// Per-bit activity, LSB first, from one signal's window history.
+fn bit_activity(
+    history: &SignalTransitions,
+    width: usize,
+    from_raw: u64,
+    to_raw: u64,
+) -> Vec<BitActivity> {
+    let mut activity = vec![BitActivity::default(); width];
+    let mut states = bit_states(history.initial.as_deref(), width);
+    let mut last_time = from_raw;
+
+    for change in &history.changes {
+        let duration = change.time - last_time;
+        for (bit, state) in activity.iter_mut().zip(&states) {
+            bit.hold(*state, duration);
+        }
+        last_time = change.time;
+
+        let steps = change
+            .values
+            .iter()
+            .map(|value| bit_states(Some(value.as_str()), width))
+            .collect::<Vec<_>>();
+        let Some(settled) = steps.last() else {
+            continue;
+        };
+        for (index, bit) in activity.iter_mut().enumerate() {
+            let before = states[index];
+            let after = settled[index];
+            let mut moves = 0u64;
+            let mut current = before;
+            for step in &steps {
+                if step[index] != current {
+                    moves += 1;
+                    current = step[index];
+                }
+            }
+            let settled_moves = u64::from(before != after);
+            if before.is_binary() && after.is_binary() && before != after {
+                bit.tc += 1;
+            }
+            bit.ig += (moves - settled_moves).div_ceil(2);
+        }
+        states.clone_from(settled);
+    }
+
+    let duration = to_raw - last_time;
+    for (bit, state) in activity.iter_mut().zip(&states) {
+        bit.hold(*state, duration);
+    }
+    activity
+}
+
+/// Decodes an MSB-first bit string into LSB-first states; missing values are unknown.
+fn bit_states(bits: Option<&str>, width: usize) -> Vec<BitState> {
+    let mut states = vec![BitState::Unknown; width];
+    if let Some(bits) = bits {
+        for (state, ch) in states.iter_mut().zip(bits.chars().rev()) {
+            *state = BitState::from_char(ch);
+        }
+    }
+    states
+}
+
+/// Declared index per LSB-first bit, falling back to `[width-1:0]`.
+fn bit_indices(width: usize, range: Option<(i64, i64)>) -> Vec<Option<i64>> {
+    match range {
+        Some((msb, lsb)) if width > 1 || msb != lsb => {
+            let step = if msb >= lsb { 1 } else { -1 };
+            (0..width as i64)
+                .map(|offset| Some(lsb + offset * step))
+                .collect()
+        }
+        _ if width > 1 => (0..width as i64).map(Some).collect(),
+        _ => vec![None; width],
+    }
+}
+
+fn write_header(text: &mut String, metadata: &WaveformMetadata, duration: u64) {
+    let split_at = metadata
+        .time_unit
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(metadata.time_unit.len());
+    let (value, unit) = metadata.time_unit.split_at(split_at);
+
+    text.push_str("(SAIFILE\n");
+    text.push_str("(SAIFVERSION \"2.0\")\n");
+    text.push_str("(DIRECTION \"backward\")\n");
+    text.push_str("(DESIGN )\n");
+    text.push_str("(VENDOR \"kleverhq\")\n");
+    text.push_str("(PROGRAM_NAME \"wavepeek\")\n");
+    let _ = writeln!(text, "(VERSION \"{}\")", env!("CARGO_PKG_VERSION"));
+    text.push_str("(DIVIDER / )\n");
+    let _ = writeln!(text, "(TIMESCALE {value} {unit})");
+    let _ = writeln!(text, "(DURATION {duration})");
+}
+
+fn write_nets(text: &mut String, indent: &str, nets: &[SaifNet]) {
+    if nets.is_empty() {
+        return;
+    }
+    let _ = writeln!(text, "{indent}  (NET");
+    for net in nets {
+        for (bit, index) in net.bits.iter().zip(&net.indices).rev() {
+            let name = match index {
+                Some(index) => format!("{}\\[{index}\\]", net.name),
+                None => net.name.clone(),
+            };
+            let _ = writeln!(text, "{indent}    ({name}");
+            let _ = writeln!(
+                text,
+                "{indent}      (T0 {}) (T1 {}) (TX {}) (TZ {})",
+                bit.t0, bit.t1, bit.tx, bit.tz
+            );
+            let _ = writeln!(text, "{indent}      (TC {}) (IG {})", bit.tc, bit.ig);
+            let _ = writeln!(text, "{indent}    )");
+        }
+    }
+    let _ = writeln!(text, "{indent}  )");
+}
+
+fn close_instance(text: &mut String, depth: usize) {
+    let _ = writeln!(text, "{})", "  ".repeat(depth));
+}
+
+/// Backslash-escapes every character that is not legal in a plain SAIF identifier.
+fn escape_identifier(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for ch in name.chars() {
+        if !(ch.is_ascii_alphanumeric() || ch == '_') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitActivity, bit_activity, bit_indices, escape_identifier};
+    use crate::waveform::{SignalTransitions, ValueTransition};
+
+    fn change(time: u64, values: &[&str]) -> ValueTransition {
+        ValueTransition {
+            time,
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn bit_activity_splits_time_by_state_and_counts_binary_toggles() {
+        let history = SignalTransitions {
+            initial: None,
+            changes: vec![
+                change(2, &["0"]),
+                change(5, &["1"]),
+                change(7, &["x"]),
+                change(8, &["0"]),
+            ],
+        };
+
+        assert_eq!(
+            bit_activity(&history, 1, 0, 10),
+            vec![BitActivity {
+                t0: 5,
+                t1: 2,
+                tx: 3,
+                tz: 0,
+                tc: 1,
+                ig: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn bit_activity_counts_zero_width_pulses_as_glitches() {
+        let history = SignalTransitions {
+            initial: Some("00".to_string()),
+            changes: vec![change(4, &["01", "00"]), change(6, &["10", "x1", "11"])],
+        };
+
+        let activity = bit_activity(&history, 2, 0, 10);
+        assert_eq!((activity[0].tc, activity[0].ig), (1, 1));
+        assert_eq!((activity[1].tc, activity[1].ig), (1, 1));
+        assert_eq!(activity[0].t0 + activity[0].t1, 10);
+    }
+
+    #[test]
+    fn bit_indices_follow_declared_range_direction() {
+        assert_eq!(bit_indices(1, None), vec![None]);
+        assert_eq!(
+            bit_indices(3, Some((7, 5))),
+            vec![Some(5), Some(6), Some(7)]
+        );
+        assert_eq!(bit_indices(2, Some((0, 1))), vec![Some(1), Some(0)]);
+        assert_eq!(bit_indices(2, None), vec![Some(0), Some(1)]);
+        assert_eq!(escape_identifier("gen[0].u"), "gen\\[0\\]\\.u");
+    }
+}
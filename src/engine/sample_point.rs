@@ -0,0 +1,48 @@
+use crate::cli::sampling::SampleMode;
+use crate::engine::time::{DumpTimeContext, duration_ticks};
+use crate::error::WavepeekError;
+
+/// Dump ticks between a trigger edge and its sample point; `None` samples at the trigger itself.
+pub(crate) fn edge_sample_offset(
+    mode: &SampleMode,
+    dump_time: DumpTimeContext,
+    help_command: &str,
+) -> Result<Option<u64>, WavepeekError> {
+    let skew = match mode {
+        SampleMode::Native => return Ok(None),
+        SampleMode::PreEdge => return Ok(Some(1)),
+        SampleMode::PostEdge => return Ok(Some(0)),
+        SampleMode::Skew(skew) => skew,
+    };
+    duration_ticks(skew, dump_time).map(Some).map_err(|reason| {
+        WavepeekError::Args(format!(
+            "invalid --sample-mode skew '{skew}': {reason}. See '{help_command} --help'."
+        ))
+    })
+}
+
+/// Edge offset for commands that always sample relative to an edge, so `native` is rejected.
+pub(crate) fn required_edge_sample_offset(
+    mode: &SampleMode,
+    dump_time: DumpTimeContext,
+    help_command: &str,
+) -> Result<u64, WavepeekError> {
+    edge_sample_offset(mode, dump_time, help_command)?.ok_or_else(|| {
+        WavepeekError::Args(format!(
+            "{help_command} samples relative to edges; use --sample-mode post-edge instead of native. See '{help_command} --help'."
+        ))
+    })
+}
+
+/// Sample point `offset` ticks before `trigger`, or `None` when that precedes the dump start.
+pub(crate) fn sample_time_before(trigger: u64, offset: u64, dump_start_raw: u64) -> Option<u64> {
+    trigger
+        .checked_sub(offset)
+        .filter(|sample_time| *sample_time >= dump_start_raw)
+}
+
+pub(crate) fn edge_only_sample_mode_message(mode: &SampleMode) -> String {
+    format!(
+        "--sample-mode {mode} requires --on with only edge event terms (posedge, negedge, or edge) or time terms (every, at, or timestamps); use --sample-mode native for wildcard or plain signal triggers"
+    )
+}
@@ -2,9 +2,10 @@ use crate::cli::limits::LimitArg;
 use crate::cli::scope::ScopeArgs;
 use crate::debug_trace::DebugTrace;
 use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::hierarchy_diff::{self, CompareInfo, PrefixRemap};
 use crate::engine::{CommandData, CommandName, CommandResult};
 use crate::error::WavepeekError;
-use crate::waveform::Waveform;
+use crate::waveform::{self, SourceLocation, Waveform};
 use regex::Regex;
 use serde::Serialize;
 
@@ -13,15 +14,27 @@ pub struct ScopeEntry {
     pub path: String,
     pub depth: usize,
     pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<SourceLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiation: Option<SourceLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare: Option<CompareInfo>,
 }
 
 pub fn run(args: ScopeArgs) -> Result<CommandResult, WavepeekError> {
     let ScopeArgs {
         waves,
+        compare,
+        remap_prefix,
         max,
         max_depth,
         filter,
+        module_type,
         tree,
+        with_source,
         json,
         jsonl,
     } = args;
@@ -38,6 +51,19 @@ pub fn run(args: ScopeArgs) -> Result<CommandResult, WavepeekError> {
             filter
         ))
     })?;
+    let module_type = module_type
+        .map(|pattern| {
+            Regex::new(pattern.as_str()).map_err(|error| {
+                WavepeekError::Args(format!(
+                    "invalid regex '{pattern}': {error}. See 'wavepeek scope --help'."
+                ))
+            })
+        })
+        .transpose()?;
+    let remaps = remap_prefix
+        .iter()
+        .map(|raw| PrefixRemap::parse("scope", raw))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mut diagnostics = Vec::new();
     if max.is_unlimited() {
@@ -62,16 +88,30 @@ pub fn run(args: ScopeArgs) -> Result<CommandResult, WavepeekError> {
             "format": waveform.format_name(),
         })
     });
-    let mut entries = waveform
-        .scopes_depth_first(max_depth.numeric())?
+    let selection = ScopeSelection {
+        max_depth: max_depth.numeric(),
+        filter: &filter,
+        module_type: module_type.as_ref(),
+    };
+    let mut entries = selection.collect(&waveform, &[])?;
+    let compared = compare.is_some();
+    if let Some(compare) = compare {
+        let other = Waveform::open(compare.as_path())?;
+        let other_entries = selection.collect(&other, &remaps)?;
+        entries = hierarchy_diff::compare_listings(
+            entries,
+            other_entries,
+            &remaps,
+            |entry| entry.path.as_str(),
+            |entry| (entry.kind.as_str(), None),
+        )
         .into_iter()
-        .filter(|entry| filter.is_match(entry.path.as_str()))
-        .map(|entry| ScopeEntry {
-            path: entry.path,
-            depth: entry.depth,
-            kind: entry.kind,
+        .map(|(entry, compare)| ScopeEntry {
+            compare: Some(compare),
+            ..entry
         })
-        .collect::<Vec<_>>();
+        .collect();
+    }
     debug.event(
         "scope.collect.done",
         || serde_json::json!({"scopes": entries.len()}),
@@ -87,10 +127,22 @@ pub fn run(args: ScopeArgs) -> Result<CommandResult, WavepeekError> {
         ));
     }
 
+    if with_source {
+        for entry in &mut entries {
+            let sources = waveform.scope_sources(entry.path.as_str())?;
+            entry.source = sources.declaration;
+            entry.instantiation = sources.instantiation;
+        }
+    }
+
     if entries.is_empty() {
         diagnostics.push(Diagnostic::warning(
             WarningDiagnosticCode::EmptyResult,
-            "no scopes found",
+            if compared {
+                "no scope differences found"
+            } else {
+                "no scopes found"
+            },
         ));
     }
 
@@ -105,3 +157,44 @@ pub fn run(args: ScopeArgs) -> Result<CommandResult, WavepeekError> {
         diagnostics,
     })
 }
+
+/// Scope listing filters, matched against dump-A names for both sides of a `--compare`.
+struct ScopeSelection<'a> {
+    max_depth: Option<usize>,
+    filter: &'a Regex,
+    module_type: Option<&'a Regex>,
+}
+
+impl ScopeSelection<'_> {
+    fn collect(
+        &self,
+        waveform: &Waveform,
+        remaps: &[PrefixRemap],
+    ) -> Result<Vec<ScopeEntry>, WavepeekError> {
+        Ok(waveform
+            .scopes_depth_first(self.max_depth)?
+            .into_iter()
+            .filter(|entry| self.matches(entry, remaps))
+            .map(|entry| ScopeEntry {
+                path: entry.path,
+                depth: entry.depth,
+                kind: entry.kind,
+                module: entry.module,
+                source: None,
+                instantiation: None,
+                compare: None,
+            })
+            .collect())
+    }
+
+    fn matches(&self, entry: &waveform::ScopeEntry, remaps: &[PrefixRemap]) -> bool {
+        self.filter
+            .is_match(hierarchy_diff::b_to_a(remaps, entry.path.as_str()).as_str())
+            && self.module_type.is_none_or(|module_type| {
+                entry
+                    .module
+                    .as_deref()
+                    .is_some_and(|module| module_type.is_match(module))
+            })
+    }
+}
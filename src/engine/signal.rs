@@ -1,10 +1,13 @@
+use std::collections::HashSet;
+
 use crate::cli::limits::LimitArg;
 use crate::cli::signal::SignalArgs;
 use crate::debug_trace::DebugTrace;
 use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::hierarchy_diff::{self, CompareInfo, PrefixRemap};
 use crate::engine::{CommandData, CommandName, CommandResult};
 use crate::error::WavepeekError;
-use crate::waveform::Waveform;
+use crate::waveform::{self, SignalId, Waveform};
 use regex::Regex;
 use serde::Serialize;
 
@@ -17,15 +20,30 @@ pub struct SignalEntry {
     pub kind: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare: Option<CompareInfo>,
 }
 
 pub fn run(args: SignalArgs) -> Result<CommandResult, WavepeekError> {
     let SignalArgs {
         waves,
         scope,
+        global,
+        aliases_of,
+        compare,
+        remap_prefix,
         max,
         filter,
+        path,
+        kind,
+        width,
+        min_width,
+        max_width,
+        direction,
         recursive,
+        dedupe_aliases,
         max_depth,
         abs,
         json,
@@ -37,13 +55,31 @@ pub fn run(args: SignalArgs) -> Result<CommandResult, WavepeekError> {
             "--max must be greater than 0. See 'wavepeek signal --help'.".to_string(),
         ));
     }
+    if let (Some(min_width), Some(max_width)) = (min_width, max_width)
+        && min_width > max_width
+    {
+        return Err(WavepeekError::Args(
+            "--min-width must be less than or equal to --max-width. See 'wavepeek signal --help'."
+                .to_string(),
+        ));
+    }
 
-    let filter = Regex::new(filter.as_str()).map_err(|error| {
-        WavepeekError::Args(format!(
-            "invalid regex '{}': {error}. See 'wavepeek signal --help'.",
-            filter
-        ))
-    })?;
+    let name_filter = compile_regex(filter.as_str())?;
+    let whole_name = compile_regex(format!("^(?:{filter})$").as_str())?;
+    let criteria = SignalCriteria {
+        path: path.as_deref().map(compile_regex).transpose()?,
+        kinds: kind.iter().map(|kind| kind.trim().to_string()).collect(),
+        min_width: width.or(min_width),
+        max_width: width.or(max_width),
+        directions: direction
+            .iter()
+            .map(|direction| direction.as_str())
+            .collect(),
+    };
+    let remaps = remap_prefix
+        .iter()
+        .map(|raw| PrefixRemap::parse("signal", raw))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mut diagnostics = Vec::new();
     if max.is_unlimited() {
@@ -63,6 +99,7 @@ pub fn run(args: SignalArgs) -> Result<CommandResult, WavepeekError> {
         LimitArg::Numeric(value) => Some(value),
         LimitArg::Unlimited => None,
     };
+    let scope = scope.unwrap_or_default();
     let scope_prefix = format!("{scope}.");
 
     let debug = DebugTrace::for_command(CommandName::Signal);
@@ -74,31 +111,92 @@ pub fn run(args: SignalArgs) -> Result<CommandResult, WavepeekError> {
             "format": waveform.format_name(),
         })
     });
-    let waveform_entries = if recursive {
-        waveform.signals_in_scope_recursive(scope.as_str(), effective_max_depth)?
-    } else {
-        waveform.signals_in_scope(scope.as_str())?
+    let whole_dump = global || aliases_of.is_some();
+    let listing = SignalListing {
+        whole_dump,
+        recursive,
+        scope: scope.as_str(),
+        max_depth: effective_max_depth,
+    };
+    let waveform_entries = listing.list(&waveform)?;
+    let waveform_entries = match aliases_of.as_deref() {
+        Some(target) => same_net_entries(&waveform, target, waveform_entries)?,
+        None => waveform_entries,
     };
     debug.event(
         "signal.list.done",
         || serde_json::json!({"signals": waveform_entries.len()}),
     );
-    let mut entries = waveform_entries
-        .into_iter()
-        .filter(|entry| filter.is_match(entry.name.as_str()))
-        .map(|entry| SignalEntry {
-            display: signal_display_name(
-                recursive,
-                scope_prefix.as_str(),
-                entry.path.as_str(),
-                entry.name.as_str(),
-            ),
+    let select = |entry: waveform::SignalEntry, remaps: &[PrefixRemap]| {
+        let a_path = hierarchy_diff::b_to_a(remaps, entry.path.as_str());
+        if !name_filter.is_match(entry.name.as_str()) || !criteria.matches(&entry, &a_path) {
+            return None;
+        }
+        Some(SignalEntry {
+            display: if whole_dump {
+                entry.path.clone()
+            } else {
+                signal_display_name(
+                    recursive,
+                    scope_prefix.as_str(),
+                    a_path.as_str(),
+                    entry.name.as_str(),
+                )
+            },
             name: entry.name,
             path: entry.path,
             kind: entry.kind,
             width: entry.width,
+            direction: entry.direction,
+            compare: None,
         })
+    };
+    let mut entries = waveform_entries
+        .into_iter()
+        .filter_map(|entry| select(entry, &[]))
         .collect::<Vec<_>>();
+    let compared = compare.is_some();
+    if let Some(compare) = compare {
+        let other = Waveform::open(compare.as_path())?;
+        let other_scope = hierarchy_diff::a_to_b(&remaps, scope.as_str());
+        let other_entries = match (SignalListing {
+            scope: other_scope.as_str(),
+            ..listing
+        })
+        .list(&other)
+        {
+            Err(WavepeekError::Scope(_)) if !whole_dump => Vec::new(),
+            other_entries => other_entries?,
+        };
+        let other_entries = other_entries
+            .into_iter()
+            .filter_map(|entry| select(entry, &remaps))
+            .collect::<Vec<_>>();
+        entries = hierarchy_diff::compare_listings(
+            entries,
+            other_entries,
+            &remaps,
+            |entry| entry.path.as_str(),
+            |entry| (entry.kind.as_str(), entry.width),
+        )
+        .into_iter()
+        .map(|(entry, compare)| SignalEntry {
+            compare: Some(compare),
+            ..entry
+        })
+        .collect();
+    }
+    if whole_dump {
+        rank_global_matches(&mut entries, &whole_name);
+    }
+    if dedupe_aliases {
+        let hidden = drop_alias_paths(&waveform, &mut entries)?;
+        if hidden > 0 {
+            diagnostics.push(Diagnostic::info(format!(
+                "--dedupe-aliases hid {hidden} alias path(s) of listed signals"
+            )));
+        }
+    }
     debug.event(
         "signal.filter.done",
         || serde_json::json!({"signals": entries.len()}),
@@ -117,7 +215,13 @@ pub fn run(args: SignalArgs) -> Result<CommandResult, WavepeekError> {
     if entries.is_empty() {
         diagnostics.push(Diagnostic::warning(
             WarningDiagnosticCode::EmptyResult,
-            "no signals found in selected scope",
+            if compared {
+                "no signal differences found"
+            } else if whole_dump {
+                "no signals matched the search filters"
+            } else {
+                "no signals found in selected scope"
+            },
         ));
     }
 
@@ -133,6 +237,132 @@ pub fn run(args: SignalArgs) -> Result<CommandResult, WavepeekError> {
     })
 }
 
+/// Which part of the hierarchy a listing covers; `--compare` reuses it for the second dump.
+#[derive(Clone, Copy)]
+struct SignalListing<'a> {
+    whole_dump: bool,
+    recursive: bool,
+    scope: &'a str,
+    max_depth: Option<usize>,
+}
+
+impl SignalListing<'_> {
+    fn list(&self, waveform: &Waveform) -> Result<Vec<waveform::SignalEntry>, WavepeekError> {
+        if self.whole_dump {
+            let mut entries = Vec::new();
+            for scope in waveform.scopes_depth_first(None)? {
+                entries.extend(waveform.signals_in_scope(scope.path.as_str())?);
+            }
+            Ok(entries)
+        } else if self.recursive {
+            waveform.signals_in_scope_recursive(self.scope, self.max_depth)
+        } else {
+            waveform.signals_in_scope(self.scope)
+        }
+    }
+}
+
+/// Keeps the entries whose path is bound to the same waveform data as `target`.
+fn same_net_entries(
+    waveform: &Waveform,
+    target: &str,
+    entries: Vec<waveform::SignalEntry>,
+) -> Result<Vec<waveform::SignalEntry>, WavepeekError> {
+    let target_id = waveform.resolve_signals(&[target.to_string()])?[0].id;
+    let ids = signal_ids(
+        waveform,
+        entries.iter().map(|entry| entry.path.clone()).collect(),
+    )?;
+    Ok(entries
+        .into_iter()
+        .zip(ids)
+        .filter(|(_, id)| *id == target_id)
+        .map(|(entry, _)| entry)
+        .collect())
+}
+
+/// Drops every entry whose waveform data was already listed under an earlier path.
+fn drop_alias_paths(
+    waveform: &Waveform,
+    entries: &mut Vec<SignalEntry>,
+) -> Result<usize, WavepeekError> {
+    let before = entries.len();
+    let mut seen = HashSet::new();
+    let mut ids = signal_ids(
+        waveform,
+        entries.iter().map(|entry| entry.path.clone()).collect(),
+    )?
+    .into_iter();
+    entries.retain(|_| ids.next().is_some_and(|id| seen.insert(id)));
+    Ok(before - entries.len())
+}
+
+/// Waveform data id of every path, resolved in one batch.
+fn signal_ids(waveform: &Waveform, paths: Vec<String>) -> Result<Vec<SignalId>, WavepeekError> {
+    Ok(waveform
+        .resolve_signals(&paths)?
+        .into_iter()
+        .map(|resolved| resolved.id)
+        .collect())
+}
+
+/// Metadata filters shared by scoped listings and global search.
+struct SignalCriteria<'a> {
+    path: Option<Regex>,
+    kinds: Vec<String>,
+    min_width: Option<u32>,
+    max_width: Option<u32>,
+    directions: Vec<&'a str>,
+}
+
+impl SignalCriteria<'_> {
+    /// `path` is the entry path in dump-A names, which differs from `entry.path` under `--remap-prefix`.
+    fn matches(&self, entry: &crate::waveform::SignalEntry, path: &str) -> bool {
+        if let Some(pattern) = &self.path
+            && !pattern.is_match(path)
+        {
+            return false;
+        }
+        if !self.kinds.is_empty() && !self.kinds.contains(&entry.kind) {
+            return false;
+        }
+        if self.min_width.is_some() || self.max_width.is_some() {
+            let Some(width) = entry.width else {
+                return false;
+            };
+            if self.min_width.is_some_and(|min| width < min)
+                || self.max_width.is_some_and(|max| width > max)
+            {
+                return false;
+            }
+        }
+        self.directions.is_empty()
+            || entry
+                .direction
+                .as_deref()
+                .is_some_and(|direction| self.directions.contains(&direction))
+    }
+}
+
+/// Global search order: whole-name `--filter` matches first, then shallower paths, then path text.
+fn rank_global_matches(entries: &mut [SignalEntry], whole_name: &Regex) {
+    entries.sort_by_cached_key(|entry| {
+        (
+            !whole_name.is_match(entry.name.as_str()),
+            entry.path.matches('.').count(),
+            entry.path.clone(),
+        )
+    });
+}
+
+fn compile_regex(pattern: &str) -> Result<Regex, WavepeekError> {
+    Regex::new(pattern).map_err(|error| {
+        WavepeekError::Args(format!(
+            "invalid regex '{pattern}': {error}. See 'wavepeek signal --help'."
+        ))
+    })
+}
+
 fn signal_display_name(recursive: bool, scope_prefix: &str, path: &str, name: &str) -> String {
     if !recursive {
         return name.to_string();
@@ -140,3 +370,35 @@ fn signal_display_name(recursive: bool, scope_prefix: &str, path: &str, name: &s
 
     path.strip_prefix(scope_prefix).unwrap_or(name).to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SignalCriteria;
+    use crate::waveform::SignalEntry;
+
+    fn entry(width: Option<u32>, direction: Option<&str>) -> SignalEntry {
+        SignalEntry {
+            name: "data".to_string(),
+            path: "top.data".to_string(),
+            kind: "wire".to_string(),
+            width,
+            direction: direction.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn criteria_match_width_range_and_recorded_direction() {
+        let criteria = SignalCriteria {
+            path: None,
+            kinds: Vec::new(),
+            min_width: Some(2),
+            max_width: Some(8),
+            directions: vec!["input", "inout"],
+        };
+        assert!(criteria.matches(&entry(Some(8), Some("input")), "top.data"));
+        assert!(!criteria.matches(&entry(Some(8), Some("output")), "top.data"));
+        assert!(!criteria.matches(&entry(Some(8), None), "top.data"));
+        assert!(!criteria.matches(&entry(Some(16), Some("input")), "top.data"));
+        assert!(!criteria.matches(&entry(None, Some("inout")), "top.data"));
+    }
+}
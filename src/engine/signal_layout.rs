@@ -0,0 +1,266 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::contract::schema::INPUT_SCHEMA_URL;
+use crate::engine::signal_pattern::compile_signal_pattern;
+use crate::engine::signal_select::BitSelect;
+use crate::engine::value::{ValueField, ValueFields};
+use crate::engine::value_format::format_verilog_literal;
+use crate::error::WavepeekError;
+use crate::waveform::Waveform;
+use crate::waveform::expr_host::PackedFieldSource;
+
+const LAYOUTS_KIND: &str = "signal.layouts";
+
+#[derive(Debug, Deserialize)]
+struct LayoutFile {
+    #[serde(rename = "$schema")]
+    schema: String,
+    kind: String,
+    layouts: Vec<LayoutFileEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LayoutFileEntry {
+    signal: String,
+    fields: Vec<LayoutFileField>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LayoutFileField {
+    name: String,
+    msb: u32,
+    lsb: u32,
+    #[serde(rename = "enum", default)]
+    labels: BTreeMap<String, String>,
+}
+
+/// Layouts loaded from a `--layout` file, in declaration order.
+#[derive(Debug, Clone)]
+pub(crate) struct SignalLayouts {
+    entries: Vec<LayoutEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct LayoutEntry {
+    /// Compiled glob or `re:` pattern; `None` for an exact canonical path.
+    pattern: Option<Regex>,
+    layout: SignalLayout,
+}
+
+/// Named bit ranges that decode one packed signal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SignalLayout {
+    signal: String,
+    fields: Vec<LayoutField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LayoutField {
+    name: String,
+    select: BitSelect,
+    /// Labels keyed by the field's bits, MSB first and padded to the field width.
+    labels: HashMap<String, String>,
+}
+
+impl SignalLayouts {
+    pub(crate) fn load(path: &Path, help_command: &str) -> Result<Self, WavepeekError> {
+        let contents = fs::read_to_string(path).map_err(|error| {
+            WavepeekError::File(format!(
+                "failed to read layout file '{}': {error}",
+                path.display()
+            ))
+        })?;
+        let input: LayoutFile = serde_json::from_str(&contents).map_err(|error| {
+            WavepeekError::Args(format!("invalid layout JSON '{}': {error}", path.display()))
+        })?;
+        let invalid = |reason: String| {
+            WavepeekError::Args(format!(
+                "layout file '{}' {reason}. See '{help_command} --help'.",
+                path.display()
+            ))
+        };
+
+        if input.schema != INPUT_SCHEMA_URL {
+            return Err(invalid(format!(
+                "uses unsupported $schema {}; expected {INPUT_SCHEMA_URL}",
+                input.schema
+            )));
+        }
+        if input.kind != LAYOUTS_KIND {
+            return Err(invalid(format!(
+                "has kind {}; expected {LAYOUTS_KIND}",
+                input.kind
+            )));
+        }
+        if input.layouts.is_empty() {
+            return Err(invalid("must contain at least one layout".to_string()));
+        }
+
+        let mut entries = Vec::with_capacity(input.layouts.len());
+        for entry in input.layouts {
+            let signal = entry.signal.trim().to_string();
+            if signal.is_empty() {
+                return Err(invalid(
+                    "contains a layout with an empty signal".to_string(),
+                ));
+            }
+            if entry.fields.is_empty() {
+                return Err(invalid(format!(
+                    "layout for '{signal}' must contain at least one field"
+                )));
+            }
+            let pattern = compile_signal_pattern(signal.as_str(), help_command)?;
+            let mut names = HashSet::new();
+            let mut fields = Vec::with_capacity(entry.fields.len());
+            for field in entry.fields {
+                if !is_field_name(field.name.as_str()) {
+                    return Err(invalid(format!(
+                        "layout for '{signal}' has invalid field name '{}'",
+                        field.name
+                    )));
+                }
+                if !names.insert(field.name.clone()) {
+                    return Err(invalid(format!(
+                        "layout for '{signal}' contains duplicate field '{}'",
+                        field.name
+                    )));
+                }
+                if field.msb < field.lsb {
+                    return Err(invalid(format!(
+                        "layout field '{signal}.{}' must have msb >= lsb",
+                        field.name
+                    )));
+                }
+                let select = BitSelect::new(field.msb, field.lsb);
+                let mut labels = HashMap::with_capacity(field.labels.len());
+                for (key, label) in field.labels {
+                    let bits = enum_key_bits(key.as_str(), select.width()).ok_or_else(|| {
+                        invalid(format!(
+                            "layout field '{signal}.{}' has enum key '{key}' that is not a {}-bit decimal or 0x-prefixed hex value",
+                            field.name,
+                            select.width()
+                        ))
+                    })?;
+                    labels.insert(bits, label);
+                }
+                fields.push(LayoutField {
+                    name: field.name,
+                    select,
+                    labels,
+                });
+            }
+            entries.push(LayoutEntry {
+                pattern,
+                layout: SignalLayout { signal, fields },
+            });
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// First layout whose signal names or matches the canonical `path`.
+    pub(crate) fn for_signal(&self, path: &str) -> Option<&SignalLayout> {
+        self.entries
+            .iter()
+            .find(|entry| match &entry.pattern {
+                Some(pattern) => pattern.is_match(path),
+                None => entry.layout.signal == path,
+            })
+            .map(|entry| &entry.layout)
+    }
+
+    /// Layout for the signal at canonical `path`, checked against its recorded width.
+    pub(crate) fn resolve(
+        &self,
+        waveform: &Waveform,
+        path: &str,
+    ) -> Result<Option<SignalLayout>, WavepeekError> {
+        let Some(layout) = self.for_signal(path) else {
+            return Ok(None);
+        };
+        let resolved = waveform.resolve_signals(&[path.to_string()])?;
+        let width = resolved.first().map_or(0, |signal| signal.width);
+        layout.check_width(path, width)?;
+        Ok(Some(layout.clone()))
+    }
+}
+
+impl PackedFieldSource for SignalLayouts {
+    fn field_range(&self, path: &str, field: &str) -> Option<(u32, u32)> {
+        self.for_signal(path)?
+            .fields
+            .iter()
+            .find(|candidate| candidate.name == field)
+            .map(|candidate| (candidate.select.msb(), candidate.select.lsb()))
+    }
+}
+
+impl SignalLayout {
+    /// Rejects layouts whose fields reach past the `width`-bit signal at `path`.
+    fn check_width(&self, path: &str, width: u32) -> Result<(), WavepeekError> {
+        match self.fields.iter().find(|field| field.select.msb() >= width) {
+            Some(field) => Err(WavepeekError::Signal(format!(
+                "layout field '{}' {} is outside the {width}-bit signal '{path}'",
+                field.name,
+                field.select.suffix()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Decodes an MSB-first bit string into field literals, or enum labels where one matches.
+    pub(crate) fn decode(&self, bits: &str) -> ValueFields {
+        self.fields
+            .iter()
+            .map(|field| {
+                let slice = field.select.slice(bits);
+                let value = field
+                    .labels
+                    .get(slice)
+                    .cloned()
+                    .unwrap_or_else(|| format_verilog_literal(field.select.width(), slice));
+                (field.name.clone(), ValueField::Value(value))
+            })
+            .collect()
+    }
+}
+
+fn is_field_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
+}
+
+/// Bits of an enum key padded to `width`, or `None` when the key does not fit.
+fn enum_key_bits(key: &str, width: u32) -> Option<String> {
+    let value = match key.strip_prefix("0x").or_else(|| key.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16).ok()?,
+        None if key.starts_with(|ch: char| ch.is_ascii_digit()) => key.parse::<u128>().ok()?,
+        None => return None,
+    };
+    let bits = format!("{value:b}");
+    let width = usize::try_from(width).ok()?;
+    (bits.len() <= width).then(|| format!("{bits:0>width$}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::enum_key_bits;
+
+    #[test]
+    fn enum_keys_pad_to_field_width_and_reject_overflow() {
+        assert_eq!(enum_key_bits("2", 4).as_deref(), Some("0010"));
+        assert_eq!(enum_key_bits("0xF", 4).as_deref(), Some("1111"));
+        assert_eq!(enum_key_bits("0", 1).as_deref(), Some("0"));
+        assert_eq!(enum_key_bits("16", 4), None);
+        assert_eq!(enum_key_bits("READ", 4), None);
+        assert_eq!(enum_key_bits("-1", 4), None);
+    }
+}
@@ -0,0 +1,237 @@
+use regex::Regex;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::WavepeekError;
+use crate::waveform::Waveform;
+
+/// Token prefix selecting regex syntax instead of a glob or an exact name.
+const REGEX_PREFIX: &str = "re:";
+/// Expanded names spelled out in the expansion diagnostic before it switches to a count.
+const LISTED_EXPANSIONS: usize = 8;
+
+/// One signal selected by an exact name, glob, or `re:` token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExpandedSignal {
+    /// Path relative to `--scope`, or the canonical path without one.
+    pub display: String,
+    pub path: String,
+}
+
+/// Expands `--signals`/`--payload` pattern tokens against the signals below `--scope`.
+///
+/// Globs match the scope-relative path with `*`/`?` stopping at `.`; `re:` tokens are unanchored
+/// regexes over the same relative path. Candidates are listed once, on the first pattern.
+pub(crate) struct SignalPatternExpander<'a> {
+    waveform: &'a Waveform,
+    scope: Option<&'a str>,
+    flag: &'static str,
+    help_command: &'static str,
+    candidates: Option<Vec<ExpandedSignal>>,
+}
+
+impl<'a> SignalPatternExpander<'a> {
+    pub(crate) fn new(
+        waveform: &'a Waveform,
+        scope: Option<&'a str>,
+        flag: &'static str,
+        help_command: &'static str,
+    ) -> Self {
+        Self {
+            waveform,
+            scope,
+            flag,
+            help_command,
+            candidates: None,
+        }
+    }
+
+    /// Returns `None` for exact names, which callers resolve as before.
+    pub(crate) fn expand(
+        &mut self,
+        token: &str,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<Option<Vec<ExpandedSignal>>, WavepeekError> {
+        let Some(pattern) = compile_signal_pattern(token, self.help_command)? else {
+            return Ok(None);
+        };
+
+        let mut matches = self
+            .candidates()?
+            .iter()
+            .filter(|candidate| pattern.is_match(candidate.display.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        matches.sort_by(|lhs, rhs| lhs.display.cmp(&rhs.display));
+        matches.dedup_by(|lhs, rhs| lhs.path == rhs.path);
+
+        if matches.is_empty() {
+            return Err(WavepeekError::Signal(match self.scope {
+                Some(scope) => format!("pattern '{token}' matched no signals in scope '{scope}'"),
+                None => format!("pattern '{token}' matched no signals in dump"),
+            }));
+        }
+
+        let mut listed = matches
+            .iter()
+            .take(LISTED_EXPANSIONS)
+            .map(|signal| signal.display.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        if matches.len() > LISTED_EXPANSIONS {
+            listed.push_str(format!(", ... ({} more)", matches.len() - LISTED_EXPANSIONS).as_str());
+        }
+        diagnostics.push(Diagnostic::info(format!(
+            "{} pattern '{token}' expanded to {} signal(s): {listed}",
+            self.flag,
+            matches.len()
+        )));
+        Ok(Some(matches))
+    }
+
+    fn candidates(&mut self) -> Result<&[ExpandedSignal], WavepeekError> {
+        if self.candidates.is_none() {
+            let entries = match self.scope {
+                Some(scope) => self.waveform.signals_in_scope_recursive(scope, None)?,
+                None => {
+                    let mut entries = Vec::new();
+                    for scope in self.waveform.scopes_depth_first(None)? {
+                        entries.extend(self.waveform.signals_in_scope(scope.path.as_str())?);
+                    }
+                    entries
+                }
+            };
+            let prefix = self.scope.map(|scope| format!("{scope}."));
+            self.candidates = Some(
+                entries
+                    .into_iter()
+                    .map(|entry| ExpandedSignal {
+                        display: prefix
+                            .as_deref()
+                            .and_then(|prefix| entry.path.strip_prefix(prefix))
+                            .unwrap_or(entry.path.as_str())
+                            .to_string(),
+                        path: entry.path,
+                    })
+                    .collect(),
+            );
+        }
+        Ok(self.candidates.as_deref().unwrap_or_default())
+    }
+}
+
+/// Resolves `--signals` tokens: patterns expand below `scope`, exact names are joined onto it.
+///
+/// A missing `scope` is reported up front so a bad scope does not surface as a bad signal.
+pub(crate) fn resolve_signal_tokens(
+    waveform: &Waveform,
+    scope: Option<&str>,
+    tokens: &[String],
+    help_command: &'static str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<ExpandedSignal>, WavepeekError> {
+    if let Some(scope) = scope {
+        waveform.signals_in_scope(scope)?;
+    }
+
+    let mut patterns = SignalPatternExpander::new(waveform, scope, "--signals", help_command);
+    let mut resolved = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let display = token.trim();
+        if display.is_empty() {
+            return Err(WavepeekError::Args(format!(
+                "signal names must not be empty. See '{help_command} --help'."
+            )));
+        }
+        if let Some(expanded) = patterns.expand(display, diagnostics)? {
+            resolved.extend(expanded);
+            continue;
+        }
+
+        let path = match scope {
+            Some(scope) => format!("{scope}.{display}"),
+            None => display.to_string(),
+        };
+        resolved.push(ExpandedSignal {
+            display: display.to_string(),
+            path,
+        });
+    }
+
+    Ok(resolved)
+}
+
+/// Compiles a glob or `re:` token; exact names yield `None`.
+pub(crate) fn compile_signal_pattern(
+    token: &str,
+    help_command: &str,
+) -> Result<Option<Regex>, WavepeekError> {
+    let source = if let Some(pattern) = token.strip_prefix(REGEX_PREFIX) {
+        pattern.to_string()
+    } else if token.contains(['*', '?']) {
+        glob_to_regex(token)
+    } else {
+        return Ok(None);
+    };
+
+    Regex::new(source.as_str()).map(Some).map_err(|error| {
+        WavepeekError::Args(format!(
+            "invalid signal pattern '{token}': {error}. See '{help_command} --help'."
+        ))
+    })
+}
+
+/// Anchored regex for a glob; `*` and `?` never cross a `.` hierarchy separator.
+///
+/// Brackets stay literal so element names such as `mem[3]` keep working inside globs.
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    for ch in glob.chars() {
+        match ch {
+            '*' => regex.push_str("[^.]*"),
+            '?' => regex.push_str("[^.]"),
+            _ => regex.push_str(regex::escape(ch.encode_utf8(&mut [0; 4])).as_str()),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile_signal_pattern;
+
+    #[test]
+    fn glob_segments_stop_at_hierarchy_separator() {
+        let pattern = compile_signal_pattern("u_core.*_valid", "wavepeek value")
+            .expect("glob should compile")
+            .expect("glob is a pattern");
+        assert!(pattern.is_match("u_core.rd_valid"));
+        assert!(!pattern.is_match("u_core.sub.rd_valid"));
+        assert!(!pattern.is_match("x.u_core.rd_valid"));
+
+        let element = compile_signal_pattern("mem[?]", "wavepeek value")
+            .expect("glob should compile")
+            .expect("glob is a pattern");
+        assert!(element.is_match("mem[3]"));
+        assert!(!element.is_match("mem3"));
+    }
+
+    #[test]
+    fn regex_prefix_is_unanchored_and_exact_names_are_not_patterns() {
+        let pattern = compile_signal_pattern("re:axi_.*data$", "wavepeek value")
+            .expect("regex should compile")
+            .expect("regex is a pattern");
+        assert!(pattern.is_match("u_bus.axi_rdata"));
+        assert!(
+            compile_signal_pattern("top.cpu.clk", "wavepeek value")
+                .expect("exact name is valid")
+                .is_none()
+        );
+        assert!(
+            compile_signal_pattern("mem[0]", "wavepeek value")
+                .expect("exact name is valid")
+                .is_none()
+        );
+        assert!(compile_signal_pattern("re:(", "wavepeek value").is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::error::WavepeekError;
+use crate::expr::ast::{IntegralBase, LogicalExprNode, SelectionKindAst};
+use crate::expr::parse_logical_expr_ast;
+use crate::waveform::Waveform;
+
+/// Placeholder operand the select suffix is attached to before parsing.
+const SELECT_OPERAND: &str = "sel";
+
+/// Constant bit range taken from a `--signals` entry such as `data[15:8]` or `data[8+:8]`.
+///
+/// Bits count from 0 at the LSB, the same way selections inside `--on` expressions do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BitSelect {
+    msb: u32,
+    lsb: u32,
+}
+
+impl BitSelect {
+    pub(crate) const fn new(msb: u32, lsb: u32) -> Self {
+        Self { msb, lsb }
+    }
+
+    pub(crate) const fn msb(self) -> u32 {
+        self.msb
+    }
+
+    pub(crate) const fn lsb(self) -> u32 {
+        self.lsb
+    }
+
+    pub(crate) const fn width(self) -> u32 {
+        self.msb - self.lsb + 1
+    }
+
+    /// Canonical `[msb:lsb]` (or `[bit]`) suffix appended to the selected path.
+    pub(crate) fn suffix(self) -> String {
+        if self.msb == self.lsb {
+            format!("[{}]", self.msb)
+        } else {
+            format!("[{}:{}]", self.msb, self.lsb)
+        }
+    }
+
+    /// Selected bits of an MSB-first bit string that spans the whole signal.
+    pub(crate) fn slice(self, bits: &str) -> &str {
+        let end = bits.len().saturating_sub(self.lsb as usize);
+        let start = bits.len().saturating_sub(self.msb as usize + 1);
+        &bits[start..end]
+    }
+}
+
+/// Signal named by a `--signals` entry, with the bit select it carries, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SelectedSignal {
+    pub path: String,
+    pub select: Option<BitSelect>,
+}
+
+/// Resolves a `--signals` entry that may end in a constant bit or part select.
+///
+/// `to_path` maps a name from the entry to a canonical path. A trailing `[...]` is only read as a
+/// select when the whole entry is not itself a recorded signal, so element names such as
+/// `mem[3]` keep resolving to the element.
+pub(crate) fn resolve_selected_signal(
+    waveform: &Waveform,
+    token: &str,
+    help_command: &str,
+    to_path: impl Fn(&str) -> Result<String, WavepeekError>,
+) -> Result<SelectedSignal, WavepeekError> {
+    let whole = to_path(token);
+    let Some((name, suffix)) = split_select_suffix(token) else {
+        return whole.map(|path| SelectedSignal { path, select: None });
+    };
+    if let Ok(path) = &whole
+        && waveform.resolve_signals(std::slice::from_ref(path)).is_ok()
+    {
+        return whole.map(|path| SelectedSignal { path, select: None });
+    }
+
+    let select = parse_bit_select(token, suffix, help_command)?;
+    let path = to_path(name)?;
+    let resolved = waveform.resolve_signals(std::slice::from_ref(&path))?;
+    let width = resolved.first().map_or(0, |signal| signal.width);
+    if select.msb >= width {
+        return Err(WavepeekError::Signal(format!(
+            "select '{suffix}' is outside the {width}-bit signal '{path}'"
+        )));
+    }
+    Ok(SelectedSignal {
+        path,
+        select: Some(select),
+    })
+}
+
+/// Splits `name[...]` at its last `[`; entries without a trailing `]` have no select.
+fn split_select_suffix(token: &str) -> Option<(&str, &str)> {
+    if !token.ends_with(']') {
+        return None;
+    }
+    let open = token.rfind('[')?;
+    (open > 0).then(|| token.split_at(open))
+}
+
+fn parse_bit_select(
+    token: &str,
+    suffix: &str,
+    help_command: &str,
+) -> Result<BitSelect, WavepeekError> {
+    let invalid = |reason: &str| {
+        WavepeekError::Args(format!(
+            "invalid bit select in signal '{token}': {reason}. See '{help_command} --help'."
+        ))
+    };
+
+    let ast = parse_logical_expr_ast(format!("{SELECT_OPERAND}{suffix}").as_str())
+        .map_err(|diagnostic| invalid(diagnostic.message.as_str()))?;
+    let LogicalExprNode::Selection {
+        base, selection, ..
+    } = ast.root
+    else {
+        return Err(invalid(
+            "expected [bit], [msb:lsb], [base+:width] or [base-:width]",
+        ));
+    };
+    if !matches!(*base, LogicalExprNode::OperandRef { .. }) {
+        return Err(invalid("only one select may follow the signal name"));
+    }
+
+    let index = |node: &LogicalExprNode| {
+        const_index(node).ok_or_else(|| invalid("indices must be constant non-negative integers"))
+    };
+    let indexed_width = |width: u32| {
+        if width == 0 {
+            Err(invalid("indexed select width must be at least 1"))
+        } else {
+            Ok(width)
+        }
+    };
+    let (msb, lsb) = match selection {
+        SelectionKindAst::Bit { index: bit } => {
+            let bit = index(&bit)?;
+            (bit, bit)
+        }
+        SelectionKindAst::Part { msb, lsb } => (index(&msb)?, index(&lsb)?),
+        SelectionKindAst::IndexedUp { base, width } => {
+            let (base, width) = (index(&base)?, indexed_width(index(&width)?)?);
+            let msb = base
+                .checked_add(width - 1)
+                .ok_or_else(|| invalid("indexed select is out of range"))?;
+            (msb, base)
+        }
+        SelectionKindAst::IndexedDown { base, width } => {
+            let (base, width) = (index(&base)?, indexed_width(index(&width)?)?);
+            let lsb = base
+                .checked_sub(width - 1)
+                .ok_or_else(|| invalid("indexed select must stay at or above bit 0"))?;
+            (base, lsb)
+        }
+    };
+    if msb < lsb {
+        return Err(invalid(
+            "part select must be written [msb:lsb] with msb >= lsb",
+        ));
+    }
+    Ok(BitSelect { msb, lsb })
+}
+
+fn const_index(node: &LogicalExprNode) -> Option<u32> {
+    match node {
+        LogicalExprNode::IntegralLiteral { literal, .. } => {
+            let radix = match literal.base {
+                IntegralBase::Binary => 2,
+                IntegralBase::Decimal => 10,
+                IntegralBase::Hex => 16,
+            };
+            u32::from_str_radix(literal.digits.as_str(), radix).ok()
+        }
+        LogicalExprNode::Parenthesized { expr, .. } => const_index(expr),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitSelect, parse_bit_select, split_select_suffix};
+
+    fn parse(suffix: &str) -> Result<BitSelect, String> {
+        parse_bit_select("data", suffix, "wavepeek value").map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn select_forms_normalize_to_lsb_zero_ranges() {
+        assert_eq!(parse("[15:8]"), Ok(BitSelect { msb: 15, lsb: 8 }));
+        assert_eq!(parse("[3]"), Ok(BitSelect { msb: 3, lsb: 3 }));
+        assert_eq!(parse("[8+:8]"), Ok(BitSelect { msb: 15, lsb: 8 }));
+        assert_eq!(parse("[15-:4]"), Ok(BitSelect { msb: 15, lsb: 12 }));
+        assert_eq!(parse("[4'h3]"), Ok(BitSelect { msb: 3, lsb: 3 }));
+        assert!(parse("[0:7]").is_err());
+        assert!(parse("[a]").is_err());
+        assert!(parse("[2-:4]").is_err());
+        assert!(parse("[1'bx]").is_err());
+    }
+
+    #[test]
+    fn slice_counts_bits_from_the_lsb() {
+        let select = BitSelect { msb: 7, lsb: 4 };
+        assert_eq!(select.slice("1010010111110000"), "1111");
+        assert_eq!(select.width(), 4);
+        assert_eq!(select.suffix(), "[7:4]");
+        assert_eq!(BitSelect { msb: 0, lsb: 0 }.slice("10"), "0");
+        assert_eq!(
+            split_select_suffix("mem[3][7:0]"),
+            Some(("mem[3]", "[7:0]"))
+        );
+        assert_eq!(split_select_suffix("[3]"), None);
+        assert_eq!(split_select_suffix("clk"), None);
+    }
+}
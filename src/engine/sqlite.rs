@@ -0,0 +1,322 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use rusqlite::Connection;
+
+use crate::cli::export::SqliteArgs;
+use crate::debug_trace::DebugTrace;
+use crate::engine::expr_runtime::{open_waveform_traced, with_shared_waveform};
+use crate::engine::saif::selected_scopes;
+use crate::engine::time::format_raw_timestamp;
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::waveform::{SignalEntry, Waveform};
+
+/// Signals resolved and loaded per backend call, bounding transient memory on wide scopes.
+const SIGNAL_BATCH: usize = 256;
+
+const METADATA_SQL: &str = "CREATE TABLE metadata(key TEXT NOT NULL, value TEXT NOT NULL)";
+const SCOPES_SQL: &str = "CREATE TABLE scopes(id INTEGER PRIMARY KEY, parent_id INTEGER, path TEXT NOT NULL, name TEXT NOT NULL, depth INTEGER NOT NULL, kind TEXT NOT NULL)";
+const SIGNALS_SQL: &str = "CREATE TABLE signals(id INTEGER PRIMARY KEY, scope_id INTEGER, path TEXT NOT NULL, name TEXT NOT NULL, kind TEXT, width INTEGER)";
+const CHANGES_SQL: &str = "CREATE TABLE changes(signal_id INTEGER NOT NULL, raw_time INTEGER NOT NULL, value TEXT NOT NULL)";
+const SCOPES_PATH_SQL: &str = "CREATE INDEX scopes_path ON scopes(path)";
+const SIGNALS_PATH_SQL: &str = "CREATE INDEX signals_path ON signals(path)";
+const CHANGES_SIGNAL_TIME_SQL: &str =
+    "CREATE INDEX changes_signal_time ON changes(signal_id, raw_time)";
+const CHANGES_TIME_SQL: &str = "CREATE INDEX changes_time ON changes(raw_time)";
+
+/// A signal row and whether it has values to record.
+struct ExportedSignal {
+    entry: SignalEntry,
+    sampled: bool,
+}
+
+pub fn run(args: SqliteArgs) -> Result<CommandResult, WavepeekError> {
+    let debug = DebugTrace::for_command(CommandName::ExportSqlite);
+    let waveform = open_waveform_traced(args.waves.as_path(), &debug)?;
+
+    let mut diagnostics = Vec::new();
+    let (
+        mut waveform,
+        RawTimeWindow {
+            metadata,
+            dump_time,
+            from_raw,
+            to_raw,
+            ..
+        },
+    ) = with_shared_waveform(waveform, |waveform| {
+        resolve_raw_window(
+            waveform,
+            None,
+            None,
+            "wavepeek export sqlite",
+            args.from.as_deref(),
+            args.to.as_deref(),
+            &debug,
+            &mut diagnostics,
+        )
+    })?;
+
+    let scopes = selected_scopes(&waveform, args.scope.as_deref())?;
+    let signals = selected_signals(&waveform, &args, &scopes)?;
+    debug.event(
+        "sqlite.select.done",
+        || serde_json::json!({"scopes": scopes.len(), "signals": signals.len()}),
+    );
+
+    let mut db = create_database(args.out.as_path())?;
+    let write_error = |error| write_error(args.out.as_path(), error);
+    let tx = db.transaction().map_err(write_error)?;
+    tx.execute_batch(
+        [METADATA_SQL, SCOPES_SQL, SIGNALS_SQL, CHANGES_SQL]
+            .map(|sql| format!("{sql};"))
+            .concat()
+            .as_str(),
+    )
+    .map_err(write_error)?;
+
+    {
+        let mut insert = tx
+            .prepare("INSERT INTO metadata VALUES (?1, ?2)")
+            .map_err(write_error)?;
+        for (key, value) in [
+            ("waves", args.waves.display().to_string()),
+            ("time_unit", metadata.time_unit.clone()),
+            ("time_start", metadata.time_start.clone()),
+            ("time_end", metadata.time_end.clone()),
+            ("from", format_raw_timestamp(from_raw, dump_time.dump_tick)?),
+            ("to", format_raw_timestamp(to_raw, dump_time.dump_tick)?),
+        ] {
+            insert.execute((key, value)).map_err(write_error)?;
+        }
+    }
+
+    let mut scope_ids = HashMap::with_capacity(scopes.len());
+    {
+        let mut insert = tx
+            .prepare("INSERT INTO scopes VALUES (?1, ?2, ?3, ?4, ?5, ?6)")
+            .map_err(write_error)?;
+        for (index, scope) in scopes.iter().enumerate() {
+            let id = index as i64 + 1;
+            insert
+                .execute((
+                    id,
+                    parent_id(&scope_ids, scope.path.as_str()),
+                    scope.path.as_str(),
+                    scope.path.rsplit('.').next().unwrap_or_default(),
+                    scope.depth as i64,
+                    scope.kind.as_str(),
+                ))
+                .map_err(write_error)?;
+            scope_ids.insert(scope.path.as_str(), id);
+        }
+    }
+
+    {
+        let mut insert = tx
+            .prepare("INSERT INTO signals VALUES (?1, ?2, ?3, ?4, ?5, ?6)")
+            .map_err(write_error)?;
+        for (index, signal) in signals.iter().enumerate() {
+            let entry = &signal.entry;
+            insert
+                .execute((
+                    index as i64 + 1,
+                    parent_id(&scope_ids, entry.path.as_str()),
+                    entry.path.as_str(),
+                    entry.name.as_str(),
+                    (!entry.kind.is_empty()).then_some(entry.kind.as_str()),
+                    entry.width,
+                ))
+                .map_err(write_error)?;
+        }
+    }
+
+    let mut change_count = 0usize;
+    {
+        let mut insert = tx
+            .prepare("INSERT INTO changes VALUES (?1, ?2, ?3)")
+            .map_err(write_error)?;
+        let sampled = signals
+            .iter()
+            .enumerate()
+            .filter(|(_, signal)| signal.sampled)
+            .map(|(index, signal)| (index as i64 + 1, signal.entry.path.clone()))
+            .collect::<Vec<_>>();
+        for batch in sampled.chunks(SIGNAL_BATCH) {
+            let paths = batch
+                .iter()
+                .map(|(_, path)| path.clone())
+                .collect::<Vec<_>>();
+            let resolved = waveform.resolve_signals(&paths)?;
+            let histories = waveform.signal_transitions(&resolved, from_raw, to_raw)?;
+            for ((signal_id, _), history) in batch.iter().zip(histories) {
+                let mut previous = history.initial;
+                if let Some(value) = previous.as_deref() {
+                    insert
+                        .execute((signal_id, raw_time(from_raw)?, value))
+                        .map_err(write_error)?;
+                    change_count += 1;
+                }
+                for change in history.changes {
+                    let Some(settled) = change.values.last() else {
+                        continue;
+                    };
+                    if previous.as_ref() == Some(settled) {
+                        continue;
+                    }
+                    insert
+                        .execute((signal_id, raw_time(change.time)?, settled))
+                        .map_err(write_error)?;
+                    change_count += 1;
+                    previous = Some(settled.clone());
+                }
+            }
+        }
+    }
+    debug.event(
+        "sqlite.changes.done",
+        || serde_json::json!({"changes": change_count}),
+    );
+
+    // Indexes are built once the tables are filled, which is far cheaper than per-row upkeep.
+    tx.execute_batch(
+        [
+            SCOPES_PATH_SQL,
+            SIGNALS_PATH_SQL,
+            CHANGES_SIGNAL_TIME_SQL,
+            CHANGES_TIME_SQL,
+        ]
+        .map(|sql| format!("{sql};"))
+        .concat()
+        .as_str(),
+    )
+    .map_err(write_error)?;
+    tx.commit().map_err(write_error)?;
+
+    Ok(CommandResult {
+        command: CommandName::ExportSqlite,
+        output_mode: crate::output_mode::OutputMode::Human,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: false,
+        },
+        data: CommandData::Text(format!(
+            "wrote SQLite database with {} signal(s) and {change_count} change(s) in {} scope(s) to {}",
+            signals.len(),
+            scopes.len(),
+            args.out.display()
+        )),
+        diagnostics,
+    })
+}
+
+/// All signals of the selected scopes, or the requested `--signals` in order.
+fn selected_signals(
+    waveform: &Waveform,
+    args: &SqliteArgs,
+    scopes: &[crate::waveform::ScopeEntry],
+) -> Result<Vec<ExportedSignal>, WavepeekError> {
+    let entries = if args.signals.is_empty() {
+        let mut entries = Vec::new();
+        for scope in scopes {
+            entries.extend(waveform.signals_in_scope(scope.path.as_str())?);
+        }
+        entries
+    } else {
+        let mut seen = HashSet::new();
+        let mut paths = Vec::with_capacity(args.signals.len());
+        for token in &args.signals {
+            let name = token.trim();
+            if name.is_empty() {
+                return Err(WavepeekError::Args(
+                    "signal names must not be empty. See '{HELP} --help'.".to_string(),
+                ));
+            }
+            let path = match args.scope.as_deref() {
+                Some(scope) => format!("{scope}.{name}"),
+                None => name.to_string(),
+            };
+            if seen.insert(path.clone()) {
+                paths.push(path);
+            }
+        }
+        let resolved = waveform.resolve_signals(&paths)?;
+
+        // Kinds come from the parent scope listing; paths below non-scope parents keep none.
+        let mut listings: HashMap<String, Vec<SignalEntry>> = HashMap::new();
+        let mut entries = Vec::with_capacity(resolved.len());
+        for signal in resolved {
+            let parent = parent_path(signal.path.as_str()).unwrap_or_default();
+            let listed = listings
+                .entry(parent.to_string())
+                .or_insert_with(|| waveform.signals_in_scope(parent).unwrap_or_default())
+                .iter()
+                .find(|entry| entry.path == signal.path)
+                .cloned();
+            entries.push(listed.unwrap_or_else(|| {
+                SignalEntry {
+                    name: signal
+                        .path
+                        .rsplit('.')
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    path: signal.path.clone(),
+                    kind: String::new(),
+                    width: Some(signal.width),
+                    direction: None,
+                }
+            }));
+        }
+        entries
+    };
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| ExportedSignal {
+            sampled: entry.width.is_some() && entry.kind != "event",
+            entry,
+        })
+        .collect())
+}
+
+fn parent_path(path: &str) -> Option<&str> {
+    path.rsplit_once('.').map(|(parent, _)| parent)
+}
+
+fn parent_id(scope_ids: &HashMap<&str, i64>, path: &str) -> Option<i64> {
+    parent_path(path)
+        .and_then(|parent| scope_ids.get(parent))
+        .copied()
+}
+
+/// Opens a fresh database at `path`, replacing any existing file.
+fn create_database(path: &Path) -> Result<Connection, WavepeekError> {
+    match fs::remove_file(path) {
+        Ok(()) => {}
+        Err(error) if error.kind() == ErrorKind::NotFound => {}
+        Err(error) => return Err(write_error(path, error)),
+    }
+    let db = Connection::open(path).map_err(|error| write_error(path, error))?;
+    // A failed export leaves no usable file anyway, so skip the rollback journal.
+    db.execute_batch("PRAGMA journal_mode = OFF; PRAGMA synchronous = OFF;")
+        .map_err(|error| write_error(path, error))?;
+    Ok(db)
+}
+
+fn write_error(path: &Path, error: impl Display) -> WavepeekError {
+    WavepeekError::File(format!("cannot write '{}': {error}", path.display()))
+}
+
+fn raw_time(raw: u64) -> Result<i64, WavepeekError> {
+    i64::try_from(raw).map_err(|_| {
+        WavepeekError::Internal(format!(
+            "raw timestamp {raw} exceeds the SQLite integer range"
+        ))
+    })
+}
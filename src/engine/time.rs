@@ -1,3 +1,4 @@
+use crate::cli::time_unit::OutputTimeUnit;
 use crate::error::WavepeekError;
 use crate::waveform::WaveformMetadata;
 
@@ -20,7 +21,7 @@ pub(crate) enum TimeUnit {
 }
 
 impl TimeUnit {
-    fn suffix(self) -> &'static str {
+    pub(crate) fn suffix(self) -> &'static str {
         match self {
             Self::Zs => "zs",
             Self::As => "as",
@@ -161,6 +162,17 @@ pub(crate) fn parse_dump_time_context(
     })
 }
 
+/// Dump start and end in raw ticks.
+pub(crate) fn dump_bounds_raw(context: DumpTimeContext) -> Result<(u64, u64), WavepeekError> {
+    let start = u64::try_from(context.dump_start_zs / context.dump_tick_zs).map_err(|_| {
+        WavepeekError::Internal("dump start timestamp exceeds supported range".to_string())
+    })?;
+    let end = u64::try_from(context.dump_end_zs / context.dump_tick_zs).map_err(|_| {
+        WavepeekError::Internal("dump end timestamp exceeds supported range".to_string())
+    })?;
+    Ok((start, end))
+}
+
 pub(crate) fn validate_time_token_to_raw(
     token: &str,
     context: DumpTimeContext,
@@ -185,13 +197,138 @@ pub(crate) fn validate_time_token_to_raw(
     u64::try_from(raw).map_err(|_| TimeValidationError::RawOutOfRange)
 }
 
+/// Converts a duration such as `20ps` into whole dump ticks.
+pub(crate) fn validate_duration_to_ticks(
+    token: &str,
+    context: DumpTimeContext,
+) -> Result<u64, TimeValidationError> {
+    let parsed = parse_time_token(token).ok_or(TimeValidationError::InvalidToken)?;
+    let duration_zs = as_zeptoseconds(parsed).ok_or(TimeValidationError::TooLarge)?;
+    if duration_zs % context.dump_tick_zs != 0 {
+        return Err(TimeValidationError::NotAligned);
+    }
+    u64::try_from(duration_zs / context.dump_tick_zs)
+        .map_err(|_| TimeValidationError::RawOutOfRange)
+}
+
+/// [`validate_duration_to_ticks`] with the error as a short reason.
+pub(crate) fn duration_ticks(token: &str, context: DumpTimeContext) -> Result<u64, String> {
+    validate_duration_to_ticks(token, context).map_err(|error| match error {
+        TimeValidationError::NotAligned => match format_raw_timestamp(1, context.dump_tick) {
+            Ok(dump_precision) => {
+                format!("cannot be represented exactly in dump precision '{dump_precision}'")
+            }
+            Err(error) => error.to_string(),
+        },
+        TimeValidationError::TooLarge | TimeValidationError::RawOutOfRange => {
+            "exceeds supported raw timestamp range".to_string()
+        }
+        _ => "expected <integer><unit> (for example 100ps)".to_string(),
+    })
+}
+
+/// Row timestamp rendering selected with `--time-unit` and `--ticks`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TimeFormat {
+    dump_tick: ParsedTime,
+    unit: OutputTimeUnit,
+    ticks: bool,
+}
+
+impl TimeFormat {
+    pub(crate) fn new(dump_tick: ParsedTime, unit: OutputTimeUnit, ticks: bool) -> Self {
+        Self {
+            dump_tick,
+            unit,
+            ticks,
+        }
+    }
+
+    /// Formats raw dump ticks in the selected unit, with an exact decimal fraction when needed.
+    pub(crate) fn format(self, raw_time: u64) -> Result<String, WavepeekError> {
+        if self.unit == OutputTimeUnit::Dump {
+            return format_raw_timestamp(raw_time, self.dump_tick);
+        }
+        let zs = as_zeptoseconds(self.dump_tick)
+            .and_then(|tick_zs| u128::from(raw_time).checked_mul(tick_zs))
+            .ok_or_else(|| {
+                WavepeekError::Internal(
+                    "normalized time overflow while formatting timestamp".to_string(),
+                )
+            })?;
+        Ok(self.format_zeptoseconds(zs))
+    }
+
+    /// Formats a duration that need not be a whole number of dump ticks, such as a mean.
+    pub(crate) fn format_zeptoseconds(self, zs: u128) -> String {
+        let unit = match self.unit {
+            OutputTimeUnit::Dump => Some(self.dump_tick.unit),
+            OutputTimeUnit::Auto => None,
+            OutputTimeUnit::Zs => Some(TimeUnit::Zs),
+            OutputTimeUnit::As => Some(TimeUnit::As),
+            OutputTimeUnit::Fs => Some(TimeUnit::Fs),
+            OutputTimeUnit::Ps => Some(TimeUnit::Ps),
+            OutputTimeUnit::Ns => Some(TimeUnit::Ns),
+            OutputTimeUnit::Us => Some(TimeUnit::Us),
+            OutputTimeUnit::Ms => Some(TimeUnit::Ms),
+            OutputTimeUnit::S => Some(TimeUnit::S),
+        };
+        let unit = unit.unwrap_or_else(|| auto_unit(zs, self.dump_tick.unit));
+        format_zeptoseconds(zs, unit)
+    }
+
+    /// True when rows render as plain dump-unit times without a `tick` field.
+    pub(crate) fn is_dump_default(self) -> bool {
+        self.unit == OutputTimeUnit::Dump && !self.ticks
+    }
+
+    /// Raw tick for the JSON `tick` field, present only with `--ticks`.
+    pub(crate) fn tick(self, raw_time: u64) -> Option<u64> {
+        self.ticks.then_some(raw_time)
+    }
+}
+
+/// Largest unit that keeps the whole part non-zero; zero keeps the dump unit.
+fn auto_unit(zs: u128, dump_unit: TimeUnit) -> TimeUnit {
+    [
+        TimeUnit::S,
+        TimeUnit::Ms,
+        TimeUnit::Us,
+        TimeUnit::Ns,
+        TimeUnit::Ps,
+        TimeUnit::Fs,
+        TimeUnit::As,
+        TimeUnit::Zs,
+    ]
+    .into_iter()
+    .find(|unit| zs >= unit.multiplier_in_zeptoseconds())
+    .unwrap_or(dump_unit)
+}
+
+fn format_zeptoseconds(zs: u128, unit: TimeUnit) -> String {
+    let multiplier = unit.multiplier_in_zeptoseconds();
+    let whole = zs / multiplier;
+    let fraction = zs % multiplier;
+    if fraction == 0 {
+        return format!("{whole}{}", unit.suffix());
+    }
+    let digits = multiplier.ilog10() as usize;
+    let fraction = format!("{fraction:0digits$}");
+    format!(
+        "{whole}.{}{}",
+        fraction.trim_end_matches('0'),
+        unit.suffix()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        DumpTimeContext, ParsedTime, TimeUnit, TimeValidationError, as_zeptoseconds,
-        ensure_non_zero_dump_tick, format_raw_timestamp, parse_dump_time_context, parse_time_token,
-        validate_time_token_to_raw,
+        DumpTimeContext, ParsedTime, TimeFormat, TimeUnit, TimeValidationError, as_zeptoseconds,
+        duration_ticks, ensure_non_zero_dump_tick, format_raw_timestamp, parse_dump_time_context,
+        parse_time_token, validate_duration_to_ticks, validate_time_token_to_raw,
     };
+    use crate::cli::time_unit::OutputTimeUnit;
     use crate::waveform::WaveformMetadata;
 
     fn metadata() -> WaveformMetadata {
@@ -318,6 +455,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn duration_ticks_ignore_dump_bounds_and_require_whole_ticks() {
+        let context = parse_dump_time_context(&metadata()).expect("metadata should parse");
+        let too_large_token = format!("{}s", u64::MAX);
+
+        assert_eq!(validate_duration_to_ticks("20ns", context), Ok(20));
+        assert_eq!(
+            validate_duration_to_ticks("10", context),
+            Err(TimeValidationError::InvalidToken)
+        );
+        assert_eq!(
+            validate_duration_to_ticks(too_large_token.as_str(), context),
+            Err(TimeValidationError::TooLarge)
+        );
+        assert_eq!(
+            duration_ticks("1500ps", context),
+            Err("cannot be represented exactly in dump precision '1ns'".to_string())
+        );
+    }
+
     #[test]
     fn validate_time_token_to_raw_rejects_raw_range_overflow() {
         let token = format!("{}ns", u64::MAX);
@@ -422,4 +579,26 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn time_format_renders_selected_units_with_exact_fractions() {
+        let ps = ParsedTime {
+            value: 1,
+            unit: TimeUnit::Ps,
+        };
+        let ns = TimeFormat::new(ps, OutputTimeUnit::Ns, false);
+        assert_eq!(ns.format(124_999).expect("format"), "124.999ns");
+        assert_eq!(ns.format(125_000).expect("format"), "125ns");
+        assert_eq!(ns.format(7).expect("format"), "0.007ns");
+        assert_eq!(ns.tick(7), None);
+
+        let auto = TimeFormat::new(ps, OutputTimeUnit::Auto, true);
+        assert_eq!(auto.format(1_500_000).expect("format"), "1.5us");
+        assert_eq!(auto.format(999).expect("format"), "999ps");
+        assert_eq!(auto.format(0).expect("format"), "0ps");
+        assert_eq!(auto.tick(7), Some(7));
+
+        let dump = TimeFormat::new(ps, OutputTimeUnit::Dump, false);
+        assert_eq!(dump.format(124_999).expect("format"), "124999ps");
+    }
 }
@@ -0,0 +1,615 @@
+use crate::debug_trace::DebugTrace;
+use crate::diagnostic::Diagnostic;
+use crate::engine::cycle_clock::{CYCLE_UNIT, CycleClock};
+use crate::engine::expr_runtime::{SharedWaveform, named_event_match_times};
+use crate::engine::extract::parse_bound_time;
+use crate::engine::time::{
+    DumpTimeContext, TimeValidationError, as_zeptoseconds, dump_bounds_raw, format_raw_timestamp,
+    parse_dump_time_context, parse_time_token, validate_duration_to_ticks,
+};
+use crate::error::WavepeekError;
+use crate::waveform::WaveformMetadata;
+
+/// `--from`/`--to` tokens with time expressions replaced by absolute dump times.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct TimeWindowArgs {
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// Dump metadata plus the `--from`/`--to` window in raw ticks.
+#[derive(Debug, Clone)]
+pub(crate) struct RawTimeWindow {
+    pub metadata: WaveformMetadata,
+    pub dump_time: DumpTimeContext,
+    pub dump_start_raw: u64,
+    pub dump_end_raw: u64,
+    pub from_raw: u64,
+    pub to_raw: u64,
+    /// Reference clock for `cyc` units and row `cycle` fields; `None` without `--clock`.
+    pub clock: Option<CycleClock>,
+}
+
+/// Loads dump metadata and resolves `--from`/`--to` for commands that scan one time window.
+///
+/// `clock` is the `--clock` event numbering cycles; missing bounds default to the dump start/end.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn resolve_raw_window(
+    waveform: &SharedWaveform,
+    scope: Option<&str>,
+    clock: Option<&str>,
+    help_command: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    debug: &DebugTrace,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<RawTimeWindow, WavepeekError> {
+    let metadata = waveform.borrow().metadata()?;
+    debug.event("metadata.load.done", || serde_json::json!({}));
+    let dump_time = parse_dump_time_context(&metadata)?;
+    let (dump_start_raw, dump_end_raw) = dump_bounds_raw(dump_time)?;
+
+    let clock = CycleClock::load(waveform, scope, clock, dump_time, help_command)?;
+    let window = resolve_time_window(
+        waveform,
+        scope,
+        clock.as_ref(),
+        dump_time,
+        help_command,
+        from,
+        to,
+        diagnostics,
+    )?;
+    let from_raw = match window.from.as_deref() {
+        Some(token) => parse_bound_time(token, "--from", dump_time, &metadata, help_command)?,
+        None => dump_start_raw,
+    };
+    let to_raw = match window.to.as_deref() {
+        Some(token) => parse_bound_time(token, "--to", dump_time, &metadata, help_command)?,
+        None => dump_end_raw,
+    };
+    if from_raw > to_raw {
+        return Err(WavepeekError::Args(format!(
+            "--from must be less than or equal to --to. See '{help_command} --help'."
+        )));
+    }
+
+    Ok(RawTimeWindow {
+        metadata,
+        dump_time,
+        dump_start_raw,
+        dump_end_raw,
+        from_raw,
+        to_raw,
+        clock,
+    })
+}
+
+/// Resolves `start`, `end`, `+D`, `Ncyc`, and `first/last/nth(...)` anchors in `--from`/`--to`.
+///
+/// Plain tokens pass through untouched so the caller's own validation reports them as before.
+/// A leading `+D` in `--to` counts from `--from` (or the dump start without one).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn resolve_time_window(
+    waveform: &SharedWaveform,
+    scope: Option<&str>,
+    clock: Option<&CycleClock>,
+    dump_time: DumpTimeContext,
+    help_command: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<TimeWindowArgs, WavepeekError> {
+    let resolver = TimeExprResolver::new(waveform, scope, clock, dump_time, help_command)?;
+    let from = from
+        .map(|token| resolver.resolve(token, "--from", None, diagnostics))
+        .transpose()?;
+    let relative_base = match &from {
+        Some(Resolved::Raw(raw)) => Some(*raw),
+        Some(Resolved::Plain(token)) if is_relative(to) => Some(resolver.plain_raw(token)?),
+        _ => None,
+    };
+    let to = to
+        .map(|token| resolver.resolve(token, "--to", relative_base, diagnostics))
+        .transpose()?;
+    Ok(TimeWindowArgs {
+        from: from.map(|time| resolver.token_for(time)).transpose()?,
+        to: to.map(|time| resolver.token_for(time)).transpose()?,
+    })
+}
+
+/// Resolves a comma-separated `--at` list; a leading `+D` counts from the previous entry.
+///
+/// Commas inside `nth(...)` and other anchor parentheses do not split entries. Empty entries
+/// are kept so the caller reports them with its own message.
+pub(crate) fn resolve_time_list(
+    waveform: &SharedWaveform,
+    scope: Option<&str>,
+    clock: Option<&CycleClock>,
+    dump_time: DumpTimeContext,
+    help_command: &str,
+    list: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<String>, WavepeekError> {
+    let resolver = TimeExprResolver::new(waveform, scope, clock, dump_time, help_command)?;
+    let mut previous = None;
+    let mut tokens = Vec::new();
+    for entry in split_time_list(list) {
+        let token = entry.trim();
+        if token.is_empty() {
+            tokens.push(String::new());
+            continue;
+        }
+        let base = match &previous {
+            Some(Resolved::Plain(plain)) if token.starts_with('+') => {
+                Some(resolver.plain_raw(plain)?)
+            }
+            Some(Resolved::Raw(raw)) => Some(*raw),
+            _ => None,
+        };
+        let resolved = resolver.resolve(token, "--at", base, diagnostics)?;
+        tokens.push(resolver.token_for(resolved.clone())?);
+        previous = Some(resolved);
+    }
+    Ok(tokens)
+}
+
+/// Whether `token` uses time-expression syntax rather than a plain `<integer><unit>` time.
+pub(crate) fn is_time_expression(token: &str) -> bool {
+    let token = token.trim();
+    token.starts_with('+')
+        || token.contains('(')
+        || token.ends_with(CYCLE_UNIT)
+        || ["start", "end"]
+            .iter()
+            .any(|keyword| token.starts_with(keyword))
+        || token
+            .char_indices()
+            .any(|(index, ch)| index > 0 && matches!(ch, '+' | '-'))
+}
+
+fn is_relative(token: Option<&str>) -> bool {
+    token.is_some_and(|token| token.trim().starts_with('+'))
+}
+
+/// Splits on commas that sit outside parentheses.
+fn split_time_list(list: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, ch) in list.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push(&list[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(&list[start..]);
+    entries
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Resolved {
+    /// Plain token left for the caller to validate.
+    Plain(String),
+    /// Raw dump timestamp computed from a time expression.
+    Raw(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Anchor<'a> {
+    Start,
+    End,
+    Absolute(&'a str),
+    Relative,
+    First(&'a str),
+    Last(&'a str),
+    Nth(u64, &'a str),
+}
+
+/// Signed `(negative, duration)` offset applied after the anchor.
+type Offset<'a> = (bool, &'a str);
+
+struct TimeExprResolver<'a> {
+    waveform: &'a SharedWaveform,
+    scope: Option<&'a str>,
+    clock: Option<&'a CycleClock>,
+    dump_time: DumpTimeContext,
+    help_command: &'a str,
+    start_raw: u64,
+    end_raw: u64,
+}
+
+impl<'a> TimeExprResolver<'a> {
+    fn new(
+        waveform: &'a SharedWaveform,
+        scope: Option<&'a str>,
+        clock: Option<&'a CycleClock>,
+        dump_time: DumpTimeContext,
+        help_command: &'a str,
+    ) -> Result<Self, WavepeekError> {
+        let raw = |zs: u128, bound: &str| {
+            u64::try_from(zs / dump_time.dump_tick_zs).map_err(|_| {
+                WavepeekError::Internal(format!("dump {bound} timestamp exceeds supported range"))
+            })
+        };
+        Ok(Self {
+            waveform,
+            scope,
+            clock,
+            dump_time,
+            help_command,
+            start_raw: raw(dump_time.dump_start_zs, "start")?,
+            end_raw: raw(dump_time.dump_end_zs, "end")?,
+        })
+    }
+
+    fn invalid(
+        &self,
+        token: &str,
+        arg_name: &str,
+        reason: impl std::fmt::Display,
+    ) -> WavepeekError {
+        WavepeekError::Args(format!(
+            "invalid time expression '{token}' for {arg_name}: {reason}. See '{} --help'.",
+            self.help_command
+        ))
+    }
+
+    fn resolve(
+        &self,
+        token: &str,
+        arg_name: &str,
+        relative_base: Option<u64>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<Resolved, WavepeekError> {
+        let token = token.trim();
+        if !is_time_expression(token) {
+            return Ok(Resolved::Plain(token.to_string()));
+        }
+
+        let (anchor, offsets) = self.parse(token, arg_name)?;
+        let mut raw = match anchor {
+            Anchor::Start => self.start_raw,
+            Anchor::End => self.end_raw,
+            Anchor::Relative => relative_base.unwrap_or(self.start_raw),
+            Anchor::Absolute(time) => match self.cycle_count(token, arg_name, time)? {
+                Some((clock, cycle)) => {
+                    self.cycle_edge(token, arg_name, clock, i128::from(cycle))?
+                }
+                None => self.duration_ticks(token, arg_name, time)?,
+            },
+            Anchor::First(event) => self.event_time(token, arg_name, event, EventPick::First)?,
+            Anchor::Last(event) => self.event_time(token, arg_name, event, EventPick::Last)?,
+            Anchor::Nth(n, event) => self.event_time(token, arg_name, event, EventPick::Nth(n))?,
+        };
+        for (negative, offset) in offsets {
+            if let Some((clock, count)) = self.cycle_count(token, arg_name, offset)? {
+                let current = clock.cycle_at(raw).map_or(0, i128::from);
+                let count = i128::from(count);
+                let cycle = if negative {
+                    current - count
+                } else {
+                    current + count
+                };
+                raw = self.cycle_edge(token, arg_name, clock, cycle)?;
+                continue;
+            }
+            let ticks = self.duration_ticks(token, arg_name, offset)?;
+            raw = if negative {
+                raw.checked_sub(ticks)
+                    .ok_or_else(|| self.out_of_bounds(token, arg_name, "before time 0"))?
+            } else {
+                raw.checked_add(ticks).ok_or_else(|| {
+                    self.out_of_bounds(token, arg_name, "past the largest supported time")
+                })?
+            };
+        }
+        if raw < self.start_raw || raw > self.end_raw {
+            let resolved = format!(
+                "to {}",
+                format_raw_timestamp(raw, self.dump_time.dump_tick)?
+            );
+            return Err(self.out_of_bounds(token, arg_name, resolved.as_str()));
+        }
+
+        diagnostics.push(Diagnostic::info(format!(
+            "{arg_name} time expression '{token}' resolved to {}",
+            format_raw_timestamp(raw, self.dump_time.dump_tick)?
+        )));
+        Ok(Resolved::Raw(raw))
+    }
+
+    /// Splits `anchor (+|-)duration...` into the anchor and its signed offsets.
+    fn parse<'t>(
+        &self,
+        token: &'t str,
+        arg_name: &str,
+    ) -> Result<(Anchor<'t>, Vec<Offset<'t>>), WavepeekError> {
+        let (anchor, rest) = if token.starts_with('+') {
+            (Anchor::Relative, token)
+        } else if let Some(open) = token.find('(') {
+            let close = matching_paren(token, open)
+                .ok_or_else(|| self.invalid(token, arg_name, "unbalanced parentheses"))?;
+            let inner = token[open + 1..close].trim();
+            let anchor = match token[..open].trim() {
+                "first" => Anchor::First(inner),
+                "last" => Anchor::Last(inner),
+                "nth" => {
+                    let (count, event) = inner.split_once(',').ok_or_else(|| {
+                        self.invalid(token, arg_name, "nth expects nth(N, <event>)")
+                    })?;
+                    let count = count
+                        .trim()
+                        .parse::<u64>()
+                        .ok()
+                        .filter(|count| *count > 0)
+                        .ok_or_else(|| {
+                            self.invalid(token, arg_name, "nth count must be a positive integer")
+                        })?;
+                    Anchor::Nth(count, event.trim())
+                }
+                other => {
+                    return Err(self.invalid(
+                        token,
+                        arg_name,
+                        format!("unknown anchor '{other}'; expected first, last, or nth"),
+                    ));
+                }
+            };
+            if matches!(
+                anchor,
+                Anchor::First("") | Anchor::Last("") | Anchor::Nth(_, "")
+            ) {
+                return Err(self.invalid(token, arg_name, "anchor event must not be empty"));
+            }
+            (anchor, &token[close + 1..])
+        } else {
+            let split = token
+                .char_indices()
+                .skip(1)
+                .find(|(_, ch)| matches!(ch, '+' | '-'))
+                .map_or(token.len(), |(index, _)| index);
+            let anchor = match token[..split].trim() {
+                "start" => Anchor::Start,
+                "end" => Anchor::End,
+                time => Anchor::Absolute(time),
+            };
+            (anchor, &token[split..])
+        };
+
+        let mut offsets = Vec::new();
+        let mut rest = rest.trim_start();
+        while let Some(sign) = rest.chars().next() {
+            let negative = match sign {
+                '+' => false,
+                '-' => true,
+                _ => {
+                    return Err(self.invalid(
+                        token,
+                        arg_name,
+                        format!("unexpected '{}' after the anchor", rest.trim()),
+                    ));
+                }
+            };
+            let body = &rest[1..];
+            let end = body.find(['+', '-']).unwrap_or(body.len());
+            offsets.push((negative, body[..end].trim()));
+            rest = body[end..].trim_start();
+        }
+        Ok((anchor, offsets))
+    }
+
+    /// Count of an `<integer>cyc` time, or `None` when `time` uses another unit.
+    fn cycle_count(
+        &self,
+        token: &str,
+        arg_name: &str,
+        time: &str,
+    ) -> Result<Option<(&'a CycleClock, u64)>, WavepeekError> {
+        let Some(count) = time.strip_suffix(CYCLE_UNIT) else {
+            return Ok(None);
+        };
+        let clock = self.clock.ok_or_else(|| {
+            self.invalid(
+                token,
+                arg_name,
+                "cycle units need a --clock reference clock",
+            )
+        })?;
+        let count = count.parse::<u64>().map_err(|_| {
+            self.invalid(
+                token,
+                arg_name,
+                format!("'{time}' is not an <integer>{CYCLE_UNIT} cycle count"),
+            )
+        })?;
+        Ok(Some((clock, count)))
+    }
+
+    /// Time of the edge starting `cycle`, which must be one the `--clock` recorded.
+    fn cycle_edge(
+        &self,
+        token: &str,
+        arg_name: &str,
+        clock: &CycleClock,
+        cycle: i128,
+    ) -> Result<u64, WavepeekError> {
+        u64::try_from(cycle)
+            .ok()
+            .and_then(|cycle| clock.edge(cycle))
+            .ok_or_else(|| {
+                self.invalid(
+                    token,
+                    arg_name,
+                    format!(
+                        "cycle {cycle} is outside cycles 1..{} of --clock '{}'",
+                        clock.edge_count(),
+                        clock.source()
+                    ),
+                )
+            })
+    }
+
+    /// Converts an `<integer><unit>` duration into whole dump ticks.
+    fn duration_ticks(
+        &self,
+        token: &str,
+        arg_name: &str,
+        time: &str,
+    ) -> Result<u64, WavepeekError> {
+        match validate_duration_to_ticks(time, self.dump_time) {
+            Ok(ticks) => Ok(ticks),
+            Err(TimeValidationError::NotAligned) => {
+                let precision = format_raw_timestamp(1, self.dump_time.dump_tick)?;
+                Err(self.invalid(
+                    token,
+                    arg_name,
+                    format!(
+                        "'{time}' cannot be represented exactly in dump precision '{precision}'"
+                    ),
+                ))
+            }
+            Err(TimeValidationError::TooLarge | TimeValidationError::RawOutOfRange) => {
+                Err(self.invalid(token, arg_name, format!("'{time}' is too large")))
+            }
+            Err(_) => Err(self.invalid(
+                token,
+                arg_name,
+                format!("'{time}' is not an <integer><unit> time (for example 10ns)"),
+            )),
+        }
+    }
+
+    /// `resolved` completes "resolves ...", e.g. "to 45ns" or "before time 0".
+    fn out_of_bounds(&self, token: &str, arg_name: &str, resolved: &str) -> WavepeekError {
+        let format = |raw| format_raw_timestamp(raw, self.dump_time.dump_tick).unwrap_or_default();
+        WavepeekError::Args(format!(
+            "time expression '{token}' for {arg_name} resolves {resolved}, outside dump bounds [{}, {}]. See '{} --help'.",
+            format(self.start_raw),
+            format(self.end_raw),
+            self.help_command
+        ))
+    }
+
+    /// Matching time of an anchor event, searched over the whole dump.
+    fn event_time(
+        &self,
+        token: &str,
+        arg_name: &str,
+        event: &str,
+        pick: EventPick,
+    ) -> Result<u64, WavepeekError> {
+        let times = named_event_match_times(
+            self.waveform,
+            self.scope,
+            event,
+            self.start_raw,
+            self.end_raw,
+        )?
+        .ok_or_else(|| {
+            self.invalid(
+                token,
+                arg_name,
+                "anchor events must name their signals instead of using '*'",
+            )
+        })?;
+        let picked = match pick {
+            EventPick::First => times.first(),
+            EventPick::Last => times.last(),
+            EventPick::Nth(n) => usize::try_from(n - 1)
+                .ok()
+                .and_then(|index| times.get(index)),
+        };
+        match (picked, pick) {
+            (Some(timestamp), _) => Ok(*timestamp),
+            (None, EventPick::Nth(n)) => Err(WavepeekError::Args(format!(
+                "time expression '{token}' for {arg_name} needs {n} matches of '{event}' but the dump has {}. See '{} --help'.",
+                times.len(),
+                self.help_command
+            ))),
+            (None, _) => Err(WavepeekError::Args(format!(
+                "time expression '{token}' for {arg_name} found no match of '{event}' in the dump. See '{} --help'.",
+                self.help_command
+            ))),
+        }
+    }
+
+    /// Raw time of a plain token that another entry counts from.
+    fn plain_raw(&self, token: &str) -> Result<u64, WavepeekError> {
+        let zs = parse_time_token(token)
+            .and_then(as_zeptoseconds)
+            .ok_or_else(|| {
+                WavepeekError::Args(format!(
+                    "invalid time token '{token}': expected <integer><unit> (for example 10ns). See '{} --help'.",
+                    self.help_command
+                ))
+            })?;
+        u64::try_from(zs / self.dump_time.dump_tick_zs).map_err(|_| {
+            WavepeekError::Args(format!(
+                "time '{token}' exceeds supported raw timestamp range. See '{} --help'.",
+                self.help_command
+            ))
+        })
+    }
+
+    fn token_for(&self, resolved: Resolved) -> Result<String, WavepeekError> {
+        match resolved {
+            Resolved::Plain(token) => Ok(token),
+            Resolved::Raw(raw) => format_raw_timestamp(raw, self.dump_time.dump_tick),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventPick {
+    First,
+    Last,
+    Nth(u64),
+}
+
+fn matching_paren(token: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, ch) in token[open..].char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_time_expression, split_time_list};
+
+    #[test]
+    fn time_expressions_are_told_apart_from_plain_tokens() {
+        for token in [
+            "end",
+            "start+10ns",
+            "end-200ns",
+            "+500ns",
+            "first(posedge clk)",
+        ] {
+            assert!(is_time_expression(token), "{token}");
+        }
+        for token in ["10ns", "0", "1.5ns", "abc"] {
+            assert!(!is_time_expression(token), "{token}");
+        }
+        assert_eq!(
+            split_time_list("5ns, nth(3, posedge done),+2ns"),
+            vec!["5ns", " nth(3, posedge done)", "+2ns"]
+        );
+    }
+}
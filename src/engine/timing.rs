@@ -0,0 +1,253 @@
+use serde::Serialize;
+
+use crate::cli::timing::TimingArgs;
+use crate::debug_trace::DebugTrace;
+use crate::diagnostic::{Diagnostic, WarningDiagnosticCode};
+use crate::engine::cycle_clock::CycleClock;
+use crate::engine::expr_runtime::{named_event_match_times, open_shared_waveform_traced};
+use crate::engine::extract::{initial_diagnostics, max_entries, truncate_rows};
+use crate::engine::signal_pattern::{ExpandedSignal, resolve_signal_tokens};
+use crate::engine::time::{DumpTimeContext, TimeFormat, duration_ticks};
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::value_format::format_verilog_literal;
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::waveform::SignalTransitions;
+
+const HELP: &str = "wavepeek timing";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimingCheckKind {
+    /// The data changed at or before the edge, within `--setup` of it.
+    Setup,
+    /// The data changed after the edge, within `--hold` of it.
+    Hold,
+}
+
+impl std::fmt::Display for TimingCheckKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Setup => f.write_str("setup"),
+            Self::Hold => f.write_str("hold"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimingViolation {
+    pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    /// `--clock` cycle of `time`, `None` before the first clock edge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
+    pub kind: TimingCheckKind,
+    #[serde(skip_serializing)]
+    pub display: String,
+    pub path: String,
+    /// Value one dump tick before the change.
+    pub previous: String,
+    pub value: String,
+    pub edge: String,
+    /// Measured slack against the limit; never positive because only violations are reported.
+    pub slack: String,
+}
+
+/// Setup/hold limits in dump ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimingLimits {
+    setup: u64,
+    hold: u64,
+}
+
+pub fn run(args: TimingArgs) -> Result<CommandResult, WavepeekError> {
+    let output_mode = crate::output_mode::OutputMode::from_json_flags(args.json, args.jsonl);
+    let max_entries = max_entries(&args.max)?;
+    let mut diagnostics = initial_diagnostics(&args.max);
+
+    let debug = DebugTrace::for_command(CommandName::Timing);
+    let waveform = open_shared_waveform_traced(args.waves.as_path(), &debug)?;
+    let RawTimeWindow {
+        dump_time,
+        dump_start_raw,
+        dump_end_raw,
+        from_raw,
+        to_raw,
+        clock,
+        ..
+    } = resolve_raw_window(
+        &waveform,
+        args.scope.as_deref(),
+        Some(args.clock.as_str()),
+        HELP,
+        args.from.as_deref(),
+        args.to.as_deref(),
+        &debug,
+        &mut diagnostics,
+    )?;
+    let limits = TimingLimits {
+        setup: parse_limit(args.setup.as_str(), "--setup", dump_time)?,
+        hold: parse_limit(args.hold.as_str(), "--hold", dump_time)?,
+    };
+    debug.event("time.parse.done", || serde_json::json!({}));
+
+    let signals = resolve_signal_tokens(
+        &waveform.borrow(),
+        args.scope.as_deref(),
+        &args.signals,
+        HELP,
+        &mut diagnostics,
+    )?;
+    let edges = named_event_match_times(
+        &waveform,
+        args.scope.as_deref(),
+        args.clock.as_str(),
+        from_raw.saturating_sub(limits.hold).max(dump_start_raw),
+        to_raw.saturating_add(limits.setup).min(dump_end_raw),
+    )?
+    .ok_or_else(|| {
+        WavepeekError::Args(format!(
+            "--clock '{}' must name its clock signal instead of using '*'. See '{HELP} --help'.",
+            args.clock
+        ))
+    })?;
+    debug.event(
+        "timing.clock.done",
+        || serde_json::json!({"edges": edges.len()}),
+    );
+
+    let paths = signals
+        .iter()
+        .map(|signal| signal.path.clone())
+        .collect::<Vec<_>>();
+    let mut waveform = waveform.borrow_mut();
+    let resolved = waveform.resolve_signals(&paths)?;
+    // History starts one tick early so a change at `--from` still sees the value before it.
+    let histories = waveform.signal_transitions(&resolved, from_raw.saturating_sub(1), to_raw)?;
+
+    let scan = TimingScan {
+        edges: &edges,
+        limits,
+        time_format: TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks),
+        clock: clock.as_ref(),
+    };
+    let mut found = Vec::new();
+    for ((signal, resolved), history) in signals.iter().zip(&resolved).zip(&histories) {
+        scan.signal_violations(signal, resolved.width, history, &mut found)?;
+    }
+    found.sort_by(
+        |(left_time, left_edge, left), (right_time, right_edge, right)| {
+            left_time
+                .cmp(right_time)
+                .then_with(|| left.path.cmp(&right.path))
+                .then_with(|| left_edge.cmp(right_edge))
+        },
+    );
+    let mut rows = found.into_iter().map(|(_, _, row)| row).collect::<Vec<_>>();
+    debug.event(
+        "timing.check.done",
+        || serde_json::json!({"rows": rows.len()}),
+    );
+
+    if rows.is_empty() {
+        diagnostics.push(Diagnostic::warning(
+            WarningDiagnosticCode::EmptyResult,
+            "no setup or hold violations found in selected time range",
+        ));
+    }
+    truncate_rows(&mut rows, max_entries, &mut diagnostics);
+
+    Ok(CommandResult {
+        command: CommandName::Timing,
+        output_mode,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: args.abs,
+        },
+        data: CommandData::Timing(rows),
+        diagnostics,
+    })
+}
+
+/// Timing check settings shared by every signal.
+struct TimingScan<'a> {
+    edges: &'a [u64],
+    limits: TimingLimits,
+    time_format: TimeFormat,
+    clock: Option<&'a CycleClock>,
+}
+
+impl TimingScan<'_> {
+    /// Checks every change of one signal inside the selected range against the edges around it.
+    fn signal_violations(
+        &self,
+        signal: &ExpandedSignal,
+        width: u32,
+        history: &SignalTransitions,
+        found: &mut Vec<(u64, u64, TimingViolation)>,
+    ) -> Result<(), WavepeekError> {
+        let limits = self.limits;
+        let mut previous = history.initial.as_deref();
+        // Every write within a timestamp counts, so zero-delay glitches are checked too.
+        let writes = history.changes.iter().flat_map(|change| {
+            change
+                .values
+                .iter()
+                .map(move |value| (change.time, value.as_str()))
+        });
+        for (time, value) in writes {
+            // The first recorded value is an initialization, not a data transition.
+            let Some(before) = previous.replace(value) else {
+                continue;
+            };
+            if before == value {
+                continue;
+            }
+
+            let first_edge = self
+                .edges
+                .partition_point(|edge| *edge < time.saturating_sub(limits.hold));
+            let last_edge = self
+                .edges
+                .partition_point(|edge| *edge <= time.saturating_add(limits.setup));
+            for &edge in &self.edges[first_edge..last_edge] {
+                let (kind, distance, limit) = if time <= edge {
+                    (TimingCheckKind::Setup, edge - time, limits.setup)
+                } else {
+                    (TimingCheckKind::Hold, time - edge, limits.hold)
+                };
+                if distance > limit {
+                    continue;
+                }
+                let shortfall = limit - distance;
+                let sign = if shortfall > 0 { "-" } else { "" };
+                found.push((
+                    time,
+                    edge,
+                    TimingViolation {
+                        time: self.time_format.format(time)?,
+                        tick: self.time_format.tick(time),
+                        cycle: self.clock.and_then(|clock| clock.cycle_at(time)),
+                        kind,
+                        display: signal.display.clone(),
+                        path: signal.path.clone(),
+                        previous: format_verilog_literal(width, before),
+                        value: format_verilog_literal(width, value),
+                        edge: self.time_format.format(edge)?,
+                        slack: format!("{sign}{}", self.time_format.format(shortfall)?),
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_limit(token: &str, flag: &str, dump_time: DumpTimeContext) -> Result<u64, WavepeekError> {
+    duration_ticks(token, dump_time).map_err(|reason| {
+        WavepeekError::Args(format!(
+            "invalid {flag} '{token}': {reason}. See '{HELP} --help'."
+        ))
+    })
+}
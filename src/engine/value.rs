@@ -2,14 +2,21 @@ use serde::Serialize;
 
 use crate::cli::value::ValueArgs;
 use crate::debug_trace::DebugTrace;
+use crate::diagnostic::Diagnostic;
+use crate::engine::cycle_clock::CycleClock;
+use crate::engine::expr_runtime::with_shared_waveform;
+use crate::engine::signal_layout::{SignalLayout, SignalLayouts};
+use crate::engine::signal_pattern::SignalPatternExpander;
+use crate::engine::signal_select::{BitSelect, resolve_selected_signal};
 use crate::engine::time::{
-    DumpTimeContext, TimeValidationError, format_raw_timestamp, parse_dump_time_context,
+    DumpTimeContext, TimeFormat, TimeValidationError, parse_dump_time_context,
     validate_time_token_to_raw,
 };
+use crate::engine::time_expr::resolve_time_list;
 use crate::engine::value_format::format_verilog_literal;
 use crate::engine::{CommandData, CommandName, CommandResult};
 use crate::error::WavepeekError;
-use crate::waveform::{Waveform, WaveformMetadata};
+use crate::waveform::{SampledSignal, Waveform, WaveformMetadata};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ValueSignalValue {
@@ -17,11 +24,83 @@ pub struct ValueSignalValue {
     pub display: String,
     pub path: String,
     pub value: String,
+    /// Field values, nested by struct level, when the requested name is a struct or union or
+    /// when a `--layout` entry decodes the signal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<ValueFields>,
+    /// Whether `fields` were decoded from `value` by a layout rather than sampled per field.
+    #[serde(skip_serializing)]
+    pub decoded: bool,
+}
+
+/// Field values of one aggregate, keyed by field name in declaration order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueFields(Vec<(String, ValueField)>);
+
+impl ValueFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ValueField)> {
+        self.0.iter().map(|(name, field)| (name.as_str(), field))
+    }
+
+    /// Replaces the field called `name`, or appends it after the existing fields.
+    fn insert(&mut self, name: &str, field: ValueField) {
+        match self.0.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, existing)) => *existing = field,
+            None => self.0.push((name.to_string(), field)),
+        }
+    }
+
+    /// The nested fields called `name`, appended empty when missing.
+    fn nested(&mut self, name: &str) -> Option<&mut ValueFields> {
+        let index = match self.0.iter().position(|(existing, _)| existing == name) {
+            Some(index) => index,
+            None => {
+                self.0
+                    .push((name.to_string(), ValueField::Fields(ValueFields::new())));
+                self.0.len() - 1
+            }
+        };
+        match &mut self.0[index].1 {
+            ValueField::Fields(children) => Some(children),
+            ValueField::Value(_) => None,
+        }
+    }
+}
+
+impl FromIterator<(String, ValueField)> for ValueFields {
+    fn from_iter<I: IntoIterator<Item = (String, ValueField)>>(iter: I) -> Self {
+        let mut fields = Self::new();
+        for (name, field) in iter {
+            fields.insert(name.as_str(), field);
+        }
+        fields
+    }
+}
+
+impl Serialize for ValueFields {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum ValueField {
+    Value(String),
+    Fields(ValueFields),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ValueSnapshot {
     pub time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<u64>,
     pub signals: Vec<ValueSignalValue>,
 }
 
@@ -31,12 +110,115 @@ pub type ValueData = Vec<ValueSnapshot>;
 struct RequestedSignal {
     display: String,
     path: String,
+    select: Option<BitSelect>,
+    /// Field paths relative to `path` when it names a struct or union, in listing order.
+    fields: Option<Vec<String>>,
+    layout: Option<SignalLayout>,
+}
+
+impl RequestedSignal {
+    fn sample_paths(&self) -> Vec<String> {
+        match &self.fields {
+            Some(fields) => fields
+                .iter()
+                .map(|field| format!("{}.{field}", self.path))
+                .collect(),
+            None => vec![self.path.clone()],
+        }
+    }
+
+    /// Builds this signal's value from the next samples, consuming one per sampled path.
+    fn take_value(
+        &self,
+        sampled: &mut impl Iterator<Item = SampledSignal>,
+    ) -> Result<ValueSignalValue, WavepeekError> {
+        let mut next = || {
+            sampled.next().ok_or_else(|| {
+                WavepeekError::Internal("sampled signal count does not match request".to_string())
+            })
+        };
+
+        if let Some(fields) = &self.fields {
+            let mut tree = ValueFields::new();
+            for field in fields {
+                let sample = next()?;
+                insert_field(
+                    &mut tree,
+                    field.as_str(),
+                    format_verilog_literal(sample.width, sample.bits.as_str()),
+                );
+            }
+            return Ok(ValueSignalValue {
+                display: self.display.clone(),
+                path: self.path.clone(),
+                value: format_assignment_pattern(&tree),
+                fields: Some(tree),
+                decoded: false,
+            });
+        }
+
+        let sample = next()?;
+        Ok(match self.select {
+            Some(select) => ValueSignalValue {
+                display: self.display.clone(),
+                path: format!("{}{}", sample.path, select.suffix()),
+                value: format_verilog_literal(select.width(), select.slice(sample.bits.as_str())),
+                fields: None,
+                decoded: false,
+            },
+            None => ValueSignalValue {
+                display: self.display.clone(),
+                value: format_verilog_literal(sample.width, sample.bits.as_str()),
+                fields: self
+                    .layout
+                    .as_ref()
+                    .map(|layout| layout.decode(sample.bits.as_str())),
+                decoded: self.layout.is_some(),
+                path: sample.path,
+            },
+        })
+    }
+}
+
+fn insert_field(tree: &mut ValueFields, field: &str, value: String) {
+    match field.split_once('.') {
+        Some((head, rest)) => {
+            if let Some(children) = tree.nested(head) {
+                insert_field(children, rest, value);
+            }
+        }
+        None => tree.insert(field, ValueField::Value(value)),
+    }
+}
+
+/// Compact SystemVerilog assignment pattern such as `'{data:8'h5a,hdr:'{len:4'h3}}`.
+///
+/// Array elements, recorded as `[i]`, are keyed by their index `i`.
+pub(crate) fn format_assignment_pattern(tree: &ValueFields) -> String {
+    let fields = tree
+        .iter()
+        .map(|(name, field)| {
+            (
+                name.strip_prefix('[')
+                    .and_then(|name| name.strip_suffix(']'))
+                    .unwrap_or(name),
+                field,
+            )
+        })
+        .map(|(name, field)| match field {
+            ValueField::Value(value) => format!("{name}:{value}"),
+            ValueField::Fields(children) => {
+                format!("{name}:{}", format_assignment_pattern(children))
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("'{{{}}}", fields.join(","))
 }
 
 pub fn run(args: ValueArgs) -> Result<CommandResult, WavepeekError> {
     let debug = DebugTrace::for_command(CommandName::Value);
     debug.event("backend.open.start", || serde_json::json!({}));
-    let mut waveform = Waveform::open(args.waves.as_path())?;
+    let waveform = Waveform::open(args.waves.as_path())?;
     debug.event("backend.open.done", || {
         serde_json::json!({
             "backend": waveform.backend_name(),
@@ -46,39 +228,73 @@ pub fn run(args: ValueArgs) -> Result<CommandResult, WavepeekError> {
     let metadata = waveform.metadata()?;
     debug.event("metadata.load.done", || serde_json::json!({}));
 
-    let requested_signals = resolve_requested_signals(&waveform, args.scope.as_deref(), &args)?;
+    let layouts = args
+        .layout
+        .as_deref()
+        .map(|path| SignalLayouts::load(path, "wavepeek value"))
+        .transpose()?;
+    let mut diagnostics = Vec::new();
+    let requested_signals = resolve_requested_signals(
+        &waveform,
+        args.scope.as_deref(),
+        &args,
+        layouts.as_ref(),
+        &mut diagnostics,
+    )?;
     debug.event(
         "signal.select.done",
         || serde_json::json!({"signals": requested_signals.len()}),
     );
 
     let dump_time = parse_dump_time_context(&metadata)?;
-    let query_times_raw = parse_at_tokens(args.at.as_str(), &metadata, dump_time)?;
+    let (mut waveform, (clock, at_tokens)) = with_shared_waveform(waveform, |waveform| {
+        let clock = CycleClock::load(
+            waveform,
+            args.scope.as_deref(),
+            args.clock.as_deref(),
+            dump_time,
+            "wavepeek value",
+        )?;
+        let at_tokens = resolve_time_list(
+            waveform,
+            args.scope.as_deref(),
+            clock.as_ref(),
+            dump_time,
+            "wavepeek value",
+            args.at.as_str(),
+            &mut diagnostics,
+        )?;
+        Ok((clock, at_tokens))
+    })?;
+    let query_times_raw =
+        parse_at_tokens(at_tokens.iter().map(String::as_str), &metadata, dump_time)?;
     debug.event(
         "time.parse.done",
         || serde_json::json!({"times": query_times_raw.len()}),
     );
 
+    let time_format = TimeFormat::new(dump_time.dump_tick, args.time_unit, args.ticks);
     let canonical_paths = requested_signals
         .iter()
-        .map(|signal| signal.path.clone())
+        .flat_map(RequestedSignal::sample_paths)
         .collect::<Vec<_>>();
     let mut snapshots = Vec::with_capacity(query_times_raw.len());
 
     for query_time_raw in query_times_raw {
-        let sampled = waveform.sample_signals_at_time(&canonical_paths, query_time_raw)?;
+        let mut sampled = waveform
+            .sample_signals_at_time(&canonical_paths, query_time_raw)?
+            .into_iter();
         let signals = requested_signals
             .iter()
-            .zip(sampled)
-            .map(|(requested, sampled)| ValueSignalValue {
-                display: requested.display.clone(),
-                path: sampled.path,
-                value: format_verilog_literal(sampled.width, sampled.bits.as_str()),
-            })
-            .collect::<Vec<_>>();
+            .map(|requested| requested.take_value(&mut sampled))
+            .collect::<Result<Vec<_>, _>>()?;
 
         snapshots.push(ValueSnapshot {
-            time: format_raw_timestamp(query_time_raw, dump_time.dump_tick)?,
+            time: time_format.format(query_time_raw)?,
+            tick: time_format.tick(query_time_raw),
+            cycle: clock
+                .as_ref()
+                .and_then(|clock| clock.cycle_at(query_time_raw)),
             signals,
         });
     }
@@ -97,17 +313,17 @@ pub fn run(args: ValueArgs) -> Result<CommandResult, WavepeekError> {
             signals_abs: args.abs,
         },
         data: CommandData::Value(snapshots),
-        diagnostics: Vec::new(),
+        diagnostics,
     })
 }
 
-fn parse_at_tokens(
-    at: &str,
+fn parse_at_tokens<'a>(
+    tokens: impl IntoIterator<Item = &'a str>,
     metadata: &WaveformMetadata,
     dump_time: DumpTimeContext,
 ) -> Result<Vec<u64>, WavepeekError> {
     let mut raw_times = Vec::new();
-    for token in at.split(',') {
+    for token in tokens {
         let token = token.trim();
         if token.is_empty() {
             return Err(WavepeekError::Args(
@@ -133,11 +349,19 @@ fn resolve_requested_signals(
     waveform: &Waveform,
     scope: Option<&str>,
     args: &ValueArgs,
+    layouts: Option<&SignalLayouts>,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<Vec<RequestedSignal>, WavepeekError> {
+    let layout_for = |path: &str| match layouts {
+        Some(layouts) => layouts.resolve(waveform, path),
+        None => Ok(None),
+    };
+
     if let Some(scope) = scope {
         waveform.signals_in_scope(scope)?;
     }
 
+    let mut patterns = SignalPatternExpander::new(waveform, scope, "--signals", "wavepeek value");
     let mut resolved = Vec::with_capacity(args.signals.len());
     for token in &args.signals {
         let display = token.trim();
@@ -146,20 +370,58 @@ fn resolve_requested_signals(
                 "signal names must not be empty. See 'wavepeek value --help'.".to_string(),
             ));
         }
+        if let Some(expanded) = patterns.expand(display, diagnostics)? {
+            for signal in expanded {
+                resolved.push(RequestedSignal {
+                    layout: layout_for(signal.path.as_str())?,
+                    display: signal.display,
+                    path: signal.path,
+                    select: None,
+                    fields: None,
+                });
+            }
+            continue;
+        }
 
-        let path = match scope {
-            Some(scope) => format!("{scope}.{display}"),
-            None => display.to_string(),
+        let selected = resolve_selected_signal(waveform, display, "wavepeek value", |name| {
+            Ok(match scope {
+                Some(scope) => format!("{scope}.{name}"),
+                None => name.to_string(),
+            })
+        })?;
+        let fields = if selected.select.is_none() && waveform.is_aggregate_scope(&selected.path) {
+            Some(aggregate_fields(waveform, selected.path.as_str())?)
+        } else {
+            None
+        };
+        let layout = if selected.select.is_none() && fields.is_none() {
+            layout_for(selected.path.as_str())?
+        } else {
+            None
         };
         resolved.push(RequestedSignal {
             display: display.to_string(),
-            path,
+            path: selected.path,
+            select: selected.select,
+            fields,
+            layout,
         });
     }
 
     Ok(resolved)
 }
 
+/// Field paths of an aggregate relative to `path`, including nested fields, in declaration order.
+fn aggregate_fields(waveform: &Waveform, path: &str) -> Result<Vec<String>, WavepeekError> {
+    let fields = waveform.aggregate_fields(path)?;
+    if fields.is_empty() {
+        return Err(WavepeekError::Signal(format!(
+            "aggregate '{path}' has no recorded fields"
+        )));
+    }
+    Ok(fields)
+}
+
 fn map_value_time_validation_error(
     token: &str,
     metadata: &WaveformMetadata,
@@ -199,6 +461,7 @@ mod tests {
         RequestedSignal, WaveformMetadata, map_value_time_validation_error, parse_at_tokens,
         resolve_requested_signals, run,
     };
+    use crate::cli::time_unit::OutputTimeUnit;
     use crate::cli::value::ValueArgs;
     use crate::engine::CommandData;
     use crate::engine::time::{TimeValidationError, parse_dump_time_context};
@@ -228,16 +491,25 @@ mod tests {
                     waves: PathBuf::from(fixture.path()),
                     at: "5ns".to_string(),
                     scope: Some("top".to_string()),
+                    time_unit: OutputTimeUnit::Dump,
+                    ticks: false,
+                    clock: None,
                     signals: vec!["sig".to_string()],
+                    layout: None,
                     abs: false,
                     json: false,
                     jsonl: false,
                 },
+                None,
+                &mut Vec::new(),
             )
             .expect("scoped signals should resolve"),
             vec![RequestedSignal {
                 display: "sig".to_string(),
                 path: "top.sig".to_string(),
+                select: None,
+                fields: None,
+                layout: None,
             }]
         );
         assert!(
@@ -248,11 +520,17 @@ mod tests {
                     waves: PathBuf::from(fixture.path()),
                     at: "5ns".to_string(),
                     scope: None,
+                    time_unit: OutputTimeUnit::Dump,
+                    ticks: false,
+                    clock: None,
                     signals: vec!["  ".to_string()],
+                    layout: None,
                     abs: false,
                     json: false,
                     jsonl: false,
                 },
+                None,
+                &mut Vec::new(),
             )
             .expect_err("empty signal names should fail")
             .to_string()
@@ -301,11 +579,12 @@ mod tests {
 
         let dump_time = parse_dump_time_context(&metadata).expect("dump time should parse");
         assert_eq!(
-            parse_at_tokens("5ns, 0ns ,5ns", &metadata, dump_time).expect("time list should parse"),
+            parse_at_tokens("5ns, 0ns ,5ns".split(','), &metadata, dump_time)
+                .expect("time list should parse"),
             vec![5, 0, 5]
         );
         assert!(
-            parse_at_tokens("5ns,,0ns", &metadata, dump_time)
+            parse_at_tokens("5ns,,0ns".split(','), &metadata, dump_time)
                 .expect_err("empty time list entries should fail")
                 .to_string()
                 .contains("time list in --at must not contain empty entries")
@@ -315,7 +594,11 @@ mod tests {
             waves: PathBuf::from(fixture.path()),
             at: "5ns".to_string(),
             scope: Some("top".to_string()),
+            time_unit: OutputTimeUnit::Dump,
+            ticks: false,
+            clock: None,
             signals: vec!["sig".to_string()],
+            layout: None,
             abs: true,
             json: true,
             jsonl: false,
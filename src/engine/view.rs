@@ -0,0 +1,256 @@
+use serde::Serialize;
+
+use crate::cli::view::ViewArgs;
+use crate::debug_trace::DebugTrace;
+use crate::engine::expr_runtime::{open_waveform_traced, with_shared_waveform};
+use crate::engine::signal_pattern::{ExpandedSignal, resolve_signal_tokens};
+use crate::engine::time::TimeFormat;
+use crate::engine::time_expr::{RawTimeWindow, resolve_raw_window};
+use crate::engine::value_format::format_verilog_literal;
+use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
+use crate::error::WavepeekError;
+use crate::output::{MIN_WAVE_WIDTH, terminal_columns, wave_plot_columns};
+use crate::waveform::Waveform;
+
+const HELP: &str = "wavepeek view";
+
+/// One drawn trace: a Verilog literal (or `None` before the first value) per column.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WaveTrace {
+    pub display: String,
+    pub path: String,
+    pub width: u32,
+    pub values: Vec<Option<String>>,
+}
+
+/// Text waveform payload shared by `view` and `change --wave`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WaveData {
+    pub times: Vec<String>,
+    pub traces: Vec<WaveTrace>,
+    pub width: usize,
+    pub ascii: bool,
+}
+
+pub fn run(args: ViewArgs) -> Result<CommandResult, WavepeekError> {
+    let width = args.width.unwrap_or_else(terminal_columns);
+    if width < MIN_WAVE_WIDTH {
+        return Err(WavepeekError::Args(format!(
+            "--width must be at least {MIN_WAVE_WIDTH} columns. See '{HELP} --help'."
+        )));
+    }
+
+    let debug = DebugTrace::for_command(CommandName::View);
+    let waveform = open_waveform_traced(args.waves.as_path(), &debug)?;
+
+    let mut diagnostics = Vec::new();
+    let requested_signals = resolve_signal_tokens(
+        &waveform,
+        args.scope.as_deref(),
+        &args.signals,
+        HELP,
+        &mut diagnostics,
+    )?;
+    let (
+        mut waveform,
+        RawTimeWindow {
+            dump_time,
+            from_raw,
+            to_raw,
+            ..
+        },
+    ) = with_shared_waveform(waveform, |waveform| {
+        resolve_raw_window(
+            waveform,
+            args.scope.as_deref(),
+            None,
+            HELP,
+            args.from.as_deref(),
+            args.to.as_deref(),
+            &debug,
+            &mut diagnostics,
+        )
+    })?;
+    debug.event("time.parse.done", || serde_json::json!({}));
+
+    let labels = requested_signals
+        .iter()
+        .map(|signal| {
+            if args.abs {
+                signal.path.as_str()
+            } else {
+                signal.display.as_str()
+            }
+        })
+        .collect::<Vec<_>>();
+    let column_times = column_times(from_raw, to_raw, wave_plot_columns(&labels, width));
+    let (times, traces) = sample_wave(
+        &mut waveform,
+        &requested_signals,
+        &column_times,
+        TimeFormat::new(dump_time.dump_tick, args.time_unit, false),
+    )?;
+    debug.event(
+        "view.sample.done",
+        || serde_json::json!({"columns": times.len(), "signals": traces.len()}),
+    );
+
+    Ok(CommandResult {
+        command: CommandName::View,
+        output_mode: crate::output_mode::OutputMode::Human,
+        human_options: HumanRenderOptions {
+            scope_tree: false,
+            signals_abs: args.abs,
+        },
+        data: CommandData::Wave(WaveData {
+            times,
+            traces,
+            width,
+            ascii: args.ascii,
+        }),
+        diagnostics,
+    })
+}
+
+/// Samples each signal once per column time, returning formatted times and traces.
+pub(crate) fn sample_wave(
+    waveform: &mut Waveform,
+    signals: &[ExpandedSignal],
+    column_times: &[u64],
+    time_format: TimeFormat,
+) -> Result<(Vec<String>, Vec<WaveTrace>), WavepeekError> {
+    let canonical_paths = signals
+        .iter()
+        .map(|signal| signal.path.clone())
+        .collect::<Vec<_>>();
+    let resolved = waveform.resolve_signals(&canonical_paths)?;
+
+    let mut traces = signals
+        .iter()
+        .zip(resolved.iter())
+        .map(|(requested, resolved)| WaveTrace {
+            display: requested.display.clone(),
+            path: resolved.path.clone(),
+            width: resolved.width,
+            values: Vec::with_capacity(column_times.len()),
+        })
+        .collect::<Vec<_>>();
+    let mut times = Vec::with_capacity(column_times.len());
+    for &raw_time in column_times {
+        let sampled = waveform.sample_resolved_optional(&resolved, raw_time)?;
+        for (trace, sample) in traces.iter_mut().zip(sampled) {
+            trace.values.push(
+                sample
+                    .bits
+                    .map(|bits| format_verilog_literal(sample.width, bits.as_str())),
+            );
+        }
+        times.push(time_format.format(raw_time)?);
+    }
+    Ok((times, traces))
+}
+
+/// Spreads up to `columns` sample points uniformly over the inclusive raw window.
+pub(crate) fn column_times(from_raw: u64, to_raw: u64, columns: usize) -> Vec<u64> {
+    let span = u128::from(to_raw - from_raw);
+    let count = usize::try_from(span + 1)
+        .map_or(columns, |ticks| ticks.min(columns))
+        .max(1);
+    if count == 1 {
+        return vec![from_raw];
+    }
+
+    let steps = (count - 1) as u128;
+    (0..count)
+        .map(|index| {
+            let offset = span * index as u128 / steps;
+            from_raw + u64::try_from(offset).expect("offset stays within the raw window")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use tempfile::NamedTempFile;
+
+    use super::{column_times, run};
+    use crate::cli::time_unit::OutputTimeUnit;
+    use crate::cli::view::ViewArgs;
+    use crate::engine::CommandData;
+
+    const TEST_VCD: &str = concat!(
+        "$timescale 1ns $end\n",
+        "$scope module top $end\n",
+        "$var wire 1 ! clk $end\n",
+        "$var wire 4 \" data $end\n",
+        "$upscope $end\n",
+        "$enddefinitions $end\n",
+        "#0\n0!\nb0000 \"\n#5\n1!\nb1010 \"\n#10\n0!\n"
+    );
+
+    fn args(path: PathBuf) -> ViewArgs {
+        ViewArgs {
+            waves: path,
+            from: None,
+            to: None,
+            scope: Some("top".to_string()),
+            signals: vec!["clk".to_string(), "data".to_string()],
+            width: Some(40),
+            ascii: false,
+            time_unit: OutputTimeUnit::Dump,
+            abs: false,
+        }
+    }
+
+    #[test]
+    fn column_times_cover_window_endpoints_without_exceeding_ticks() {
+        assert_eq!(column_times(0, 10, 3), vec![0, 5, 10]);
+        assert_eq!(column_times(0, 3, 10), vec![0, 1, 2, 3]);
+        assert_eq!(column_times(7, 7, 10), vec![7]);
+    }
+
+    #[test]
+    fn view_samples_each_trace_once_per_column() {
+        let fixture = write_fixture(TEST_VCD, ".view-run.vcd");
+        let result = run(args(PathBuf::from(fixture.path()))).expect("view should succeed");
+        let CommandData::Wave(data) = result.data else {
+            panic!("expected wave data");
+        };
+
+        assert_eq!(data.times.len(), 11);
+        assert_eq!(data.times.first().map(String::as_str), Some("0ns"));
+        assert_eq!(data.times.last().map(String::as_str), Some("10ns"));
+        assert_eq!(data.traces[0].path, "top.clk");
+        assert_eq!(data.traces[1].values[5].as_deref(), Some("4'ha"));
+        assert!(data.traces.iter().all(|trace| trace.values.len() == 11));
+    }
+
+    #[test]
+    fn view_rejects_narrow_width_and_inverted_window() {
+        let fixture = write_fixture(TEST_VCD, ".view-errors.vcd");
+
+        let mut narrow = args(PathBuf::from(fixture.path()));
+        narrow.width = Some(5);
+        let error = run(narrow).expect_err("narrow width should fail");
+        assert!(error.to_string().contains("--width must be at least"));
+
+        let mut inverted = args(PathBuf::from(fixture.path()));
+        inverted.from = Some("8ns".to_string());
+        inverted.to = Some("2ns".to_string());
+        let error = run(inverted).expect_err("inverted window should fail");
+        assert!(
+            error
+                .to_string()
+                .contains("--from must be less than or equal to --to")
+        );
+    }
+
+    fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+        let fixture = NamedTempFile::with_suffix(suffix).expect("fixture should create");
+        fs::write(fixture.path(), contents).expect("fixture should write");
+        fixture
+    }
+}
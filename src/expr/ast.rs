@@ -16,11 +16,40 @@ pub struct EventTermAst {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BasicEventAst {
-    AnyTracked { span: Span },
-    Named { name: String, span: Span },
-    Posedge { name: String, span: Span },
-    Negedge { name: String, span: Span },
-    Edge { name: String, span: Span },
+    AnyTracked {
+        span: Span,
+    },
+    Named {
+        name: String,
+        span: Span,
+    },
+    Posedge {
+        name: String,
+        span: Span,
+    },
+    Negedge {
+        name: String,
+        span: Span,
+    },
+    Edge {
+        name: String,
+        span: Span,
+    },
+    /// `every <period> [phase <offset>]`: a regular time grid, independent of signal changes.
+    Every {
+        period: String,
+        phase: Option<String>,
+        span: Span,
+    },
+    /// `at <time>, <time>, ...`: explicit trigger times.
+    At {
+        times: Vec<String>,
+        span: Span,
+    },
+    /// `timestamps`: every recorded dump timestamp.
+    Timestamps {
+        span: Span,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -166,6 +166,12 @@ pub fn event_matches_at(
                 let (posedge, negedge) = edge_event_matches(host, handle, frame, &mut cache)?;
                 posedge || negedge
             }
+            BoundEventKind::Every { period, phase } => frame
+                .timestamp
+                .checked_sub(phase)
+                .is_some_and(|offset| offset % period == 0),
+            BoundEventKind::At(ref times) => times.binary_search(&frame.timestamp).is_ok(),
+            BoundEventKind::Timestamps => host.is_dump_timestamp(frame.timestamp),
         };
 
         if !event_matches {
@@ -86,4 +86,28 @@ pub trait ExpressionHost {
     ///
     /// Non-event operands are not queried through this method.
     fn event_occurred(&self, handle: SignalHandle, timestamp: u64) -> Result<bool, ExprDiagnostic>;
+
+    /// Returns whether `name` is a struct or union whose fields resolve as `name.field`.
+    fn is_aggregate(&self, _name: &str) -> bool {
+        false
+    }
+
+    /// Returns whether `name` is a `signal.field` reference into a packed signal layout.
+    fn is_packed_field(&self, _name: &str) -> bool {
+        false
+    }
+
+    /// Converts a time literal such as `10ns` into raw dump ticks.
+    ///
+    /// Used by `every` and `at` event terms; the error is a short reason for the diagnostic.
+    fn time_ticks(&self, _token: &str) -> Result<u64, String> {
+        Err("time literals are not available without a waveform".to_string())
+    }
+
+    /// Returns whether `timestamp` is one of the dump's recorded timestamps.
+    ///
+    /// Used by the `timestamps` event term.
+    fn is_dump_timestamp(&self, _timestamp: u64) -> bool {
+        false
+    }
 }
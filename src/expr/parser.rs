@@ -190,17 +190,46 @@ impl<'a> StrictParser<'a> {
                 self.index += 1;
                 Ok((BasicEventAst::AnyTracked { span: token_span }, token_span))
             }
+            TokenKind::Identifier if self.tokens[self.index].lexeme == "timestamps" => {
+                self.index += 1;
+                Ok((BasicEventAst::Timestamps { span: token_span }, token_span))
+            }
+            TokenKind::Identifier
+                if self.tokens[self.index].lexeme == "every"
+                    && self.is_time_literal_at(self.index + 1) =>
+            {
+                self.parse_every_event()
+            }
+            TokenKind::Identifier
+                if self.tokens[self.index].lexeme == "at"
+                    && self.is_time_literal_at(self.index + 1) =>
+            {
+                self.parse_at_event()
+            }
             TokenKind::Identifier => {
-                let name = std::mem::take(&mut self.tokens[self.index].lexeme);
+                let mut name = std::mem::take(&mut self.tokens[self.index].lexeme);
+                // `\name` always names a signal, so `\timestamps` is not the time term.
+                let escape_len = if name.starts_with('\\') {
+                    name.remove(0);
+                    if name.is_empty() {
+                        return Err(parse_diag(
+                            "EXPR-PARSE-EVENT-MISSING-NAME",
+                            "missing signal name after '\\'",
+                            token_span,
+                            &["write '\\name' to use a signal named like a time term"],
+                        ));
+                    }
+                    1
+                } else {
+                    0
+                };
                 if let Some((offset, ch)) = invalid_name_char(name.as_str()) {
                     let message = format!("unexpected character '{ch}' in signal name");
+                    let start = token_span.start + escape_len + offset;
                     return Err(parse_diag(
                         "EXPR-PARSE-EVENT-LEX-CHAR",
                         message.as_str(),
-                        Span::new(
-                            token_span.start + offset,
-                            token_span.start + offset + ch.len_utf8(),
-                        ),
+                        Span::new(start, start + ch.len_utf8()),
                         &["signal names must use [A-Za-z0-9_.$[]:]"],
                     ));
                 }
@@ -320,6 +349,76 @@ impl<'a> StrictParser<'a> {
         Ok((ast, span))
     }
 
+    fn parse_every_event(&mut self) -> Result<(BasicEventAst, Span), ExprDiagnostic> {
+        let keyword_span = self.current().expect("every token should exist").span;
+        self.index += 1;
+        let period_span = self.tokens[self.index].span;
+        let period = std::mem::take(&mut self.tokens[self.index].lexeme);
+        self.index += 1;
+        let mut end = period_span.end;
+
+        let phase = match self.current() {
+            Some(token) if token.kind == TokenKind::Identifier && token.lexeme == "phase" => {
+                let phase_keyword_span = token.span;
+                self.index += 1;
+                if !self.is_time_literal_at(self.index) {
+                    return Err(parse_diag(
+                        "EXPR-PARSE-EVENT-MISSING-TIME",
+                        "missing time after 'phase'",
+                        phase_keyword_span,
+                        &["expected a time such as '2ns' after 'phase'"],
+                    ));
+                }
+                end = self.tokens[self.index].span.end;
+                let phase = std::mem::take(&mut self.tokens[self.index].lexeme);
+                self.index += 1;
+                Some(phase)
+            }
+            _ => None,
+        };
+
+        let span = Span::new(keyword_span.start, end);
+        Ok((
+            BasicEventAst::Every {
+                period,
+                phase,
+                span,
+            },
+            span,
+        ))
+    }
+
+    fn parse_at_event(&mut self) -> Result<(BasicEventAst, Span), ExprDiagnostic> {
+        let keyword_span = self.current().expect("at token should exist").span;
+        self.index += 1;
+        let mut times = Vec::new();
+        let end = loop {
+            let time_end = self.tokens[self.index].span.end;
+            times.push(std::mem::take(&mut self.tokens[self.index].lexeme));
+            self.index += 1;
+            // A comma continues the time list only when another time follows; otherwise it
+            // separates event terms.
+            let continues = matches!(
+                self.current().map(|token| &token.kind),
+                Some(TokenKind::Comma)
+            ) && self.is_time_literal_at(self.index + 1);
+            if !continues {
+                break time_end;
+            }
+            self.index += 1;
+        };
+
+        let span = Span::new(keyword_span.start, end);
+        Ok((BasicEventAst::At { times, span }, span))
+    }
+
+    fn is_time_literal_at(&self, index: usize) -> bool {
+        self.tokens.get(index).is_some_and(|token| {
+            token.kind == TokenKind::Identifier
+                && token.lexeme.starts_with(|ch: char| ch.is_ascii_digit())
+        })
+    }
+
     fn capture_iff_payload(&mut self, iff_span: Span) -> Result<(String, Span), ExprDiagnostic> {
         if self.index >= self.tokens.len() {
             return Err(parse_diag(
@@ -706,12 +805,24 @@ impl<'a> LogicalParser<'a> {
                             };
                         }
                         _ => {
-                            return Err(logical_parse_diag(
-                                "EXPR-PARSE-LOGICAL-EXPECTED",
-                                "unsupported member-like suffix",
-                                dot_span,
-                                &["only .triggered() is supported as a member-like suffix"],
-                            ));
+                            // `arr[N].field` names a field of one element of an aggregate array.
+                            let (LogicalTokenKind::Identifier(field), Some(element)) =
+                                (&token.kind, constant_element_name(&node))
+                            else {
+                                return Err(logical_parse_diag(
+                                    "EXPR-PARSE-LOGICAL-EXPECTED",
+                                    "unsupported member-like suffix",
+                                    dot_span,
+                                    &[
+                                        "only .triggered() and fields of constant array elements (name[N].field) are supported as member-like suffixes",
+                                    ],
+                                ));
+                            };
+                            self.index += 1;
+                            node = LogicalExprNode::OperandRef {
+                                name: format!("{element}.{field}"),
+                                span: Span::new(node.span().start, token.span.end),
+                            };
                         }
                     }
                 }
@@ -1458,6 +1569,27 @@ fn is_name_char(ch: char) -> bool {
     ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '$' | '[' | ']' | ':')
 }
 
+/// Hierarchical name of `name[N]` when `node` selects one element with an unsized decimal index.
+fn constant_element_name(node: &LogicalExprNode) -> Option<String> {
+    let LogicalExprNode::Selection {
+        base,
+        selection: SelectionKindAst::Bit { index },
+        ..
+    } = node
+    else {
+        return None;
+    };
+    match (base.as_ref(), index.as_ref()) {
+        (
+            LogicalExprNode::OperandRef { name, .. },
+            LogicalExprNode::IntegralLiteral { literal, .. },
+        ) if literal.width.is_none() && literal.base == IntegralBase::Decimal => {
+            Some(format!("{name}[{}]", literal.digits))
+        }
+        _ => None,
+    }
+}
+
 fn logical_parse_diag(
     code: &'static str,
     message: &str,
@@ -3574,6 +3706,29 @@ mod tests {
         assert_eq!(error.code, "EXPR-PARSE-LOGICAL-TRAILING");
     }
 
+    #[test]
+    fn parser_folds_constant_element_fields_into_operand_names() {
+        let ast = parse_logical_expr_ast("pkts[1].hdr.len == 3")
+            .expect("element field access should parse");
+        match ast.root {
+            LogicalExprNode::Binary { left, .. } => match *left {
+                LogicalExprNode::OperandRef { name, span } => {
+                    assert_eq!(name, "pkts[1].hdr.len");
+                    assert_eq!(span, Span::new(0, 15));
+                }
+                other => panic!("expected folded operand, got {other:?}"),
+            },
+            other => panic!("expected binary root, got {other:?}"),
+        }
+
+        let error = parse_logical_expr_ast("pkts[i].len")
+            .expect_err("non-constant element indices cannot name a field");
+        assert_eq!(error.code, "EXPR-PARSE-LOGICAL-EXPECTED");
+        let error = parse_logical_expr_ast("pkts[4'd1].len")
+            .expect_err("sized element indices cannot name a field");
+        assert_eq!(error.code, "EXPR-PARSE-LOGICAL-EXPECTED");
+    }
+
     #[test]
     fn parser_rejects_more_inside_selection_and_cast_forms() {
         let error =
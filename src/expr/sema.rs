@@ -26,6 +26,14 @@ pub(crate) enum BoundEventKind {
     Posedge(SignalHandle),
     Negedge(SignalHandle),
     Edge(SignalHandle),
+    /// Raw ticks `phase + k * period` for every `k >= 0`.
+    Every {
+        period: u64,
+        phase: u64,
+    },
+    /// Sorted, deduplicated raw ticks.
+    At(Vec<u64>),
+    Timestamps,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -193,6 +201,37 @@ pub fn bind_event_expr_ast(
                 ensure_integral(&ty, *span, "edge operand")?;
                 BoundEventKind::Edge(handle)
             }
+            BasicEventAst::Every {
+                period,
+                phase,
+                span,
+            } => {
+                let period = resolve_event_time(host, period, *span)?;
+                if period == 0 {
+                    return Err(ExprDiagnostic {
+                        layer: DiagnosticLayer::Semantic,
+                        code: "EXPR-SEMANTIC-EVENT-TIME",
+                        message: "'every' period must be greater than zero".to_string(),
+                        primary_span: *span,
+                        notes: vec![],
+                    });
+                }
+                let phase = match phase {
+                    Some(phase) => resolve_event_time(host, phase, *span)?,
+                    None => 0,
+                };
+                BoundEventKind::Every { period, phase }
+            }
+            BasicEventAst::At { times, span } => {
+                let mut ticks = times
+                    .iter()
+                    .map(|time| resolve_event_time(host, time, *span))
+                    .collect::<Result<Vec<_>, _>>()?;
+                ticks.sort_unstable();
+                ticks.dedup();
+                BoundEventKind::At(ticks)
+            }
+            BasicEventAst::Timestamps { .. } => BoundEventKind::Timestamps,
         };
 
         let iff = if let Some(iff) = &term.iff {
@@ -227,6 +266,20 @@ fn resolve_event_signal(
     })
 }
 
+fn resolve_event_time(
+    host: &dyn ExpressionHost,
+    token: &str,
+    span: Span,
+) -> Result<u64, ExprDiagnostic> {
+    host.time_ticks(token).map_err(|reason| ExprDiagnostic {
+        layer: DiagnosticLayer::Semantic,
+        code: "EXPR-SEMANTIC-EVENT-TIME",
+        message: format!("invalid event time '{token}'"),
+        primary_span: span,
+        notes: vec![reason],
+    })
+}
+
 pub fn bind_logical_expr_ast(
     ast: &LogicalExprAst,
     host: &dyn ExpressionHost,
@@ -1857,18 +1910,17 @@ fn eval_const_binary(
         | BinaryOpAst::BitAnd
         | BinaryOpAst::BitXor
         | BinaryOpAst::BitXnor
-        | BinaryOpAst::BitOr => {
+        | BinaryOpAst::BitOr
             if left
                 .bits
                 .iter()
                 .chain(right.bits.iter())
-                .any(|bit| matches!(bit, BoundBit::X | BoundBit::Z))
-            {
-                return BoundIntegralValue {
-                    bits: vec![BoundBit::X; ty.width as usize],
-                    signed: ty.is_signed,
-                };
-            }
+                .any(|bit| matches!(bit, BoundBit::X | BoundBit::Z)) =>
+        {
+            return BoundIntegralValue {
+                bits: vec![BoundBit::X; ty.width as usize],
+                signed: ty.is_signed,
+            };
         }
         _ => {}
     }
@@ -4,10 +4,15 @@ use serde::Serialize;
 
 use crate::contract::{output::OutputEnvelope, stream};
 use crate::diagnostic::{Diagnostic, DiagnosticKind};
+use crate::engine::value::{ValueFields, format_assignment_pattern};
 use crate::engine::{CommandData, CommandName, CommandResult, HumanRenderOptions};
 use crate::error::WavepeekError;
 use crate::output_mode::OutputMode;
 
+pub(crate) const MIN_WAVE_WIDTH: usize = 20;
+const DEFAULT_WAVE_WIDTH: usize = 80;
+const MAX_WAVE_CELL_WIDTH: usize = 12;
+
 pub struct JsonlWriter<W: Write> {
     writer: W,
     command: CommandName,
@@ -107,6 +112,24 @@ pub fn write(result: CommandResult) -> Result<(), WavepeekError> {
     }
 }
 
+/// Writes the `--html` report file and prints where it went.
+pub fn write_html(
+    result: CommandResult,
+    report: &crate::engine::report::HtmlReport,
+) -> Result<(), WavepeekError> {
+    let html = render_html(&result, report)?;
+    std::fs::write(&report.path, html).map_err(|error| {
+        WavepeekError::File(format!("cannot write '{}': {error}", report.path.display()))
+    })?;
+    write_stdout(&format!(
+        "wrote HTML report with {} row(s) to {}",
+        report.strips.len(),
+        report.path.display()
+    ));
+    emit_human_diagnostics(&result.diagnostics);
+    Ok(())
+}
+
 pub fn write_jsonl_result<W: Write>(
     result: CommandResult,
     writer: &mut JsonlWriter<W>,
@@ -148,6 +171,27 @@ pub fn write_jsonl_result<W: Write>(
                 writer.item(row)?;
             }
         }
+        CommandData::Cdc(rows) => {
+            for row in rows {
+                writer.item(row)?;
+            }
+        }
+        CommandData::Timing(rows) => {
+            for row in rows {
+                writer.item(row)?;
+            }
+        }
+        CommandData::Glitch(rows) => {
+            for row in rows {
+                writer.item(row)?;
+            }
+        }
+        CommandData::Audit(rows) => {
+            for row in rows {
+                writer.item(row)?;
+            }
+        }
+        CommandData::Clock(data) => writer.item(data)?,
         CommandData::ExtractAhb(data) => {
             writer.begin_context(&data.context())?;
             for event in &data.events {
@@ -185,6 +229,7 @@ pub fn write_jsonl_result<W: Write>(
         }
         CommandData::Schema(_)
         | CommandData::Text(_)
+        | CommandData::Wave(_)
         | CommandData::DocsTopics(_)
         | CommandData::DocsSearch(_) => {
             return Err(WavepeekError::Args(
@@ -235,6 +280,28 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
             lines.push(format!("time_unit: {}", info.time_unit));
             lines.push(format!("time_start: {}", info.time_start));
             lines.push(format!("time_end: {}", info.time_end));
+            lines.push(format!("format: {}", info.format));
+            lines.push(format!("backend: {}", info.backend));
+            lines.push(format!("scope_count: {}", info.scope_count));
+            lines.push(format!("signal_count: {}", info.signal_count));
+            if let Some(timestamp_count) = info.timestamp_count {
+                lines.push(format!("timestamp_count: {timestamp_count}"));
+            }
+            if let Some(date) = info.date.as_deref() {
+                lines.push(format!("date: {date}"));
+            }
+            if let Some(simulator) = info.simulator.as_deref() {
+                lines.push(format!("simulator: {simulator}"));
+            }
+            if let Some(timescale) = info.timescale.as_deref() {
+                lines.push(format!("timescale: {timescale}"));
+            }
+            if let Some(activity) = info.activity.as_ref() {
+                lines.push("activity:".to_string());
+                for entry in activity {
+                    lines.push(format!("  {} changes={}", entry.path, entry.changes));
+                }
+            }
             lines.join("\n")
         }
         CommandData::Scope(scopes) => {
@@ -243,26 +310,31 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
             } else {
                 scopes
                     .iter()
-                    .map(|entry| format!("{} {} kind={}", entry.depth, entry.path, entry.kind))
+                    .map(|entry| {
+                        format!("{} {} {}", entry.depth, entry.path, scope_attributes(entry))
+                    })
                     .collect::<Vec<_>>()
                     .join("\n")
             }
         }
         CommandData::Signal(signals) => signals
             .iter()
-            .map(|entry| match entry.width {
-                Some(width) => {
-                    format!(
-                        "{} kind={} width={width}",
-                        signal_display_name(entry, options.signals_abs),
-                        entry.kind
-                    )
-                }
-                None => format!(
+            .map(|entry| {
+                let mut line = format!(
                     "{} kind={}",
                     signal_display_name(entry, options.signals_abs),
                     entry.kind
-                ),
+                );
+                if let Some(width) = entry.width {
+                    line.push_str(format!(" width={width}").as_str());
+                }
+                if let Some(direction) = entry.direction.as_deref() {
+                    line.push_str(format!(" direction={direction}").as_str());
+                }
+                if let Some(compare) = entry.compare.as_ref() {
+                    line.push_str(compare_attributes(compare).as_str());
+                }
+                line
             })
             .collect::<Vec<_>>()
             .join("\n"),
@@ -271,13 +343,20 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
             .map(|snapshot| {
                 let mut parts = Vec::with_capacity(snapshot.signals.len() + 1);
                 parts.push(format!("@{}", snapshot.time));
+                if let Some(cycle) = snapshot.cycle {
+                    parts.push(format!("cycle={cycle}"));
+                }
                 for signal in &snapshot.signals {
                     let display = if options.signals_abs {
                         signal.path.as_str()
                     } else {
                         signal.display.as_str()
                     };
-                    parts.push(format!("{display}={}", signal.value));
+                    parts.push(render_assignment(
+                        display,
+                        signal.value.as_str(),
+                        signal.fields.as_ref().filter(|_| signal.decoded),
+                    ));
                 }
                 parts.join(" ")
             })
@@ -288,6 +367,9 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
             .map(|snapshot| {
                 let mut parts = Vec::with_capacity(snapshot.signals.len() + 2);
                 parts.push(format!("@{}", snapshot.time));
+                if let Some(cycle) = snapshot.cycle {
+                    parts.push(format!("cycle={cycle}"));
+                }
                 if snapshot.sample_time != snapshot.time {
                     parts.push(format!("sample@{}", snapshot.sample_time));
                 }
@@ -297,7 +379,11 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
                     } else {
                         signal.display.as_str()
                     };
-                    parts.push(format!("{display}={}", signal.value));
+                    parts.push(render_assignment(
+                        display,
+                        signal.value.as_str(),
+                        signal.fields.as_ref(),
+                    ));
                 }
                 parts.join(" ")
             })
@@ -306,14 +392,171 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
         CommandData::Property(rows) => rows
             .iter()
             .map(|row| {
-                if row.sample_time == row.time {
-                    format!("@{} {}", row.time, row.kind)
+                let mut parts = vec![format!("@{}", row.time)];
+                if let Some(cycle) = row.cycle {
+                    parts.push(format!("cycle={cycle}"));
+                }
+                if row.sample_time != row.time {
+                    parts.push(format!("sample@{}", row.sample_time));
+                }
+                parts.push(row.kind.to_string());
+                parts.join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CommandData::Clock(clock) => {
+            let display = if options.signals_abs {
+                clock.path.as_str()
+            } else {
+                clock.display.as_str()
+            };
+            let mut lines = vec![
+                format!("signal: {display}"),
+                format!("rising_edges: {}", clock.rising_edges),
+                format!("falling_edges: {}", clock.falling_edges),
+            ];
+            if let Some(period) = clock.period.as_ref() {
+                lines.push(format!(
+                    "period: nominal={} min={} max={} mean={}",
+                    period.nominal, period.min, period.max, period.mean
+                ));
+            }
+            if let Some(frequency) = clock.frequency.as_deref() {
+                lines.push(format!("frequency: {frequency}"));
+            }
+            if let Some(duty) = clock.duty_cycle.as_ref() {
+                lines.push(format!(
+                    "duty_cycle: min={}% max={}% mean={}%",
+                    duty.min, duty.max, duty.mean
+                ));
+            }
+            for gate in &clock.gated {
+                lines.push(format!(
+                    "gated: @{}..{}{} duration={} level={}",
+                    gate.start,
+                    gate.end
+                        .as_deref()
+                        .map_or_else(String::new, |end| format!("@{end}")),
+                    cycle_suffix(gate.cycle),
+                    gate.duration,
+                    gate.level
+                ));
+            }
+            for change in &clock.frequency_changes {
+                lines.push(format!(
+                    "frequency_change: @{}{} {}->{} {}->{}",
+                    change.time,
+                    cycle_suffix(change.cycle),
+                    change.previous_period,
+                    change.period,
+                    change.previous_frequency,
+                    change.frequency
+                ));
+            }
+            lines.join("\n")
+        }
+        CommandData::Audit(rows) => {
+            let mut lines = Vec::new();
+            let mut current_scope = None;
+            for row in rows {
+                if current_scope != Some(row.scope.as_str()) {
+                    lines.push(format!("{}:", row.scope));
+                    current_scope = Some(row.scope.as_str());
+                }
+                let display = if options.signals_abs {
+                    row.path.as_str()
+                } else {
+                    row.name.as_str()
+                };
+                lines.push(format!(
+                    "  {display} {} unknown_bits={} first_known={}",
+                    row.value.as_deref().unwrap_or("(no value)"),
+                    row.unknown_bits,
+                    row.first_known
+                        .as_deref()
+                        .map_or_else(|| "never".to_string(), |time| format!("@{time}"))
+                ));
+            }
+            lines.join("\n")
+        }
+        CommandData::Glitch(rows) => rows
+            .iter()
+            .map(|row| {
+                let display = if options.signals_abs {
+                    row.path.as_str()
+                } else {
+                    row.display.as_str()
+                };
+                let values = match row.previous.as_deref() {
+                    Some(previous) => format!("{previous}->{}->{}", row.value, row.next),
+                    None => format!("{}->{}", row.value, row.next),
+                };
+                format!(
+                    "@{}{} {} {display} {values} width={}",
+                    row.time,
+                    cycle_suffix(row.cycle),
+                    row.kind,
+                    row.width
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CommandData::Timing(rows) => rows
+            .iter()
+            .map(|row| {
+                let display = if options.signals_abs {
+                    row.path.as_str()
+                } else {
+                    row.display.as_str()
+                };
+                format!(
+                    "@{}{} {} {display} {}->{} edge@{} slack={}",
+                    row.time,
+                    cycle_suffix(row.cycle),
+                    row.kind,
+                    row.previous,
+                    row.value,
+                    row.edge,
+                    row.slack
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CommandData::Cdc(rows) => rows
+            .iter()
+            .map(|row| {
+                let display = if options.signals_abs {
+                    row.path.as_str()
                 } else {
-                    format!("@{} sample@{} {}", row.time, row.sample_time, row.kind)
+                    row.display.as_str()
+                };
+                let change = match row.previous.as_deref() {
+                    Some(previous) => format!("{previous}->{}", row.value),
+                    None => row.value.clone(),
+                };
+                let mut parts = vec![
+                    format!("@{}{}", row.time, cycle_suffix(row.cycle)),
+                    row.kind.to_string(),
+                    display.to_string(),
+                    change,
+                ];
+                if row.dst_edge != row.time {
+                    parts.push(format!("dst@{}", row.dst_edge));
+                }
+                if let Some(offset) = &row.offset {
+                    parts.push(format!("offset={offset}"));
+                }
+                if let Some(bits_changed) = row.bits_changed {
+                    parts.push(format!("bits={bits_changed}"));
+                }
+                if let Some(src_edge) = &row.src_edge {
+                    parts.push(format!("src@{src_edge}"));
                 }
+                parts.join(" ")
             })
             .collect::<Vec<_>>()
             .join("\n"),
+        CommandData::Wave(data) => render_wave(data, options.signals_abs),
         CommandData::ExtractAhb(data) => render_ahb_human(data, options),
         CommandData::ExtractApb(data) => render_apb_human(data, options),
         CommandData::ExtractAtb(data) => render_atb_human(data, options),
@@ -325,6 +568,9 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
             .map(|row| {
                 let mut parts = Vec::with_capacity(row.payload.len() + 3);
                 parts.push(format!("@{}", row.time));
+                if let Some(cycle) = row.cycle {
+                    parts.push(format!("cycle={cycle}"));
+                }
                 parts.push(format!("sample@{}", row.sample_time));
                 if data.source_count > 1 {
                     parts.push(format!("[{}]", row.source));
@@ -335,7 +581,11 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
                     } else {
                         payload.display.as_str()
                     };
-                    parts.push(format!("{display}={}", payload.value));
+                    parts.push(render_assignment(
+                        display,
+                        payload.value.as_str(),
+                        payload.fields.as_ref(),
+                    ));
                 }
                 parts.join(" ")
             })
@@ -356,6 +606,19 @@ fn render_human(data: &CommandData, options: HumanRenderOptions) -> String {
     }
 }
 
+/// ` cycle=N` after a row time, or nothing without `--clock`.
+fn cycle_suffix(cycle: Option<u64>) -> String {
+    cycle.map_or_else(String::new, |cycle| format!(" cycle={cycle}"))
+}
+
+/// `display=value`, followed by the decoded field pattern when a layout applies.
+fn render_assignment(display: &str, value: &str, fields: Option<&ValueFields>) -> String {
+    match fields {
+        Some(fields) => format!("{display}={value} {}", format_assignment_pattern(fields)),
+        None => format!("{display}={value}"),
+    }
+}
+
 fn render_ahb_human(data: &crate::engine::ahb::AhbData, options: HumanRenderOptions) -> String {
     let mut lines = Vec::new();
     lines.push(format!("name: {}", data.name));
@@ -390,6 +653,9 @@ fn render_ahb_human(data: &crate::engine::ahb::AhbData, options: HumanRenderOpti
         }
         let mut parts = Vec::with_capacity(event.payload.len() + 3);
         parts.push(format!("@{}", event.time));
+        if let Some(cycle) = event.cycle {
+            parts.push(format!("cycle={cycle}"));
+        }
         parts.push(format!("sample@{}", event.sample_time));
         parts.push(format!("[{}]", label.join(" ")));
         for payload in &event.payload {
@@ -425,6 +691,9 @@ fn render_apb_human(data: &crate::engine::apb::ApbData, options: HumanRenderOpti
     for event in &data.events {
         let mut parts = Vec::with_capacity(event.payload.len() + 3);
         parts.push(format!("@{}", event.time));
+        if let Some(cycle) = event.cycle {
+            parts.push(format!("cycle={cycle}"));
+        }
         parts.push(format!("sample@{}", event.sample_time));
         parts.push(format!("[{} {}]", event.event, event.direction));
         for payload in &event.payload {
@@ -458,6 +727,9 @@ fn render_atb_human(data: &crate::engine::atb::AtbData, options: HumanRenderOpti
     for event in &data.events {
         let mut parts = Vec::with_capacity(event.payload.len() + 3);
         parts.push(format!("@{}", event.time));
+        if let Some(cycle) = event.cycle {
+            parts.push(format!("cycle={cycle}"));
+        }
         parts.push(format!("sample@{}", event.sample_time));
         parts.push(format!("[{}]", event.event.as_str()));
         for payload in &event.payload {
@@ -491,6 +763,9 @@ fn render_axi_human(data: &crate::engine::axi::AxiData, options: HumanRenderOpti
     for transfer in &data.transfers {
         let mut parts = Vec::with_capacity(transfer.payload.len() + 3);
         parts.push(format!("@{}", transfer.time));
+        if let Some(cycle) = transfer.cycle {
+            parts.push(format!("cycle={cycle}"));
+        }
         parts.push(format!("sample@{}", transfer.sample_time));
         parts.push(format!("[{}]", transfer.channel));
         for payload in &transfer.payload {
@@ -528,6 +803,9 @@ fn render_axistream_human(
     for transfer in &data.transfers {
         let mut parts = Vec::with_capacity(transfer.payload.len() + 2);
         parts.push(format!("@{}", transfer.time));
+        if let Some(cycle) = transfer.cycle {
+            parts.push(format!("cycle={cycle}"));
+        }
         parts.push(format!("sample@{}", transfer.sample_time));
         for payload in &transfer.payload {
             let display = if options.signals_abs {
@@ -542,6 +820,34 @@ fn render_axistream_human(
     lines.join("\n")
 }
 
+fn scope_attributes(entry: &crate::engine::scope::ScopeEntry) -> String {
+    let mut attributes = format!("kind={}", entry.kind);
+    if let Some(module) = entry.module.as_deref() {
+        attributes.push_str(format!(" module={module}").as_str());
+    }
+    if let Some(source) = entry.source.as_ref() {
+        attributes.push_str(format!(" source={}:{}", source.file, source.line).as_str());
+    }
+    if let Some(source) = entry.instantiation.as_ref() {
+        attributes.push_str(format!(" instantiated={}:{}", source.file, source.line).as_str());
+    }
+    if let Some(compare) = entry.compare.as_ref() {
+        attributes.push_str(compare_attributes(compare).as_str());
+    }
+    attributes
+}
+
+fn compare_attributes(compare: &crate::engine::hierarchy_diff::CompareInfo) -> String {
+    let mut attributes = format!(" compare={}", compare.status.as_str());
+    if let Some(kind) = compare.kind.as_deref() {
+        attributes.push_str(format!(" b_kind={kind}").as_str());
+    }
+    if let Some(width) = compare.width {
+        attributes.push_str(format!(" b_width={width}").as_str());
+    }
+    attributes
+}
+
 fn render_scope_tree(scopes: &[crate::engine::scope::ScopeEntry]) -> String {
     if scopes.is_empty() {
         return String::new();
@@ -552,7 +858,7 @@ fn render_scope_tree(scopes: &[crate::engine::scope::ScopeEntry]) -> String {
 
     for (index, entry) in scopes.iter().enumerate() {
         let label = entry.path.rsplit('.').next().unwrap_or(entry.path.as_str());
-        let scope_label = format!("{label} kind={}", entry.kind);
+        let scope_label = format!("{label} {}", scope_attributes(entry));
         let is_last = scope_entry_is_last_sibling(scopes, index);
 
         if entry.depth == 0 {
@@ -595,6 +901,516 @@ fn scope_entry_is_last_sibling(scopes: &[crate::engine::scope::ScopeEntry], inde
     true
 }
 
+struct WaveGlyphs {
+    low: char,
+    high: char,
+    rise: char,
+    fall: char,
+    unknown: char,
+    high_z: char,
+    ellipsis: char,
+}
+
+const UNICODE_WAVE_GLYPHS: WaveGlyphs = WaveGlyphs {
+    low: '▁',
+    high: '▔',
+    rise: '╱',
+    fall: '╲',
+    unknown: '▒',
+    high_z: '─',
+    ellipsis: '…',
+};
+
+const ASCII_WAVE_GLYPHS: WaveGlyphs = WaveGlyphs {
+    low: '_',
+    high: '-',
+    rise: '/',
+    fall: '\\',
+    unknown: 'x',
+    high_z: '~',
+    ellipsis: '+',
+};
+
+/// Terminal width used by wave rendering when no explicit `--width` is given.
+pub(crate) fn terminal_columns() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|columns| *columns > 0)
+        .unwrap_or(DEFAULT_WAVE_WIDTH)
+}
+
+/// Number of drawing columns left for traces once the label column is reserved.
+pub(crate) fn wave_plot_columns(labels: &[&str], width: usize) -> usize {
+    width
+        .saturating_sub(wave_label_width(labels, width) + 1)
+        .max(1)
+}
+
+fn wave_label_width(labels: &[&str], width: usize) -> usize {
+    let longest = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    longest.clamp(1, (width / 3).max(1))
+}
+
+fn render_wave(data: &crate::engine::view::WaveData, abs: bool) -> String {
+    if data.times.is_empty() || data.traces.is_empty() {
+        return String::new();
+    }
+
+    let glyphs = if data.ascii {
+        &ASCII_WAVE_GLYPHS
+    } else {
+        &UNICODE_WAVE_GLYPHS
+    };
+    let labels = data
+        .traces
+        .iter()
+        .map(|trace| {
+            if abs {
+                trace.path.as_str()
+            } else {
+                trace.display.as_str()
+            }
+        })
+        .collect::<Vec<_>>();
+    let label_width = wave_label_width(&labels, data.width);
+    let plot_columns = wave_plot_columns(&labels, data.width);
+    let cell_width = (plot_columns / data.times.len()).clamp(1, MAX_WAVE_CELL_WIDTH);
+
+    let mut lines = Vec::with_capacity(data.traces.len() + 1);
+    let ruler = render_wave_ruler(&data.times, cell_width, plot_columns);
+    lines.push(
+        format!("{:label_width$} {ruler}", "")
+            .trim_end()
+            .to_string(),
+    );
+    for (trace, label) in data.traces.iter().zip(labels) {
+        let body = if trace.width == 1 {
+            render_bit_trace(&trace.values, cell_width, glyphs)
+        } else {
+            render_bus_trace(&trace.values, cell_width, glyphs)
+        };
+        let label = fit_wave_label(label, label_width, glyphs);
+        lines.push(
+            format!("{label:<label_width$} {body}")
+                .trim_end()
+                .to_string(),
+        );
+    }
+
+    lines.join("\n")
+}
+
+fn render_wave_ruler(times: &[String], cell_width: usize, plot_columns: usize) -> String {
+    let mut ruler = String::new();
+    let mut cursor = 0usize;
+    for (index, time) in times.iter().enumerate() {
+        let position = index * cell_width;
+        let label_len = time.chars().count() + 1;
+        if position < cursor || position + label_len > plot_columns {
+            continue;
+        }
+        let filled = ruler.chars().count();
+        ruler.extend(std::iter::repeat_n(' ', position - filled));
+        ruler.push('|');
+        ruler.push_str(time);
+        cursor = position + label_len + 1;
+    }
+    ruler
+}
+
+fn render_bit_trace(values: &[Option<String>], cell_width: usize, glyphs: &WaveGlyphs) -> String {
+    let mut body = String::new();
+    let mut previous = None;
+    for value in values {
+        let bit = value
+            .as_deref()
+            .and_then(|literal| wave_literal_digits(literal).chars().last());
+        let level = match bit {
+            Some('0') => glyphs.low,
+            Some('1') => glyphs.high,
+            Some('z') => glyphs.high_z,
+            Some(_) => glyphs.unknown,
+            None => ' ',
+        };
+        let first = match (previous, bit) {
+            (Some('0'), Some('1')) => glyphs.rise,
+            (Some('1'), Some('0')) => glyphs.fall,
+            _ => level,
+        };
+        body.push(first);
+        body.extend(std::iter::repeat_n(level, cell_width - 1));
+        previous = bit;
+    }
+    body
+}
+
+fn render_bus_trace(values: &[Option<String>], cell_width: usize, glyphs: &WaveGlyphs) -> String {
+    let mut body = String::new();
+    let mut index = 0usize;
+    while index < values.len() {
+        let value = values[index].as_deref();
+        let mut cells = 1usize;
+        while values
+            .get(index + cells)
+            .is_some_and(|next| next.as_deref() == value)
+        {
+            cells += 1;
+        }
+        push_bus_segment(&mut body, value, cells * cell_width, glyphs);
+        index += cells;
+    }
+    body
+}
+
+fn push_bus_segment(body: &mut String, value: Option<&str>, len: usize, glyphs: &WaveGlyphs) {
+    let Some(value) = value else {
+        body.extend(std::iter::repeat_n(' ', len));
+        return;
+    };
+    let digits = wave_literal_digits(value);
+    if !digits.is_empty() && digits.chars().all(|digit| digit == 'x') {
+        body.extend(std::iter::repeat_n(glyphs.unknown, len));
+        return;
+    }
+    if !digits.is_empty() && digits.chars().all(|digit| digit == 'z') {
+        body.extend(std::iter::repeat_n(glyphs.high_z, len));
+        return;
+    }
+    if len == 1 {
+        body.push('|');
+        return;
+    }
+
+    let room = len - 2;
+    let digit_count = digits.chars().count();
+    body.push('<');
+    if digit_count <= room {
+        body.push_str(digits);
+        body.extend(std::iter::repeat_n(' ', room - digit_count));
+    } else if room > 0 {
+        body.extend(digits.chars().take(room - 1));
+        body.push(glyphs.ellipsis);
+    }
+    body.push('>');
+}
+
+/// Value digits of a `<width>'h<digits>` literal, or the raw text for non-integral values.
+fn wave_literal_digits(literal: &str) -> &str {
+    literal
+        .split_once("'h")
+        .map_or(literal, |(_, digits)| digits)
+}
+
+fn fit_wave_label(label: &str, width: usize, glyphs: &WaveGlyphs) -> String {
+    let count = label.chars().count();
+    if count <= width {
+        return label.to_string();
+    }
+    let mut fitted = String::with_capacity(width);
+    fitted.push(glyphs.ellipsis);
+    fitted.extend(label.chars().skip(count - (width - 1)));
+    fitted
+}
+
+const HTML_STYLE: &str = "body{font-family:system-ui,sans-serif;margin:24px;color:#1f2328}\
+h1{font-size:20px}h2{font-size:16px;margin-top:24px}\
+table{border-collapse:collapse;font-size:13px}\
+th,td{border:1px solid #d0d7de;padding:3px 8px;text-align:left;vertical-align:top}\
+th{background:#f6f8fa}code,td{font-family:ui-monospace,monospace}\
+.meta{color:#57606a;font-size:13px}.info{color:#0969da}.warning{color:#9a6700}.error{color:#cf222e}\
+details{margin:2px 0}summary{cursor:pointer;color:#57606a;font-size:12px}\
+svg text{font-family:ui-monospace,monospace;font-size:11px}\
+.trace{fill:none;stroke:#1a7f37;stroke-width:1.5}.bus{fill:#dafbe1;stroke:#1a7f37}\
+.unknown{fill:#ffcecb;stroke:#cf222e}.highz{stroke:#8250df;stroke-width:1.5;fill:none}\
+.marker{stroke:#0969da;stroke-dasharray:3 3}";
+const STRIP_LABEL_WIDTH: usize = 150;
+const STRIP_CELL_WIDTH: usize = 14;
+const STRIP_ROW_HEIGHT: usize = 22;
+/// Rows whose waveform strip starts expanded; later strips stay collapsed.
+const OPEN_STRIP_ROWS: usize = 10;
+
+fn render_html(
+    result: &CommandResult,
+    report: &crate::engine::report::HtmlReport,
+) -> Result<String, WavepeekError> {
+    let envelope = OutputEnvelope::from_result(result)?;
+    let envelope = serde_json::to_value(&envelope)
+        .map_err(|error| WavepeekError::Internal(format!("failed to serialize output: {error}")))?;
+    let command = html_escape(result.command.as_str());
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>wavepeek {command}</title>\n"));
+    html.push_str(&format!("<style>{HTML_STYLE}</style>\n</head>\n<body>\n"));
+    html.push_str(&format!("<h1>wavepeek {command}</h1>\n"));
+    html.push_str(&format!(
+        "<p class=\"meta\">waves: <code>{}</code> &middot; wavepeek {}</p>\n",
+        html_escape(report.waves.as_str()),
+        env!("CARGO_PKG_VERSION")
+    ));
+
+    let data = &envelope["data"];
+    let mut rows: &[serde_json::Value] = &[];
+    if let Some(array) = data.as_array() {
+        rows = array;
+    } else if let Some(object) = data.as_object() {
+        let context = object
+            .iter()
+            .filter(|(_, value)| !value.is_array())
+            .collect::<Vec<_>>();
+        if !context.is_empty() {
+            html.push_str("<h2>Context</h2>\n<table>\n");
+            for (key, value) in context {
+                html.push_str(&format!(
+                    "<tr><th>{}</th><td>{}</td></tr>\n",
+                    html_escape(key),
+                    html_cell(value)
+                ));
+            }
+            html.push_str("</table>\n");
+        }
+        for (key, value) in object {
+            let Some(items) = value.as_array() else {
+                continue;
+            };
+            if items.iter().any(|item| item.get("time").is_some()) {
+                rows = items;
+                continue;
+            }
+            html.push_str(&format!("<h2>{}</h2>\n", html_escape(key)));
+            html.push_str(&html_table(items, None));
+        }
+    }
+
+    if let Some(diagnostics) = envelope["diagnostics"].as_array()
+        && !diagnostics.is_empty()
+    {
+        html.push_str("<h2>Diagnostics</h2>\n<ul>\n");
+        for diagnostic in diagnostics {
+            let kind = diagnostic["kind"].as_str().unwrap_or("info");
+            let code = diagnostic["code"]
+                .as_str()
+                .map(|code| format!("[{code}]"))
+                .unwrap_or_default();
+            html.push_str(&format!(
+                "<li class=\"{kind}\">{kind}{}: {}</li>\n",
+                html_escape(code.as_str()),
+                html_escape(diagnostic["message"].as_str().unwrap_or_default())
+            ));
+        }
+        html.push_str("</ul>\n");
+    }
+
+    html.push_str(&format!("<h2>Rows ({})</h2>\n", rows.len()));
+    html.push_str(&html_table(rows, Some(&report.strips)));
+    html.push_str("</body>\n</html>\n");
+    Ok(html)
+}
+
+fn html_table(
+    items: &[serde_json::Value],
+    strips: Option<&[Option<crate::engine::report::ReportStrip>]>,
+) -> String {
+    let mut columns: Vec<&str> = Vec::new();
+    for item in items {
+        if let Some(object) = item.as_object() {
+            for key in object.keys() {
+                if !columns.contains(&key.as_str()) {
+                    columns.push(key.as_str());
+                }
+            }
+        }
+    }
+
+    // Time columns lead; serde_json objects otherwise come back key-sorted.
+    columns.sort_by_key(|column| match *column {
+        "time" => 0,
+        "tick" => 1,
+        "cycle" => 2,
+        "sample_time" => 3,
+        _ => 4,
+    });
+
+    let mut html = String::from("<table>\n<tr>");
+    for column in &columns {
+        html.push_str(&format!("<th>{}</th>", html_escape(column)));
+    }
+    html.push_str("</tr>\n");
+    for (index, item) in items.iter().enumerate() {
+        html.push_str("<tr>");
+        for column in &columns {
+            html.push_str(&format!("<td>{}</td>", html_cell(&item[*column])));
+        }
+        html.push_str("</tr>\n");
+        if let Some(Some(strip)) = strips.and_then(|strips| strips.get(index)) {
+            let open = if index < OPEN_STRIP_ROWS { " open" } else { "" };
+            html.push_str(&format!(
+                "<tr><td colspan=\"{}\"><details{open}><summary>waveform</summary>\n{}</details></td></tr>\n",
+                columns.len().max(1),
+                render_strip_svg(strip)
+            ));
+        }
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+/// One table cell: scalars verbatim, `name=value` lists for payloads, compact JSON otherwise.
+fn html_cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(text) => html_escape(text),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| {
+                let name = ["standard", "display", "path", "name"]
+                    .iter()
+                    .find_map(|key| item.get(*key).and_then(serde_json::Value::as_str));
+                match (name, item.get("value")) {
+                    (Some(name), Some(value)) => {
+                        format!("{}={}", html_escape(name), html_cell(value))
+                    }
+                    _ => html_cell(item),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("<br>"),
+        other => html_escape(other.to_string().as_str()),
+    }
+}
+
+fn render_strip_svg(strip: &crate::engine::report::ReportStrip) -> String {
+    let wave = &strip.wave;
+    let columns = wave.times.len();
+    let width = STRIP_LABEL_WIDTH + columns * STRIP_CELL_WIDTH + 60;
+    let height = (wave.traces.len() + 1) * STRIP_ROW_HEIGHT;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+
+    let column_x = |column: usize| STRIP_LABEL_WIDTH + column * STRIP_CELL_WIDTH;
+    let mut last_label: Option<usize> = None;
+    for column in [0, strip.marker, columns.saturating_sub(1)] {
+        if last_label.is_some_and(|last| column < last + 5) {
+            continue;
+        }
+        last_label = Some(column);
+        if let Some(time) = wave.times.get(column) {
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"14\">{}</text>\n",
+                column_x(column),
+                html_escape(time)
+            ));
+        }
+    }
+
+    for (row, trace) in wave.traces.iter().enumerate() {
+        let top = (row + 1) * STRIP_ROW_HEIGHT + 3;
+        let high = top;
+        let low = top + STRIP_ROW_HEIGHT - 8;
+        let mid = (high + low) / 2;
+        svg.push_str(&format!(
+            "<text x=\"0\" y=\"{}\"><title>{}</title>{}</text>\n",
+            mid + 4,
+            html_escape(trace.path.as_str()),
+            html_escape(trace.display.as_str())
+        ));
+
+        let mut index = 0usize;
+        let mut previous_level: Option<usize> = None;
+        while index < columns {
+            let value = trace.values[index].as_deref();
+            let mut cells = 1usize;
+            while trace
+                .values
+                .get(index + cells)
+                .is_some_and(|next| next.as_deref() == value)
+            {
+                cells += 1;
+            }
+            let x0 = column_x(index);
+            let x1 = column_x(index + cells);
+            index += cells;
+
+            let Some(value) = value else {
+                previous_level = None;
+                continue;
+            };
+            let digits = wave_literal_digits(value);
+            let title = format!(
+                "<title>{} = {}</title>",
+                html_escape(trace.display.as_str()),
+                html_escape(value)
+            );
+            if !digits.is_empty() && digits.chars().all(|digit| digit == 'x') {
+                svg.push_str(&format!(
+                    "<rect class=\"unknown\" x=\"{x0}\" y=\"{high}\" width=\"{}\" height=\"{}\">{title}</rect>\n",
+                    x1 - x0,
+                    low - high
+                ));
+                previous_level = None;
+            } else if !digits.is_empty() && digits.chars().all(|digit| digit == 'z') {
+                svg.push_str(&format!(
+                    "<path class=\"highz\" d=\"M{x0} {mid} H{x1}\">{title}</path>\n"
+                ));
+                previous_level = None;
+            } else if trace.width == 1 {
+                let level = if digits == "1" { high } else { low };
+                let join = previous_level
+                    .filter(|previous| *previous != level)
+                    .map(|previous| format!("M{x0} {previous} V{level} "))
+                    .unwrap_or_default();
+                svg.push_str(&format!(
+                    "<path class=\"trace\" d=\"{join}M{x0} {level} H{x1}\">{title}</path>\n"
+                ));
+                previous_level = Some(level);
+            } else {
+                svg.push_str(&format!(
+                    "<rect class=\"bus\" x=\"{x0}\" y=\"{high}\" width=\"{}\" height=\"{}\" rx=\"3\">{title}</rect>\n",
+                    x1 - x0,
+                    low - high
+                ));
+                if digits.len() * 7 + 4 <= x1 - x0 {
+                    svg.push_str(&format!(
+                        "<text x=\"{}\" y=\"{}\">{}</text>\n",
+                        x0 + 3,
+                        mid + 4,
+                        html_escape(digits)
+                    ));
+                }
+                previous_level = None;
+            }
+        }
+    }
+
+    let marker_x = column_x(strip.marker) + STRIP_CELL_WIDTH / 2;
+    svg.push_str(&format!(
+        "<line class=\"marker\" x1=\"{marker_x}\" y1=\"18\" x2=\"{marker_x}\" y2=\"{height}\"/>\n"
+    ));
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
 fn signal_display_name(entry: &crate::engine::signal::SignalEntry, abs: bool) -> &str {
     if abs {
         entry.path.as_str()
@@ -650,7 +1466,7 @@ mod tests {
 
     use super::{
         JsonlWriter, render_human, render_json, render_scope_tree, scope_entry_is_last_sibling,
-        signal_display_name, write, write_jsonl_result,
+        signal_display_name, wave_plot_columns, write, write_jsonl_result,
     };
 
     #[test]
@@ -663,6 +1479,15 @@ mod tests {
                 time_unit: "1ns".to_string(),
                 time_start: "0ns".to_string(),
                 time_end: "10ns".to_string(),
+                format: "vcd".to_string(),
+                backend: "wellen".to_string(),
+                scope_count: 1,
+                signal_count: 2,
+                timestamp_count: Some(3),
+                date: None,
+                simulator: None,
+                timescale: None,
+                activity: None,
             }),
             diagnostics: vec![],
         };
@@ -688,6 +1513,10 @@ mod tests {
                 path: "top.cpu".to_string(),
                 depth: 1,
                 kind: "module".to_string(),
+                module: None,
+                source: None,
+                instantiation: None,
+                compare: None,
             }]),
             diagnostics: vec![Diagnostic::warning(
                 WarningDiagnosticCode::OutputTruncated,
@@ -772,7 +1601,11 @@ mod tests {
             writer
                 .item(&crate::engine::change::ChangeSnapshot {
                     time: "5ns".to_string(),
+                    raw_time: 5,
+                    tick: None,
+                    cycle: None,
                     sample_time: "5ns".to_string(),
+                    sample_raw_time: 5,
                     signals: Vec::new(),
                 })
                 .expect("item record should write");
@@ -828,6 +1661,10 @@ mod tests {
                 path: "top".to_string(),
                 depth: 0,
                 kind: "module".to_string(),
+                module: None,
+                source: None,
+                instantiation: None,
+                compare: None,
             }]),
             diagnostics: vec![Diagnostic::warning(
                 WarningDiagnosticCode::OutputTruncated,
@@ -855,11 +1692,17 @@ mod tests {
             &CommandData::Property(vec![
                 crate::engine::property::PropertyCaptureRow {
                     time: "10ns".to_string(),
+                    tick: None,
+                    cycle: None,
+                    raw_time: 10,
                     sample_time: "10ns".to_string(),
                     kind: crate::engine::property::PropertyResultKind::Assert,
                 },
                 crate::engine::property::PropertyCaptureRow {
                     time: "25ns".to_string(),
+                    tick: None,
+                    cycle: None,
+                    raw_time: 25,
                     sample_time: "24ns".to_string(),
                     kind: crate::engine::property::PropertyResultKind::Deassert,
                 },
@@ -878,26 +1721,46 @@ mod tests {
                     path: "top".to_string(),
                     depth: 0,
                     kind: "module".to_string(),
+                    module: None,
+                    source: None,
+                    instantiation: None,
+                    compare: None,
                 },
                 crate::engine::scope::ScopeEntry {
                     path: "top.cpu".to_string(),
                     depth: 1,
                     kind: "module".to_string(),
+                    module: None,
+                    source: None,
+                    instantiation: None,
+                    compare: None,
                 },
                 crate::engine::scope::ScopeEntry {
                     path: "top.cpu.alu".to_string(),
                     depth: 2,
                     kind: "function".to_string(),
+                    module: None,
+                    source: None,
+                    instantiation: None,
+                    compare: None,
                 },
                 crate::engine::scope::ScopeEntry {
                     path: "top.cpu.regs".to_string(),
                     depth: 2,
                     kind: "module".to_string(),
+                    module: None,
+                    source: None,
+                    instantiation: None,
+                    compare: None,
                 },
                 crate::engine::scope::ScopeEntry {
                     path: "top.mem".to_string(),
                     depth: 1,
                     kind: "module".to_string(),
+                    module: None,
+                    source: None,
+                    instantiation: None,
+                    compare: None,
                 },
             ]),
             HumanRenderOptions {
@@ -917,16 +1780,22 @@ mod tests {
         let rendered = render_human(
             &CommandData::Value(vec![crate::engine::value::ValueSnapshot {
                 time: "10ns".to_string(),
+                tick: None,
+                cycle: None,
                 signals: vec![
                     crate::engine::value::ValueSignalValue {
                         display: "clk".to_string(),
                         path: "top.clk".to_string(),
                         value: "1'h1".to_string(),
+                        fields: None,
+                        decoded: false,
                     },
                     crate::engine::value::ValueSignalValue {
                         display: "data".to_string(),
                         path: "top.data".to_string(),
                         value: "8'h0f".to_string(),
+                        fields: None,
+                        decoded: false,
                     },
                 ],
             }]),
@@ -941,17 +1810,23 @@ mod tests {
         let rendered = render_human(
             &CommandData::Change(vec![crate::engine::change::ChangeSnapshot {
                 time: "5ns".to_string(),
+                raw_time: 5,
+                tick: None,
+                cycle: None,
                 sample_time: "4ns".to_string(),
+                sample_raw_time: 4,
                 signals: vec![
                     crate::engine::change::ChangeSignalValue {
                         display: "clk".to_string(),
                         path: "top.clk".to_string(),
                         value: "1'h1".to_string(),
+                        fields: None,
                     },
                     crate::engine::change::ChangeSignalValue {
                         display: "data".to_string(),
                         path: "top.data".to_string(),
                         value: "8'h00".to_string(),
+                        fields: None,
                     },
                 ],
             }]),
@@ -974,20 +1849,51 @@ mod tests {
                 time_unit: "1ps".to_string(),
                 time_start: "0ps".to_string(),
                 time_end: "10ps".to_string(),
+                format: "vcd".to_string(),
+                backend: "wellen".to_string(),
+                scope_count: 1,
+                signal_count: 2,
+                timestamp_count: Some(3),
+                date: None,
+                simulator: Some("Icarus Verilog".to_string()),
+                timescale: None,
+                activity: Some(vec![crate::engine::info::InfoActivity {
+                    path: "top.clk".to_string(),
+                    changes: 4,
+                }]),
             }),
             HumanRenderOptions::default(),
         );
-        assert_eq!(info, "time_unit: 1ps\ntime_start: 0ps\ntime_end: 10ps");
+        assert_eq!(
+            info,
+            concat!(
+                "time_unit: 1ps\ntime_start: 0ps\ntime_end: 10ps\n",
+                "format: vcd\nbackend: wellen\nscope_count: 1\nsignal_count: 2\n",
+                "timestamp_count: 3\nsimulator: Icarus Verilog\n",
+                "activity:\n  top.clk changes=4",
+            )
+        );
 
         let flat_scopes = render_human(
             &CommandData::Scope(vec![crate::engine::scope::ScopeEntry {
                 path: "top.cpu".to_string(),
                 depth: 1,
                 kind: "module".to_string(),
+                module: Some("cpu_core".to_string()),
+                source: None,
+                instantiation: None,
+                compare: Some(crate::engine::hierarchy_diff::CompareInfo {
+                    status: crate::engine::hierarchy_diff::CompareStatus::OnlyB,
+                    kind: None,
+                    width: None,
+                }),
             }]),
             HumanRenderOptions::default(),
         );
-        assert_eq!(flat_scopes, "1 top.cpu kind=module");
+        assert_eq!(
+            flat_scopes,
+            "1 top.cpu kind=module module=cpu_core compare=only-b"
+        );
 
         let signals = vec![
             crate::engine::signal::SignalEntry {
@@ -996,6 +1902,8 @@ mod tests {
                 path: "top.clk".to_string(),
                 kind: "wire".to_string(),
                 width: Some(1),
+                direction: Some("input".to_string()),
+                compare: None,
             },
             crate::engine::signal::SignalEntry {
                 display: "status".to_string(),
@@ -1003,6 +1911,12 @@ mod tests {
                 path: "top.status".to_string(),
                 kind: "event".to_string(),
                 width: None,
+                direction: None,
+                compare: Some(crate::engine::hierarchy_diff::CompareInfo {
+                    status: crate::engine::hierarchy_diff::CompareStatus::Changed,
+                    kind: Some("reg".to_string()),
+                    width: Some(1),
+                }),
             },
         ];
         let rendered = render_human(
@@ -1012,7 +1926,10 @@ mod tests {
                 signals_abs: true,
             },
         );
-        assert_eq!(rendered, "top.clk kind=wire width=1\ntop.status kind=event");
+        assert_eq!(
+            rendered,
+            "top.clk kind=wire width=1 direction=input\ntop.status kind=event compare=changed b_kind=reg b_width=1"
+        );
         assert_eq!(signal_display_name(&signals[0], true), "top.clk");
         assert_eq!(signal_display_name(&signals[0], false), "clk");
 
@@ -1045,22 +1962,79 @@ mod tests {
                 path: "top".to_string(),
                 depth: 0,
                 kind: "module".to_string(),
+                module: None,
+                source: None,
+                instantiation: None,
+                compare: None,
             },
             crate::engine::scope::ScopeEntry {
                 path: "top.cpu".to_string(),
                 depth: 1,
                 kind: "module".to_string(),
+                module: None,
+                source: None,
+                instantiation: None,
+                compare: None,
             },
             crate::engine::scope::ScopeEntry {
                 path: "top.mem".to_string(),
                 depth: 1,
                 kind: "module".to_string(),
+                module: None,
+                source: None,
+                instantiation: None,
+                compare: None,
             },
         ];
         assert!(!scope_entry_is_last_sibling(&scopes, 1));
         assert!(scope_entry_is_last_sibling(&scopes, 2));
     }
 
+    #[test]
+    fn wave_render_fits_labels_and_bus_values_into_width() {
+        let data = crate::engine::view::WaveData {
+            times: vec!["0ns".to_string(), "1ns".to_string(), "2ns".to_string()],
+            traces: vec![
+                crate::engine::view::WaveTrace {
+                    display: "a_rather_long_signal_name".to_string(),
+                    path: "top.a_rather_long_signal_name".to_string(),
+                    width: 32,
+                    values: vec![
+                        None,
+                        Some("32'hdeadbeef".to_string()),
+                        Some("32'h00000001".to_string()),
+                    ],
+                },
+                crate::engine::view::WaveTrace {
+                    display: "en".to_string(),
+                    path: "top.en".to_string(),
+                    width: 1,
+                    values: vec![
+                        Some("1'hz".to_string()),
+                        Some("1'h0".to_string()),
+                        Some("1'h1".to_string()),
+                    ],
+                },
+            ],
+            width: 24,
+            ascii: true,
+        };
+
+        let rendered = render_human(&CommandData::Wave(data), HumanRenderOptions::default());
+
+        assert_eq!(
+            rendered,
+            concat!(
+                "         |0ns |1ns |2ns\n",
+                "+al_name      <de+><00+>\n",
+                "en       ~~~~~_____/----",
+            )
+        );
+        assert_eq!(wave_plot_columns(&["en"], 24), 21);
+        assert_eq!(wave_plot_columns(&["a_rather_long_signal_name"], 24), 15);
+        assert_eq!(wave_plot_columns(&["a_rather_long_signal_name"], 1), 1);
+    }
+
     #[test]
     fn write_entrypoint_exercises_json_empty_human_and_diagnostic_paths() {
         write(CommandResult {
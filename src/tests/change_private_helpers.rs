@@ -13,6 +13,7 @@ fn derives_private_state_and_mode_helpers() {
         display: "sig".to_string(),
         path: "top.sig".to_string(),
         value: "1'b1".to_string(),
+        fields: None,
     };
     assert_eq!(signal.clone(), signal);
     assert!(serde_json::to_string(&signal).unwrap().contains("top.sig"));
@@ -20,7 +21,11 @@ fn derives_private_state_and_mode_helpers() {
 
     let snapshot = ChangeSnapshot {
         time: "5ns".to_string(),
+        raw_time: 5,
+        tick: None,
+        cycle: None,
         sample_time: "5ns".to_string(),
+        sample_raw_time: 5,
         signals: vec![signal.clone()],
     };
     assert_eq!(snapshot.clone(), snapshot);
@@ -30,6 +35,8 @@ fn derives_private_state_and_mode_helpers() {
     let requested = RequestedSignal {
         display: "sig".to_string(),
         path: "top.sig".to_string(),
+        select: None,
+        layout: None,
     };
     assert_eq!(requested.clone(), requested);
     assert!(format!("{requested:?}").contains("top.sig"));
@@ -56,11 +56,17 @@ fn exercises_docs_topics_human_json_and_diagnostic_rendering() {
     let properties = CommandData::Property(vec![
         PropertyCaptureRow {
             time: "0ns".to_string(),
+            tick: None,
+            cycle: None,
+            raw_time: 0,
             sample_time: "0ns".to_string(),
             kind: PropertyResultKind::Assert,
         },
         PropertyCaptureRow {
             time: "1ns".to_string(),
+            tick: None,
+            cycle: None,
+            raw_time: 1,
             sample_time: "1ns".to_string(),
             kind: PropertyResultKind::Deassert,
         },
@@ -18,6 +18,9 @@ fn derives_public_capture_types() {
 
     let row = PropertyCaptureRow {
         time: "12ns".to_string(),
+        tick: None,
+        cycle: None,
+        raw_time: 12,
         sample_time: "11ns".to_string(),
         kind: PropertyResultKind::Match,
     };
@@ -120,6 +120,7 @@ fn derive_surfaces_for_data_transfer_types() {
         path: "top.cpu".to_string(),
         depth: 1,
         kind: "module".to_string(),
+        module: None,
     };
     assert_eq!(scope.clone(), scope);
     assert!(format!("{scope:?}").contains("top.cpu"));
@@ -129,6 +130,7 @@ fn derive_surfaces_for_data_transfer_types() {
         path: "top.data".to_string(),
         kind: "wire".to_string(),
         width: Some(8),
+        direction: None,
     };
     assert_eq!(signal.clone(), signal);
     assert!(format!("{signal:?}").contains("data"));
@@ -3,18 +3,33 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::rc::Rc;
 
+use crate::engine::signal_select::BitSelect;
+use crate::engine::time::{duration_ticks, parse_dump_time_context};
 use crate::error::WavepeekError;
 use crate::expr::{
-    DiagnosticLayer, ExprDiagnostic, ExprType, ExpressionHost, SampledValue, SignalHandle, Span,
+    DiagnosticLayer, ExprDiagnostic, ExprStorage, ExprType, ExprTypeKind, ExpressionHost,
+    SampledValue, SignalHandle, Span,
 };
 use crate::waveform::{ExprResolvedSignal, Waveform};
 
+/// Named bit ranges of packed signals, resolved in expressions as `signal.field`.
+pub(crate) trait PackedFieldSource: std::fmt::Debug {
+    /// Returns `(msb, lsb)` of `field` inside the signal at canonical `path`.
+    fn field_range(&self, path: &str, field: &str) -> Option<(u32, u32)>;
+}
+
+/// Whole-signal resolution of a packed field together with its `(msb, lsb)` range.
+type ResolvedPackedField = (ExprResolvedSignal, (u32, u32));
+
 #[derive(Debug)]
 pub(crate) struct WaveformExprHost {
     waveform: Rc<RefCell<Waveform>>,
     handles_by_name: RefCell<HashMap<String, SignalHandle>>,
     signals_by_handle: RefCell<HashMap<SignalHandle, Rc<ExprResolvedSignal>>>,
     next_handle: Cell<u32>,
+    packed_fields: Option<Rc<dyn PackedFieldSource>>,
+    /// Bit range of handles that read one field of a packed signal.
+    field_ranges: RefCell<HashMap<SignalHandle, BitSelect>>,
 }
 
 impl WaveformExprHost {
@@ -33,9 +48,49 @@ impl WaveformExprHost {
             handles_by_name: RefCell::new(HashMap::new()),
             signals_by_handle: RefCell::new(HashMap::new()),
             next_handle: Cell::new(1),
+            packed_fields: None,
+            field_ranges: RefCell::new(HashMap::new()),
         }
     }
 
+    /// Resolves `signal.field` names through `source` when no recorded signal has that name.
+    pub(crate) fn with_packed_fields(mut self, source: Option<Rc<dyn PackedFieldSource>>) -> Self {
+        self.packed_fields = source;
+        self
+    }
+
+    /// Whether `handle` reads one field of a packed signal rather than the whole signal.
+    pub(crate) fn is_packed_field_handle(&self, handle: SignalHandle) -> bool {
+        self.field_ranges.borrow().contains_key(&handle)
+    }
+
+    fn packed_field_range<'n>(&self, name: &'n str) -> Option<(&'n str, (u32, u32))> {
+        let (path, field) = name.rsplit_once('.')?;
+        let range = self.packed_fields.as_ref()?.field_range(path, field)?;
+        Some((path, range))
+    }
+
+    fn resolve_packed_field(
+        &self,
+        name: &str,
+    ) -> Option<Result<ResolvedPackedField, WavepeekError>> {
+        let (path, (msb, lsb)) = self.packed_field_range(name)?;
+        Some(
+            self.waveform
+                .borrow()
+                .resolve_expr_signal(path)
+                .and_then(|resolved| {
+                    let width = resolved.expr_type.width;
+                    if msb >= width {
+                        return Err(WavepeekError::Signal(format!(
+                            "layout field '{name}' [{msb}:{lsb}] is outside the {width}-bit signal '{path}'"
+                        )));
+                    }
+                    Ok((resolved, (msb, lsb)))
+                }),
+        )
+    }
+
     fn resolved_signal(
         &self,
         handle: SignalHandle,
@@ -70,17 +125,27 @@ impl ExpressionHost for WaveformExprHost {
             return Ok(handle);
         }
 
-        let resolved = self
-            .waveform
-            .borrow()
-            .resolve_expr_signal(name)
-            .map_err(|error| ExprDiagnostic {
-                layer: DiagnosticLayer::Semantic,
-                code: "HOST-UNKNOWN-SIGNAL",
-                message: error.to_string(),
-                primary_span: Span::new(0, 0),
-                notes: vec![],
-            })?;
+        let resolved = self.waveform.borrow().resolve_expr_signal(name);
+        let (resolved, field_range) = match resolved {
+            Ok(resolved) => Ok((resolved, None)),
+            Err(error) => match self.resolve_packed_field(name) {
+                Some(field) => field.map(|(resolved, range)| (resolved, Some(range))),
+                None => Err(error),
+            },
+        }
+        .map_err(|error| ExprDiagnostic {
+            layer: DiagnosticLayer::Semantic,
+            code: "HOST-UNKNOWN-SIGNAL",
+            message: if self.is_aggregate(name) {
+                format!(
+                    "'{name}' is a struct or union; reference one of its fields as '{name}.<field>'"
+                )
+            } else {
+                error.to_string()
+            },
+            primary_span: Span::new(0, 0),
+            notes: vec![],
+        })?;
 
         let handle = SignalHandle(self.next_handle.get());
         self.next_handle.set(handle.0 + 1);
@@ -90,11 +155,32 @@ impl ExpressionHost for WaveformExprHost {
         self.signals_by_handle
             .borrow_mut()
             .insert(handle, Rc::new(resolved));
+        if let Some((msb, lsb)) = field_range {
+            self.field_ranges
+                .borrow_mut()
+                .insert(handle, BitSelect::new(msb, lsb));
+        }
         Ok(handle)
     }
 
     fn signal_type(&self, handle: SignalHandle) -> Result<ExprType, ExprDiagnostic> {
-        Ok(self.resolved_signal(handle)?.expr_type.clone())
+        let expr_type = self.resolved_signal(handle)?.expr_type.clone();
+        Ok(match self.field_ranges.borrow().get(&handle) {
+            Some(&select) => ExprType {
+                kind: ExprTypeKind::BitVector,
+                storage: if select.width() == 1 {
+                    ExprStorage::Scalar
+                } else {
+                    ExprStorage::PackedVector
+                },
+                width: select.width(),
+                is_four_state: expr_type.is_four_state,
+                is_signed: false,
+                enum_type_id: None,
+                enum_labels: None,
+            },
+            None => expr_type,
+        })
     }
 
     fn sample_value(
@@ -103,7 +189,8 @@ impl ExpressionHost for WaveformExprHost {
         timestamp: u64,
     ) -> Result<SampledValue, ExprDiagnostic> {
         let resolved = self.resolved_signal(handle)?;
-        self.waveform
+        let sampled = self
+            .waveform
             .borrow_mut()
             .sample_expr_value(&resolved, timestamp)
             .map_err(|error| ExprDiagnostic {
@@ -112,7 +199,14 @@ impl ExpressionHost for WaveformExprHost {
                 message: error.to_string(),
                 primary_span: Span::new(0, 0),
                 notes: vec![],
-            })
+            })?;
+        Ok(match (sampled, self.field_ranges.borrow().get(&handle)) {
+            (SampledValue::Integral { bits, .. }, Some(&select)) => SampledValue::Integral {
+                bits: bits.map(|bits| select.slice(bits.as_str()).to_string()),
+                label: None,
+            },
+            (sampled, _) => sampled,
+        })
     }
 
     fn event_occurred(&self, handle: SignalHandle, timestamp: u64) -> Result<bool, ExprDiagnostic> {
@@ -128,6 +222,31 @@ impl ExpressionHost for WaveformExprHost {
                 notes: vec![],
             })
     }
+
+    fn is_aggregate(&self, name: &str) -> bool {
+        self.waveform.borrow().is_aggregate_scope(name)
+    }
+
+    fn is_packed_field(&self, name: &str) -> bool {
+        self.packed_field_range(name).is_some()
+    }
+
+    fn time_ticks(&self, token: &str) -> Result<u64, String> {
+        let metadata = self
+            .waveform
+            .borrow()
+            .metadata()
+            .map_err(|error| error.to_string())?;
+        let dump_time = parse_dump_time_context(&metadata).map_err(|error| error.to_string())?;
+        duration_ticks(token, dump_time)
+    }
+
+    fn is_dump_timestamp(&self, timestamp: u64) -> bool {
+        self.waveform
+            .borrow()
+            .indexed_timestamps()
+            .is_some_and(|timestamps| timestamps.binary_search(&timestamp).is_ok())
+    }
 }
 
 #[cfg(test)]
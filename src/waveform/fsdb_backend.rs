@@ -16,8 +16,9 @@ use super::fsdb_native::{
 };
 use super::fsdb_time::{normalize_raw_time, parse_scale_unit};
 use super::types::{
-    ChangeCandidateCollectionMode, ExprResolvedSignal, ResolvedSignal, SampledSignalState,
-    ScopeEntry, SignalEntry, SignalId, SignalOffsetData, WaveformMetadata,
+    ChangeCandidateCollectionMode, DumpHeader, ExprResolvedSignal, ResolvedSignal,
+    SampledSignalState, ScopeEntry, ScopeSources, SignalEntry, SignalId, SignalOffsetData,
+    SignalTransitions, ValueTransition, WaveformMetadata,
 };
 
 #[derive(Debug)]
@@ -148,6 +149,18 @@ impl FsdbBackend {
         })
     }
 
+    /// The shim does not read FSDB creation date or simulator version yet.
+    pub(super) fn header(&self) -> DumpHeader {
+        let timescale = self.raw_metadata().ok().and_then(|metadata| {
+            let scale_unit = metadata.scale_unit.trim();
+            (!scale_unit.is_empty()).then(|| scale_unit.to_string())
+        });
+        DumpHeader {
+            timescale,
+            ..DumpHeader::default()
+        }
+    }
+
     pub(super) fn scopes_depth_first(
         &self,
         max_depth: Option<usize>,
@@ -162,6 +175,30 @@ impl FsdbBackend {
         self.hierarchy()?.signals_in_scope(scope_path)
     }
 
+    pub(super) fn scope_kind(&self, scope_path: &str) -> Option<String> {
+        self.hierarchy().ok()?.scope_kind(scope_path)
+    }
+
+    /// FSDB Reader scope kinds have no array or packing information, so only structs and unions.
+    pub(super) fn is_aggregate_scope(&self, scope_path: &str) -> bool {
+        self.scope_kind(scope_path)
+            .is_some_and(|kind| matches!(kind.as_str(), "struct" | "union"))
+    }
+
+    /// The hierarchy index keeps signals sorted by name, so fields come back in that order.
+    pub(super) fn aggregate_fields(&self, scope_path: &str) -> Result<Vec<String>, WavepeekError> {
+        let prefix = format!("{scope_path}.");
+        Ok(self
+            .signals_in_scope_recursive(scope_path, None)?
+            .into_iter()
+            .filter_map(|entry| entry.path.strip_prefix(prefix.as_str()).map(str::to_string))
+            .collect())
+    }
+
+    pub(super) fn scope_sources(&self, scope_path: &str) -> Result<ScopeSources, WavepeekError> {
+        self.hierarchy()?.scope_sources(scope_path)
+    }
+
     pub(super) fn signals_in_scope_recursive(
         &self,
         scope_path: &str,
@@ -182,6 +219,56 @@ impl FsdbBackend {
             .collect()
     }
 
+    pub(super) fn declared_index_ranges(
+        &self,
+        canonical_paths: &[String],
+    ) -> Result<Vec<Option<(i64, i64)>>, WavepeekError> {
+        let hierarchy = self.hierarchy()?;
+        canonical_paths
+            .iter()
+            .map(|path| hierarchy.resolve_signal(path.as_str()).map(|_| None))
+            .collect()
+    }
+
+    pub(super) fn signal_transitions(
+        &mut self,
+        resolved: &[ResolvedSignal],
+        from_raw: u64,
+        to_raw: u64,
+    ) -> Result<Vec<SignalTransitions>, WavepeekError> {
+        let mut transitions = Vec::with_capacity(resolved.len());
+        for signal in resolved {
+            let single = std::slice::from_ref(signal);
+            let initial = self
+                .sample_resolved_optional(single, from_raw)?
+                .pop()
+                .and_then(|sample| sample.bits);
+            let change_times = if from_raw < to_raw {
+                self.collect_change_times_with_mode(
+                    single,
+                    from_raw + 1,
+                    to_raw,
+                    ChangeCandidateCollectionMode::Auto,
+                )?
+            } else {
+                Vec::new()
+            };
+
+            let mut changes = Vec::with_capacity(change_times.len());
+            for time in change_times {
+                let values = self
+                    .sample_resolved_optional(single, time)?
+                    .pop()
+                    .and_then(|sample| sample.bits)
+                    .into_iter()
+                    .collect();
+                changes.push(ValueTransition { time, values });
+            }
+            transitions.push(SignalTransitions { initial, changes });
+        }
+        Ok(transitions)
+    }
+
     pub(super) fn resolve_expr_signal(
         &self,
         canonical_path: &str,
@@ -5,7 +5,9 @@ use std::collections::HashMap;
 use crate::error::WavepeekError;
 use crate::expr::{EnumLabelInfo, ExprStorage, ExprType, ExprTypeKind, IntegerLikeKind};
 
-use super::types::{ExprResolvedSignal, ResolvedSignal, ScopeEntry, SignalEntry, SignalId};
+use super::types::{
+    ExprResolvedSignal, ResolvedSignal, ScopeEntry, ScopeSources, SignalEntry, SignalId,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum RawScopeKind {
@@ -94,6 +96,7 @@ pub(super) struct RawScopeRecord {
     pub(super) name: String,
     pub(super) kind: RawScopeKind,
     pub(super) hidden: bool,
+    pub(super) module: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -106,6 +109,7 @@ pub(super) struct RawSignalRecord {
     pub(super) packed_component: bool,
     pub(super) datatype_id: Option<u32>,
     pub(super) value_encoding: FsdbValueEncoding,
+    pub(super) direction: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -145,6 +149,7 @@ struct ScopeNode {
     name: String,
     path: String,
     kind: String,
+    module: Option<String>,
     depth: usize,
     parent: Option<usize>,
     children: Vec<usize>,
@@ -160,6 +165,7 @@ pub(super) struct FsdbSignalInfo {
     idcode: u64,
     value_encoding: FsdbValueEncoding,
     datatype: Option<RawDatatypeRecord>,
+    direction: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -245,6 +251,7 @@ impl FsdbHierarchyBuilder {
                 name,
                 path: path.clone(),
                 kind,
+                module: record.module,
                 depth,
                 parent,
                 children: Vec::new(),
@@ -310,6 +317,7 @@ impl FsdbHierarchyBuilder {
             idcode: record.idcode,
             value_encoding,
             datatype,
+            direction: record.direction,
         };
         if let Some(existing) = self.signal_by_path.get(path.as_str()).copied() {
             if self.signals[existing] == candidate {
@@ -365,6 +373,7 @@ impl FsdbHierarchyBuilder {
             name: name.to_string(),
             path: path.clone(),
             kind: "unknown".to_string(),
+            module: None,
             depth: self.scopes[parent].depth + 1,
             parent: Some(parent),
             children: Vec::new(),
@@ -519,6 +528,18 @@ impl FsdbHierarchyIndex {
             .collect())
     }
 
+    pub(super) fn scope_kind(&self, scope_path: &str) -> Option<String> {
+        self.scope_by_path
+            .get(scope_path)
+            .map(|index| self.scopes[*index].kind.clone())
+    }
+
+    /// FSDB Reader tree callbacks carry no HDL source locations, so this only validates the path.
+    pub(super) fn scope_sources(&self, scope_path: &str) -> Result<ScopeSources, WavepeekError> {
+        self.scope_index(scope_path)?;
+        Ok(ScopeSources::default())
+    }
+
     pub(super) fn signals_in_scope_recursive(
         &self,
         scope_path: &str,
@@ -585,6 +606,7 @@ impl FsdbHierarchyIndex {
             path: scope.path.clone(),
             depth: scope.depth,
             kind: scope.kind.clone(),
+            module: scope.module.clone(),
         });
         if max_depth == Some(scope.depth) {
             return;
@@ -645,6 +667,7 @@ fn signal_entry(signal: &FsdbSignalInfo) -> SignalEntry {
         path: signal.path.clone(),
         kind: signal.kind.clone(),
         width: signal.width,
+        direction: signal.direction.map(str::to_string),
     }
 }
 
@@ -1130,22 +1153,26 @@ mod tests {
                 ScopeEntry {
                     path: "alpha".to_string(),
                     depth: 0,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
                 ScopeEntry {
                     path: "top".to_string(),
                     depth: 0,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
                 ScopeEntry {
                     path: "top.a".to_string(),
                     depth: 1,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
                 ScopeEntry {
                     path: "top.z".to_string(),
                     depth: 1,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
             ]
         );
@@ -1155,12 +1182,14 @@ mod tests {
                 ScopeEntry {
                     path: "alpha".to_string(),
                     depth: 0,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
                 ScopeEntry {
                     path: "top".to_string(),
                     depth: 0,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
             ]
         );
@@ -1175,6 +1204,7 @@ mod tests {
                 name: "secret".to_string(),
                 kind: RawScopeKind::Module,
                 hidden: true,
+                module: None,
             })
             .unwrap();
         builder
@@ -1196,11 +1226,41 @@ mod tests {
                 path: "top.visible".to_string(),
                 kind: "wire".to_string(),
                 width: None,
+                direction: None,
             }]
         );
         assert!(index.signals_in_scope("top.secret").is_err());
     }
 
+    #[test]
+    fn fsdb_hierarchy_keeps_module_type_and_port_direction() {
+        let mut builder = FsdbHierarchyBuilder::new();
+        builder
+            .scope(RawScopeRecord {
+                module: Some("fifo_ctrl".to_string()),
+                ..scope("u_fifo", RawScopeKind::Module)
+            })
+            .unwrap();
+        builder
+            .signal(RawSignalRecord {
+                direction: Some("input"),
+                ..signal(1, "push", RawSignalKind::Wire)
+            })
+            .unwrap();
+        let index = builder.finish();
+
+        assert_eq!(
+            index.scopes_depth_first(None)[0].module.as_deref(),
+            Some("fifo_ctrl")
+        );
+        assert_eq!(
+            index.signals_in_scope("u_fifo").unwrap()[0]
+                .direction
+                .as_deref(),
+            Some("input")
+        );
+    }
+
     #[test]
     fn fsdb_hierarchy_rejects_duplicate_scope_paths_in_one_tree() {
         let mut builder = FsdbHierarchyBuilder::new();
@@ -1308,6 +1368,7 @@ mod tests {
                 path: "top.clk".to_string(),
                 kind: "wire".to_string(),
                 width: None,
+                direction: None,
             }]
         );
         assert_eq!(
@@ -1348,24 +1409,28 @@ mod tests {
                     path: "top.flags[0:0]".to_string(),
                     kind: "wire".to_string(),
                     width: Some(1),
+                    direction: None,
                 },
                 SignalEntry {
                     name: "flags[0]".to_string(),
                     path: "top.flags[0]".to_string(),
                     kind: "wire".to_string(),
                     width: Some(1),
+                    direction: None,
                 },
                 SignalEntry {
                     name: "flags[3]".to_string(),
                     path: "top.flags[3]".to_string(),
                     kind: "wire".to_string(),
                     width: Some(1),
+                    direction: None,
                 },
                 SignalEntry {
                     name: "mem[3]".to_string(),
                     path: "top.mem[3]".to_string(),
                     kind: "wire".to_string(),
                     width: None,
+                    direction: None,
                 },
             ]
         );
@@ -1409,24 +1474,28 @@ mod tests {
                     path: "top.dot.name".to_string(),
                     kind: "wire".to_string(),
                     width: None,
+                    direction: None,
                 },
                 SignalEntry {
                     name: "slash/name".to_string(),
                     path: "top.slash/name".to_string(),
                     kind: "wire".to_string(),
                     width: None,
+                    direction: None,
                 },
                 SignalEntry {
                     name: "wide.dot[0]".to_string(),
                     path: "top.wide.dot[0]".to_string(),
                     kind: "wire".to_string(),
                     width: Some(32),
+                    direction: None,
                 },
                 SignalEntry {
                     name: "wide/slash[0]".to_string(),
                     path: "top.wide/slash[0]".to_string(),
                     kind: "wire".to_string(),
                     width: Some(32),
+                    direction: None,
                 },
             ]
         );
@@ -1457,6 +1526,7 @@ mod tests {
                 path: "top.range.dot[31:0]".to_string(),
                 kind: "wire".to_string(),
                 width: Some(32),
+                direction: None,
             }]
         );
         assert!(index.signals_in_scope("top.range").is_err());
@@ -1483,12 +1553,14 @@ mod tests {
                     path: "top.arr[0]".to_string(),
                     kind: "wire".to_string(),
                     width: Some(1),
+                    direction: None,
                 },
                 SignalEntry {
                     name: "q_err".to_string(),
                     path: "top.q_err".to_string(),
                     kind: "wire".to_string(),
                     width: Some(1),
+                    direction: None,
                 },
             ]
         );
@@ -1994,6 +2066,7 @@ mod tests {
             name: name.to_string(),
             kind,
             hidden: false,
+            module: None,
         }
     }
 
@@ -2018,6 +2091,7 @@ mod tests {
             packed_component: false,
             datatype_id: None,
             value_encoding: FsdbValueEncoding::BitVector,
+            direction: None,
         }
     }
 
@@ -2037,6 +2111,7 @@ mod tests {
             packed_component: false,
             datatype_id: None,
             value_encoding: FsdbValueEncoding::BitVector,
+            direction: None,
         }
     }
 
@@ -563,6 +563,7 @@ unsafe fn handle_hierarchy_event(
                 name: unsafe { borrowed_c_string(scope.name)? },
                 kind: raw_scope_kind(scope.kind),
                 hidden: scope.hidden != 0,
+                module: unsafe { borrowed_optional_c_string(scope.module) },
             })?;
         }
         ffi::WP_FSDB_TREE_EVENT_SIGNAL => {
@@ -590,6 +591,7 @@ unsafe fn handle_hierarchy_event(
                 packed_component: signal.packed_component != 0,
                 datatype_id,
                 value_encoding: raw_value_encoding(signal.value_encoding),
+                direction: raw_signal_direction(signal.direction),
             })?;
         }
         ffi::WP_FSDB_TREE_EVENT_UPSCOPE => context.builder.upscope()?,
@@ -870,6 +872,17 @@ fn raw_value_encoding(encoding: c_uint) -> FsdbValueEncoding {
     }
 }
 
+fn raw_signal_direction(direction: c_uint) -> Option<&'static str> {
+    match direction {
+        ffi::WP_FSDB_SIGNAL_DIRECTION_INPUT => Some("input"),
+        ffi::WP_FSDB_SIGNAL_DIRECTION_OUTPUT => Some("output"),
+        ffi::WP_FSDB_SIGNAL_DIRECTION_INOUT => Some("inout"),
+        ffi::WP_FSDB_SIGNAL_DIRECTION_BUFFER => Some("buffer"),
+        ffi::WP_FSDB_SIGNAL_DIRECTION_LINKAGE => Some("linkage"),
+        _ => None,
+    }
+}
+
 mod ffi {
     use std::os::raw::{c_char, c_int, c_uint, c_void};
 
@@ -946,6 +959,11 @@ mod ffi {
 
     pub(super) const WP_FSDB_VALUE_ENCODING_BIT_VECTOR: c_uint = 0;
     pub(super) const WP_FSDB_VALUE_ENCODING_DATATYPE_CANDIDATE: c_uint = 2;
+    pub(super) const WP_FSDB_SIGNAL_DIRECTION_INPUT: c_uint = 1;
+    pub(super) const WP_FSDB_SIGNAL_DIRECTION_OUTPUT: c_uint = 2;
+    pub(super) const WP_FSDB_SIGNAL_DIRECTION_INOUT: c_uint = 3;
+    pub(super) const WP_FSDB_SIGNAL_DIRECTION_BUFFER: c_uint = 4;
+    pub(super) const WP_FSDB_SIGNAL_DIRECTION_LINKAGE: c_uint = 5;
 
     #[repr(C)]
     pub(super) struct wp_fsdb_reader {
@@ -970,6 +988,7 @@ mod ffi {
         pub(super) name: *const c_char,
         pub(super) kind: c_uint,
         pub(super) hidden: c_int,
+        pub(super) module: *const c_char,
     }
 
     #[repr(C)]
@@ -984,6 +1003,7 @@ mod ffi {
         pub(super) kind: c_uint,
         pub(super) packed_component: c_int,
         pub(super) value_encoding: c_uint,
+        pub(super) direction: c_uint,
     }
 
     #[repr(C)]
@@ -28,9 +28,10 @@ use crate::expr::SampledValue;
 
 #[allow(unused_imports)]
 pub(crate) use types::{
-    ChangeCandidateCollectionMode, EXCLUDED_SCOPE_KIND_ALIASES, EXCLUDED_SIGNAL_KIND_ALIASES,
-    ExprResolvedSignal, ResolvedSignal, STABLE_SCOPE_KIND_ALIASES, STABLE_SIGNAL_KIND_ALIASES,
-    SampledSignal, SampledSignalState, ScopeEntry, SignalEntry, SignalId, SignalOffsetData,
+    ChangeCandidateCollectionMode, DumpHeader, EXCLUDED_SCOPE_KIND_ALIASES,
+    EXCLUDED_SIGNAL_KIND_ALIASES, ExprResolvedSignal, ResolvedSignal, STABLE_SCOPE_KIND_ALIASES,
+    STABLE_SIGNAL_KIND_ALIASES, SampledSignal, SampledSignalState, ScopeEntry, ScopeSources,
+    SignalEntry, SignalId, SignalOffsetData, SignalTransitions, SourceLocation, ValueTransition,
     WaveformMetadata,
 };
 
@@ -132,6 +133,14 @@ impl Waveform {
         }
     }
 
+    pub fn header(&self) -> DumpHeader {
+        match &self.backend {
+            Backend::Wellen(backend) => backend.header(),
+            #[cfg(feature = "fsdb")]
+            Backend::Fsdb(backend) => backend.header(),
+        }
+    }
+
     pub fn scopes_depth_first(
         &self,
         max_depth: Option<usize>,
@@ -151,6 +160,34 @@ impl Waveform {
         }
     }
 
+    /// Whether `path` is a struct, union, or array scope whose members are addressed as
+    /// `path.member`.
+    pub(crate) fn is_aggregate_scope(&self, path: &str) -> bool {
+        match &self.backend {
+            Backend::Wellen(backend) => backend.is_aggregate_scope(path),
+            #[cfg(feature = "fsdb")]
+            Backend::Fsdb(backend) => backend.is_aggregate_scope(path),
+        }
+    }
+
+    /// Member signal paths of the aggregate at `path`, relative to it.
+    pub(crate) fn aggregate_fields(&self, path: &str) -> Result<Vec<String>, WavepeekError> {
+        match &self.backend {
+            Backend::Wellen(backend) => backend.aggregate_fields(path),
+            #[cfg(feature = "fsdb")]
+            Backend::Fsdb(backend) => backend.aggregate_fields(path),
+        }
+    }
+
+    /// Declaration and instantiation locations recorded on `scope_path` itself.
+    pub fn scope_sources(&self, scope_path: &str) -> Result<ScopeSources, WavepeekError> {
+        match &self.backend {
+            Backend::Wellen(backend) => backend.scope_sources(scope_path),
+            #[cfg(feature = "fsdb")]
+            Backend::Fsdb(backend) => backend.scope_sources(scope_path),
+        }
+    }
+
     pub fn signals_in_scope_recursive(
         &self,
         scope_path: &str,
@@ -214,6 +251,32 @@ impl Waveform {
         }
     }
 
+    /// Declared `[msb:lsb]` index range per canonical path, when the dump records one.
+    pub(crate) fn declared_index_ranges(
+        &self,
+        canonical_paths: &[String],
+    ) -> Result<Vec<Option<(i64, i64)>>, WavepeekError> {
+        match &self.backend {
+            Backend::Wellen(backend) => backend.declared_index_ranges(canonical_paths),
+            #[cfg(feature = "fsdb")]
+            Backend::Fsdb(backend) => backend.declared_index_ranges(canonical_paths),
+        }
+    }
+
+    /// Value at `from_raw` plus every recorded change in `(from_raw, to_raw]`, per signal.
+    pub(crate) fn signal_transitions(
+        &mut self,
+        resolved: &[ResolvedSignal],
+        from_raw: u64,
+        to_raw: u64,
+    ) -> Result<Vec<SignalTransitions>, WavepeekError> {
+        match &mut self.backend {
+            Backend::Wellen(backend) => backend.signal_transitions(resolved, from_raw, to_raw),
+            #[cfg(feature = "fsdb")]
+            Backend::Fsdb(backend) => backend.signal_transitions(resolved, from_raw, to_raw),
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn resolve_expr_signal(
         &self,
@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::expr::ExprType;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -27,11 +29,39 @@ pub struct WaveformMetadata {
     pub time_end: String,
 }
 
+/// Free-form provenance fields from the dump header, when present.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DumpHeader {
+    pub date: Option<String>,
+    /// Simulator name and version, such as the VCD `$version` text.
+    pub simulator: Option<String>,
+    /// Timescale declared by the header, such as the VCD `$timescale` text.
+    pub timescale: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ScopeEntry {
     pub path: String,
     pub depth: usize,
     pub kind: String,
+    /// Module or definition type name when the dump records one.
+    pub module: Option<String>,
+}
+
+/// HDL source file and line recorded in the dump for a declaration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u64,
+}
+
+/// Source locations a dump records on one scope.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopeSources {
+    /// Where the scope's module, block, or type is declared.
+    pub declaration: Option<SourceLocation>,
+    /// Where the parent instantiates this scope.
+    pub instantiation: Option<SourceLocation>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -40,6 +70,8 @@ pub struct SignalEntry {
     pub path: String,
     pub kind: String,
     pub width: Option<u32>,
+    /// Port direction (`input`, `output`, `inout`, `buffer`, `linkage`) when the dump records one.
+    pub direction: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -63,6 +95,22 @@ pub struct ResolvedSignal {
     pub width: u32,
 }
 
+/// Value history of one signal inside a raw time window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SignalTransitions {
+    /// Value at the window start, `None` before the first recorded value.
+    pub initial: Option<String>,
+    /// Changes after the window start, in time order.
+    pub changes: Vec<ValueTransition>,
+}
+
+/// All values a dump records at one timestamp; the last one is the settled value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ValueTransition {
+    pub time: u64,
+    pub values: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
 pub(crate) struct ExprResolvedSignal {
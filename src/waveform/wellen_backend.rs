@@ -17,8 +17,9 @@ use crate::expr::{
 };
 
 use super::types::{
-    ChangeCandidateCollectionMode, ExprResolvedSignal, ResolvedSignal, SampledSignalState,
-    ScopeEntry, SignalEntry, SignalId, SignalOffsetData, WaveformMetadata,
+    ChangeCandidateCollectionMode, DumpHeader, ExprResolvedSignal, ResolvedSignal,
+    SampledSignalState, ScopeEntry, ScopeSources, SignalEntry, SignalId, SignalOffsetData,
+    SignalTransitions, SourceLocation, ValueTransition, WaveformMetadata,
 };
 
 const STREAM_THRESHOLD_WORK: usize = 20_000;
@@ -87,6 +88,21 @@ impl WellenBackend {
         })
     }
 
+    pub fn header(&self) -> DumpHeader {
+        let hierarchy = self.inner.hierarchy();
+        let field = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+        DumpHeader {
+            date: field(hierarchy.date()),
+            simulator: field(hierarchy.version()),
+            timescale: hierarchy
+                .timescale()
+                .and_then(|timescale| format_timescale(timescale).ok()),
+        }
+    }
+
     pub fn scopes_depth_first(
         &self,
         max_depth: Option<usize>,
@@ -120,6 +136,48 @@ impl WellenBackend {
         Ok(signals)
     }
 
+    /// Struct, union, and array scopes, plus any scope carrying an FST `PACK`/`ARRAY` attribute.
+    pub fn is_aggregate_scope(&self, scope_path: &str) -> bool {
+        let hierarchy = self.inner.hierarchy();
+        let names: Vec<&str> = scope_path.split('.').collect();
+        hierarchy.lookup_scope(&names).is_some_and(|scope_ref| {
+            let scope = &hierarchy[scope_ref];
+            matches!(
+                scope.scope_type(),
+                ScopeType::Struct | ScopeType::Union | ScopeType::SvArray | ScopeType::VhdlArray
+            ) || scope.pack_info().is_some()
+        })
+    }
+
+    /// Signal paths below `scope_path` relative to it, in declaration order.
+    pub fn aggregate_fields(&self, scope_path: &str) -> Result<Vec<String>, WavepeekError> {
+        let hierarchy = self.inner.hierarchy();
+        let names: Vec<&str> = scope_path.split('.').collect();
+        let scope_ref = hierarchy.lookup_scope(&names).ok_or_else(|| {
+            WavepeekError::Scope(format!("scope '{scope_path}' not found in dump"))
+        })?;
+        let mut fields = Vec::new();
+        collect_declared_fields(hierarchy, scope_ref, "", &mut fields);
+        Ok(fields)
+    }
+
+    pub fn scope_sources(&self, scope_path: &str) -> Result<ScopeSources, WavepeekError> {
+        let hierarchy = self.inner.hierarchy();
+        let names: Vec<&str> = scope_path.split('.').collect();
+        let scope_ref = hierarchy.lookup_scope(&names).ok_or_else(|| {
+            WavepeekError::Scope(format!("scope '{scope_path}' not found in dump"))
+        })?;
+        let location = |(file, line): (&str, u64)| SourceLocation {
+            file: file.to_string(),
+            line,
+        };
+        let scope = &hierarchy[scope_ref];
+        Ok(ScopeSources {
+            declaration: scope.source_loc(hierarchy).map(location),
+            instantiation: scope.instantiation_source_loc(hierarchy).map(location),
+        })
+    }
+
     pub fn signals_in_scope_recursive(
         &self,
         scope_path: &str,
@@ -168,6 +226,94 @@ impl WellenBackend {
             .collect()
     }
 
+    pub(crate) fn declared_index_ranges(
+        &self,
+        canonical_paths: &[String],
+    ) -> Result<Vec<Option<(i64, i64)>>, WavepeekError> {
+        let hierarchy = self.inner.hierarchy();
+        canonical_paths
+            .iter()
+            .map(|path| {
+                let var_ref = resolve_var_ref(hierarchy, path.as_str())?;
+                Ok(hierarchy[var_ref]
+                    .index()
+                    .map(|index| (index.msb(), index.lsb())))
+            })
+            .collect()
+    }
+
+    pub(crate) fn signal_transitions(
+        &mut self,
+        resolved: &[ResolvedSignal],
+        from_raw: u64,
+        to_raw: u64,
+    ) -> Result<Vec<SignalTransitions>, WavepeekError> {
+        let signal_refs = resolved
+            .iter()
+            .map(|signal| signal_ref(signal.id))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.ensure_signals_loaded(&signal_refs);
+        let time_table = self.inner.time_table();
+        let initial_idx = floor_time_table_index(time_table, from_raw)
+            .map(|index| {
+                u32::try_from(index).map_err(|_| {
+                    WavepeekError::Internal("time table index exceeds u32 range".to_string())
+                })
+            })
+            .transpose()?;
+
+        let mut transitions = Vec::with_capacity(resolved.len());
+        for (signal, signal_ref) in resolved.iter().zip(signal_refs) {
+            let loaded = self.inner.get_signal(signal_ref).ok_or_else(|| {
+                WavepeekError::Internal(format!(
+                    "signal '{}' could not be loaded from waveform backend",
+                    signal.path
+                ))
+            })?;
+
+            let initial = match initial_idx.and_then(|index| loaded.get_offset(index)) {
+                Some(offset) => decode_signal_bits(
+                    loaded.get_value_at(&offset, offset.elements - 1),
+                    signal.path.as_str(),
+                )?,
+                None => None,
+            };
+
+            let mut changes = Vec::new();
+            let mut last_time_idx = None;
+            for &time_idx in loaded.time_indices() {
+                // Repeated writes share one index entry per write; the offset already holds all.
+                if last_time_idx.replace(time_idx) == Some(time_idx) {
+                    continue;
+                }
+                let time = time_table[time_idx as usize];
+                if time <= from_raw {
+                    continue;
+                }
+                if time > to_raw {
+                    break;
+                }
+                let Some(offset) = loaded.get_offset(time_idx) else {
+                    continue;
+                };
+                let mut values = Vec::with_capacity(usize::from(offset.elements));
+                for element in 0..offset.elements {
+                    if let Some(bits) = decode_signal_bits(
+                        loaded.get_value_at(&offset, element),
+                        signal.path.as_str(),
+                    )? {
+                        values.push(bits);
+                    }
+                }
+                changes.push(ValueTransition { time, values });
+            }
+
+            transitions.push(SignalTransitions { initial, changes });
+        }
+
+        Ok(transitions)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn resolve_expr_signal(
         &self,
@@ -926,6 +1072,7 @@ fn collect_scope_entries(
         path: scope.full_name(hierarchy),
         depth,
         kind: scope_type_alias(scope.scope_type()).to_string(),
+        module: scope.component(hierarchy).map(str::to_string),
     });
 
     if max_depth == Some(depth) {
@@ -971,6 +1118,25 @@ fn collect_scope_signals(
     }
 }
 
+fn collect_declared_fields(
+    hierarchy: &wellen::Hierarchy,
+    scope_ref: ScopeRef,
+    prefix: &str,
+    fields: &mut Vec<String>,
+) {
+    for item in hierarchy[scope_ref].items(hierarchy) {
+        match item {
+            wellen::ItemRef::Var(var_ref) => {
+                fields.push(format!("{prefix}{}", hierarchy[var_ref].name(hierarchy)));
+            }
+            wellen::ItemRef::Scope(child) => {
+                let prefix = format!("{prefix}{}.", hierarchy[child].name(hierarchy));
+                collect_declared_fields(hierarchy, child, prefix.as_str(), fields);
+            }
+        }
+    }
+}
+
 fn signal_entry_from_var_ref(
     hierarchy: &wellen::Hierarchy,
     var_ref: wellen::VarRef,
@@ -981,6 +1147,18 @@ fn signal_entry_from_var_ref(
         path: var.full_name(hierarchy),
         kind: var_type_alias(var.var_type()).to_string(),
         width: var.length(hierarchy),
+        direction: var_direction_alias(var.direction()).map(str::to_string),
+    }
+}
+
+fn var_direction_alias(direction: wellen::VarDirection) -> Option<&'static str> {
+    match direction {
+        wellen::VarDirection::Input => Some("input"),
+        wellen::VarDirection::Output => Some("output"),
+        wellen::VarDirection::InOut => Some("inout"),
+        wellen::VarDirection::Buffer => Some("buffer"),
+        wellen::VarDirection::Linkage => Some("linkage"),
+        wellen::VarDirection::Unknown | wellen::VarDirection::Implicit => None,
     }
 }
 
@@ -1197,22 +1375,26 @@ mod tests {
                 ScopeEntry {
                     path: "top".to_string(),
                     depth: 0,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
                 ScopeEntry {
                     path: "top.cpu".to_string(),
                     depth: 1,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
                 ScopeEntry {
                     path: "top.helper".to_string(),
                     depth: 1,
-                    kind: "function".to_string()
+                    kind: "function".to_string(),
+                    module: None,
                 },
                 ScopeEntry {
                     path: "top.mem".to_string(),
                     depth: 1,
-                    kind: "module".to_string()
+                    kind: "module".to_string(),
+                    module: None,
                 },
             ]
         );
@@ -0,0 +1,182 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::NamedTempFile;
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+// `rst_n` rises at 12ns and `clk` rises at 5/15/25/35/45/55ns. At the second edge after reset
+// (25ns) `q` and `cnt` are still unknown, `dbg` floats forever, and `nv` never gets a value.
+const AUDIT_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 1 \" rst_n $end\n",
+    "$var reg 1 # q $end\n",
+    "$var wire 1 $ dbg $end\n",
+    "$var wire 1 % nv $end\n",
+    "$scope module u_fifo $end\n",
+    "$var reg 4 & cnt $end\n",
+    "$var reg 8 ' data $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\nx#\nz$\nbxxxx &\nb00000000 '\n",
+    "#5\n1!\n",
+    "#10\n0!\n",
+    "#12\n1\"\n",
+    "#15\n1!\n",
+    "#20\n0!\nb00xx &\n",
+    "#25\n1!\n",
+    "#30\n0!\n1#\n",
+    "#35\n1!\n",
+    "#40\n0!\nb0001 &\n",
+    "#45\n1!\n",
+    "#50\n0!\n",
+    "#55\n1!\n",
+    "#60\n0!\n",
+);
+
+const EVENT_ARGS: [&str; 6] = [
+    "--scope",
+    "top",
+    "--reset",
+    "posedge rst_n",
+    "--clock",
+    "posedge clk",
+];
+
+#[test]
+fn audit_reports_unknown_signals_grouped_by_scope() {
+    let fixture = write_fixture(AUDIT_VCD, ".audit-rows.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["audit", "--waves", waves])
+        .args(EVENT_ARGS)
+        .args(["--cycles", "2"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "info: sampled 7 signal(s) under 'top' at 25ns, 2 --clock event(s) after reset at 12ns",
+        ))
+        .stdout(concat!(
+            "top:\n",
+            "  dbg 1'hz unknown_bits=1 first_known=never\n",
+            "  nv (no value) unknown_bits=1 first_known=never\n",
+            "  q 1'hx unknown_bits=1 first_known=@30ns\n",
+            "top.u_fifo:\n",
+            "  cnt 4'hx unknown_bits=2 first_known=@40ns\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args(["audit", "--waves", waves])
+        .args(EVENT_ARGS)
+        .args(["--cycles", "2", "--json"])
+        .output()
+        .expect("audit should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["command"], "audit");
+    assert_eq!(value["data"].as_array().map(Vec::len), Some(4));
+    assert_eq!(
+        value["data"][3],
+        serde_json::json!({
+            "time": "25ns",
+            "cycle": 3,
+            "scope": "top.u_fifo",
+            "name": "cnt",
+            "path": "top.u_fifo.cnt",
+            "value": "4'hx",
+            "unknown_bits": 2,
+            "first_known": "40ns",
+        })
+    );
+    assert_eq!(value["data"][1]["value"], Value::Null);
+}
+
+#[test]
+fn audit_sample_point_follows_cycles() {
+    let fixture = write_fixture(AUDIT_VCD, ".audit-later.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["audit", "--waves", waves])
+        .args(EVENT_ARGS)
+        .args(["--cycles", "3", "--abs"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "top:\n",
+            "  top.dbg 1'hz unknown_bits=1 first_known=never\n",
+            "  top.nv (no value) unknown_bits=1 first_known=never\n",
+            "top.u_fifo:\n",
+            "  top.u_fifo.cnt 4'hx unknown_bits=2 first_known=@40ns\n",
+        ));
+
+    wavepeek_cmd()
+        .args(["audit", "--waves", waves])
+        .args(EVENT_ARGS)
+        .args(["--cycles", "0", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""time":"12ns","cycle":1,"scope":"top.u_fifo","name":"cnt","path":"top.u_fifo.cnt","value":"4'hx","unknown_bits":4"#));
+}
+
+#[test]
+fn audit_rejects_missing_reset_and_too_few_cycles() {
+    let fixture = write_fixture(AUDIT_VCD, ".audit-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["audit", "--waves", waves])
+        .args(EVENT_ARGS)
+        .args(["--from", "20ns"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--reset 'posedge rst_n' matched no events in the selected time range",
+        ));
+
+    wavepeek_cmd()
+        .args(["audit", "--waves", waves])
+        .args(EVENT_ARGS)
+        .args(["--cycles", "9"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--clock 'posedge clk' matched 5 events after reset at 12ns in the selected time range, fewer than --cycles 9",
+        ));
+}
+
+#[test]
+fn audit_renders_times_in_time_unit_with_ticks() {
+    let fixture = write_fixture(AUDIT_VCD, ".audit-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let output = wavepeek_cmd()
+        .args(["audit", "--waves", waves])
+        .args(EVENT_ARGS)
+        .args(["--cycles", "2", "--time-unit", "ps", "--ticks", "--json"])
+        .output()
+        .expect("audit should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["data"][3]["time"], "25000ps");
+    assert_eq!(value["data"][3]["tick"], 25);
+    assert_eq!(value["data"][3]["first_known"], "40000ps");
+    assert!(
+        value["diagnostics"][0]["message"].as_str().is_some_and(
+            |message| message.contains("at 25000ps, 2 --clock event(s) after reset at 12000ps")
+        )
+    );
+}
@@ -0,0 +1,232 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::NamedTempFile;
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+// clk_a rises at 3/17/31ns and clk_b at 10/20/30/40ns; `req` changes 1ns before the 10ns
+// clk_b edge and `cnt` jumps 4'h1 -> 4'h6 (three bits) between the 10ns and 20ns samples.
+const CDC_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk_a $end\n",
+    "$var wire 1 \" clk_b $end\n",
+    "$var wire 1 # req $end\n",
+    "$var wire 4 $ cnt $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\n0#\nb0000 $\n",
+    "#3\n1!\n",
+    "#4\nb0001 $\n",
+    "#9\n1#\n",
+    "#10\n0!\n1\"\n",
+    "#14\nb0110 $\n",
+    "#15\n0\"\n",
+    "#17\n1!\n",
+    "#20\n1\"\n",
+    "#24\n0!\n",
+    "#25\n0\"\n0#\n",
+    "#30\n1\"\n",
+    "#31\n1!\n",
+    "#35\n0\"\n",
+    "#36\nb0111 $\n",
+    "#38\n0!\n",
+    "#40\n1\"\n",
+);
+
+const CLOCK_ARGS: [&str; 6] = [
+    "--scope",
+    "top",
+    "--src-clock",
+    "posedge clk_a",
+    "--dst-clock",
+    "posedge clk_b",
+];
+
+#[test]
+fn cdc_reports_window_and_multi_bit_hazards() {
+    let fixture = write_fixture(CDC_VCD, ".cdc-hazards.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["cdc", "--waves", waves])
+        .args(CLOCK_ARGS)
+        .args(["--signals", "req,cnt", "--window", "2ns"])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(concat!(
+            "@9ns window req 1'h0->1'h1 dst@10ns offset=-1ns src@3ns\n",
+            "@20ns multi_bit cnt 4'h1->4'h6 bits=3 src@3ns\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args(["cdc", "--waves", waves])
+        .args(CLOCK_ARGS)
+        .args(["--signals", "req,cnt", "--window", "2ns", "--json"])
+        .output()
+        .expect("cdc should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["command"], "cdc");
+    assert_eq!(
+        value["data"][0],
+        serde_json::json!({
+            "time": "9ns",
+            "kind": "window",
+            "path": "top.req",
+            "previous": "1'h0",
+            "value": "1'h1",
+            "dst_edge": "10ns",
+            "offset": "-1ns",
+            "src_edge": "3ns",
+        })
+    );
+    assert_eq!(value["data"][1]["kind"], "multi_bit");
+    assert_eq!(value["data"][1]["bits_changed"], 3);
+    assert!(value["data"][1].get("offset").is_none());
+}
+
+#[test]
+fn cdc_bounds_rows_by_range_and_warns_when_empty() {
+    let fixture = write_fixture(CDC_VCD, ".cdc-range.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["cdc", "--waves", waves])
+        .args(CLOCK_ARGS)
+        .args(["--signals", "req,cnt", "--window", "2ns", "--from", "21ns"])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "no CDC hazards found in selected time range",
+        ));
+}
+
+#[test]
+fn cdc_rejects_wildcard_clocks_and_inexact_windows() {
+    let fixture = write_fixture(CDC_VCD, ".cdc-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "cdc",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--src-clock",
+            "posedge clk_a",
+            "--dst-clock",
+            "*",
+            "--signals",
+            "req",
+            "--window",
+            "1ns",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--dst-clock '*' must name its clock signal instead of using '*'",
+        ));
+
+    wavepeek_cmd()
+        .args(["cdc", "--waves", waves])
+        .args(CLOCK_ARGS)
+        .args(["--signals", "req", "--window", "1500ps"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid --window '1500ps': cannot be represented exactly in dump precision '1ns'",
+        ));
+}
+
+#[test]
+fn cdc_reports_repeated_writes_at_one_timestamp_once() {
+    let fixture = write_fixture(
+        concat!(
+            "$timescale 1ns $end\n",
+            "$scope module top $end\n",
+            "$var wire 1 ! clk_a $end\n",
+            "$var wire 1 \" clk_b $end\n",
+            "$var wire 1 # req $end\n",
+            "$upscope $end\n",
+            "$enddefinitions $end\n",
+            "#0\n0!\n0\"\n0#\n",
+            "#3\n1!\n",
+            "#9\n1#\n0#\n1#\n",
+            "#10\n1\"\n",
+            "#11\n0#\n0#\n",
+        ),
+        ".cdc-repeated.vcd",
+    );
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["cdc", "--waves", waves])
+        .args(CLOCK_ARGS)
+        .args(["--signals", "req", "--window", "2ns"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@9ns window req 1'h0->1'h1 dst@10ns offset=-1ns src@3ns\n",
+            "@11ns window req 1'h1->1'h0 dst@10ns offset=1ns src@3ns\n",
+        ));
+}
+
+#[test]
+fn cdc_renders_times_and_offsets_in_time_unit_with_ticks() {
+    let fixture = write_fixture(CDC_VCD, ".cdc-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let output = wavepeek_cmd()
+        .args(["cdc", "--waves", waves])
+        .args(CLOCK_ARGS)
+        .args(["--signals", "req", "--window", "2ns"])
+        .args(["--time-unit", "ps", "--ticks", "--json"])
+        .output()
+        .expect("cdc should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"][0],
+        serde_json::json!({
+            "time": "9000ps",
+            "tick": 9,
+            "kind": "window",
+            "path": "top.req",
+            "previous": "1'h0",
+            "value": "1'h1",
+            "dst_edge": "10000ps",
+            "offset": "-1000ps",
+            "src_edge": "3000ps",
+        })
+    );
+}
+
+#[test]
+fn cdc_clock_numbers_cycles_in_rows_and_time_tokens() {
+    let fixture = write_fixture(CDC_VCD, ".cdc-cycles.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["cdc", "--waves", waves])
+        .args(CLOCK_ARGS)
+        .args(["--signals", "req,cnt", "--window", "2ns"])
+        .args(["--clock", "posedge clk_b", "--from", "1cyc"])
+        .assert()
+        .success()
+        .stderr("info: --from time expression '1cyc' resolved to 10ns\n")
+        .stdout("@20ns cycle=2 multi_bit cnt 4'h1->4'h6 bits=3 src@3ns\n");
+}
@@ -2184,3 +2184,678 @@ fn change_runtime_manifest_cases_pass() {
         }
     }
 }
+
+#[test]
+fn change_wave_draws_one_cell_group_per_row() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, "change-wave.vcd");
+
+    let mut command = wavepeek_cmd();
+    command
+        .args([
+            "change",
+            "--waves",
+            fixture.path().to_str().expect("utf-8 path"),
+            "--scope",
+            "top",
+            "--signals",
+            "valid,data",
+            "--on",
+            "posedge clk",
+            "--wave",
+            "--width",
+            "50",
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "      |15ns       |35ns\n",
+            "valid ▔▔▔▔▔▔▔▔▔▔▔▔╲▁▁▁▁▁▁▁▁▁▁▁\n",
+            "data  <aa        ><55        >\n",
+        ))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn change_wave_rejects_json_and_requires_wave_for_width() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, "change-wave-errors.vcd");
+
+    for extra in [["--wave", "--json"], ["--width", "40"]] {
+        let mut command = wavepeek_cmd();
+        command
+            .args([
+                "change",
+                "--waves",
+                fixture.path().to_str().expect("utf-8 path"),
+                "--signals",
+                "top.data",
+                "--on",
+                "posedge top.clk",
+            ])
+            .args(extra)
+            .assert()
+            .failure()
+            .code(1)
+            .stderr(predicate::str::starts_with("fatal: args:"));
+    }
+}
+
+const SLICE_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 16 ! data [15:0] $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n",
+    "b0000000011110000 !\n",
+    "#10\n",
+    "b0000000011110001 !\n",
+    "#20\n",
+    "b0000000111110001 !\n",
+    "#30\n",
+    "b0000001011110001 !\n",
+    "#40\n",
+    "b0000001011110010 !\n",
+);
+
+#[test]
+fn change_bit_selects_fire_only_when_the_selected_slice_changes() {
+    let fixture = write_fixture(SLICE_VCD, "change-slice.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let output = wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data[15:8]",
+            "--on",
+            "*",
+            "--sample-mode",
+            "native",
+            "--json",
+        ])
+        .output()
+        .expect("change should run");
+    assert!(output.status.success());
+    let value = parse_json(&output.stdout);
+    assert_eq!(
+        value["data"],
+        json!([
+            {"time": "20ns", "sample_time": "20ns", "signals": [{"path": "top.data[15:8]", "value": "8'h01"}]},
+            {"time": "30ns", "sample_time": "30ns", "signals": [{"path": "top.data[15:8]", "value": "8'h02"}]}
+        ])
+    );
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data[8+:8],data[0]",
+            "--on",
+            "*",
+            "--sample-mode",
+            "native",
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@10ns data[8+:8]=8'h00 data[0]=1'h1\n",
+            "@20ns data[8+:8]=8'h01 data[0]=1'h1\n",
+            "@30ns data[8+:8]=8'h02 data[0]=1'h1\n",
+            "@40ns data[8+:8]=8'h02 data[0]=1'h0\n",
+        ))
+        .stderr(predicate::str::is_empty());
+}
+
+const STRUCT_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$scope struct pkt $end\n",
+    "$scope struct hdr $end\n",
+    "$var logic 4 \" len $end\n",
+    "$var logic 1 # valid $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n",
+    "0!\n",
+    "b0011 \"\n",
+    "0#\n",
+    "#5\n",
+    "1!\n",
+    "#10\n",
+    "0!\n",
+    "b0100 \"\n",
+    "1#\n",
+    "#15\n",
+    "1!\n",
+    "#20\n",
+    "0!\n",
+    "b0101 \"\n",
+    "#25\n",
+    "1!\n",
+);
+
+#[test]
+fn change_scoped_struct_fields_resolve_in_signals_and_expressions() {
+    let fixture = write_fixture(STRUCT_VCD, "change-struct.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "pkt.hdr.len",
+            "--on",
+            "posedge clk iff pkt.hdr.valid",
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@15ns sample@14ns pkt.hdr.len=4'h4\n",
+            "@25ns sample@24ns pkt.hdr.len=4'h5\n",
+        ))
+        .stderr(predicate::str::is_empty());
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "pkt.hdr.len",
+            "--on",
+            "posedge clk iff pkt.hdr",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "'top.pkt.hdr' is a struct or union; reference one of its fields as 'top.pkt.hdr.<field>'",
+        ));
+}
+
+const DESC_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 16 \" desc $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nb0001001000001100 \"\n",
+    "#10\n1!\n",
+    "#15\n0!\nb0011010000010101 \"\n",
+    "#20\n1!\n",
+    "#25\n0!\nb0101011000000110 \"\n",
+    "#30\n1!\n",
+);
+
+fn layout_json(signal: &str, addr_msb: u32) -> String {
+    json!({
+        "$schema": common::expected_input_schema_url(),
+        "kind": "signal.layouts",
+        "layouts": [{
+            "signal": signal,
+            "fields": [
+                {"name": "addr", "msb": addr_msb, "lsb": 8},
+                {"name": "len", "msb": 7, "lsb": 2},
+                {"name": "op", "msb": 1, "lsb": 0, "enum": {"0": "READ", "0x1": "WRITE"}}
+            ]
+        }]
+    })
+    .to_string()
+}
+
+#[test]
+fn change_layout_fields_decode_rows_and_resolve_in_expressions() {
+    let fixture = write_fixture(DESC_VCD, "change-layout.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let layout = write_fixture(layout_json("top.des?", 15).as_str(), "layout.json");
+    let layout_path = layout.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc",
+            "--on",
+            "posedge clk iff desc.op == 1 || desc.addr == 8'h56",
+            "--layout",
+            layout_path,
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@20ns sample@19ns desc=16'h3415 '{addr:8'h34,len:6'h05,op:WRITE}\n",
+            "@30ns sample@29ns desc=16'h5606 '{addr:8'h56,len:6'h01,op:2'h2}\n",
+        ))
+        .stderr(predicate::str::is_empty());
+
+    let output = wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--signals",
+            "top.desc",
+            "--on",
+            "top.desc.op",
+            "--sample-mode",
+            "native",
+            "--layout",
+            layout_path,
+            "--json",
+        ])
+        .output()
+        .expect("change should run");
+    assert!(output.status.success());
+    let value = parse_json(&output.stdout);
+    assert_eq!(value["data"][0]["time"], "15ns");
+    assert_eq!(
+        value["data"][0]["signals"][0]["fields"],
+        json!({"addr": "8'h34", "len": "6'h05", "op": "WRITE"})
+    );
+    assert_eq!(value["data"].as_array().map(Vec::len), Some(2));
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc",
+            "--on",
+            "posedge clk iff desc.op == 1",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("unknown signal 'desc.op'"));
+}
+
+#[test]
+fn change_time_expressions_anchor_windows_to_events_and_offsets() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, "change-time-expr.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--sample-mode",
+            "native",
+            "--from",
+            "first(posedge valid)",
+            "--to",
+            "+20ns",
+        ])
+        .assert()
+        .success()
+        .stdout("@25ns data=8'h55\n")
+        .stderr(concat!(
+            "info: --from time expression 'first(posedge valid)' resolved to 5ns\n",
+            "info: --to time expression '+20ns' resolved to 25ns\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--signals",
+            "top.data",
+            "--on",
+            "top.data",
+            "--sample-mode",
+            "native",
+            "--from",
+            "end-35ns",
+            "--to",
+            "nth(2, posedge top.clk)",
+            "--json",
+        ])
+        .output()
+        .expect("change should run");
+    assert!(output.status.success());
+    let value = parse_json(&output.stdout);
+    assert_eq!(value["data"].as_array().map(Vec::len), Some(1));
+    assert_eq!(value["data"][0]["time"], "5ns");
+    assert_eq!(
+        value["diagnostics"][1]["message"],
+        "--to time expression 'nth(2, posedge top.clk)' resolved to 15ns"
+    );
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--from",
+            "last(negedge valid)+20ns",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "time expression 'last(negedge valid)+20ns' for --from resolves to 45ns, outside dump bounds [0ns, 35ns]",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--from",
+            "start-5ns",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "time expression 'start-5ns' for --from resolves before time 0, outside dump bounds [0ns, 35ns]",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--to",
+            "nth(9, posedge clk)",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "needs 9 matches of 'posedge clk' but the dump has 4",
+        ));
+}
+
+#[test]
+fn change_clock_cycles_resolve_time_tokens_and_tag_rows() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, "change-clock-cycles.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--sample-mode",
+            "native",
+            "--clock",
+            "posedge clk",
+            "--from",
+            "2cyc",
+            "--to",
+            "+2cyc",
+        ])
+        .assert()
+        .success()
+        .stdout("@25ns cycle=3 data=8'h55\n")
+        .stderr(concat!(
+            "info: --from time expression '2cyc' resolved to 15ns\n",
+            "info: --to time expression '+2cyc' resolved to 35ns\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--signals",
+            "top.data",
+            "--on",
+            "top.data",
+            "--sample-mode",
+            "native",
+            "--clock",
+            "negedge top.clk",
+            "--json",
+        ])
+        .output()
+        .expect("change should run");
+    assert!(output.status.success());
+    let value = parse_json(&output.stdout);
+    assert_eq!(value["data"][0]["time"], "5ns");
+    assert!(value["data"][0].get("cycle").is_none());
+    assert_eq!(value["data"][1]["time"], "25ns");
+    assert_eq!(value["data"][1]["cycle"], 2);
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--clock",
+            "posedge clk",
+            "--from",
+            "5cyc",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "cycle 5 is outside cycles 1..4 of --clock 'posedge clk'",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--to",
+            "end-1cyc",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "cycle units need a --clock reference clock",
+        ));
+}
+
+#[test]
+fn change_time_unit_and_ticks_reformat_row_times() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, "change-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--time-unit",
+            "us",
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@0.015us sample@0.014us data=8'haa\n",
+            "@0.035us sample@0.034us data=8'h55\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "change",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data",
+            "--on",
+            "posedge clk",
+            "--sample-mode",
+            "native",
+            "--time-unit",
+            "ps",
+            "--ticks",
+            "--json",
+        ])
+        .output()
+        .expect("change should run");
+    assert!(output.status.success());
+    let value = parse_json(&output.stdout);
+    assert_eq!(value["data"][0]["time"], "5000ps");
+    assert_eq!(value["data"][0]["tick"], 5);
+    assert_eq!(value["data"][0]["sample_time"], "5000ps");
+}
+
+#[test]
+fn change_sample_mode_post_edge_and_skew_move_the_sample_point() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, "change-sample-skew.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let run = |mode: &str| {
+        wavepeek_cmd()
+            .args([
+                "change",
+                "--waves",
+                waves,
+                "--scope",
+                "top",
+                "--signals",
+                "data",
+                "--on",
+                "posedge clk",
+                "--sample-mode",
+                mode,
+            ])
+            .assert()
+    };
+
+    run("post-edge")
+        .success()
+        .stdout("@5ns data=8'haa\n@25ns data=8'h55\n");
+    run("skew:6ns").success().stdout(concat!(
+        "@15ns sample@9ns data=8'haa\n",
+        "@35ns sample@29ns data=8'h55\n",
+    ));
+    run("skew:1.5ns").failure().stderr(predicate::str::contains(
+        "invalid --sample-mode skew '1.5ns'",
+    ));
+
+    wavepeek_cmd()
+        .args([
+            "extract",
+            "generic",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk",
+            "--when",
+            "valid",
+            "--payload",
+            "data",
+            "--sample-mode",
+            "native",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "use --sample-mode post-edge instead of native",
+        ));
+}
+
+#[test]
+fn change_time_event_terms_sample_a_grid_and_explicit_times() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, "change-time-events.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let run = |on: &str| {
+        wavepeek_cmd()
+            .args([
+                "change",
+                "--waves",
+                waves,
+                "--scope",
+                "top",
+                "--signals",
+                "data",
+                "--on",
+                on,
+                "--sample-mode",
+                "native",
+            ])
+            .assert()
+    };
+
+    run("every 4ns")
+        .success()
+        .stdout("@8ns data=8'haa\n@28ns data=8'h55\n");
+    run("every 10ns phase 3ns iff valid")
+        .success()
+        .stdout("@13ns data=8'haa\n");
+    run("at 7ns, 26ns, timestamps iff valid")
+        .success()
+        .stdout("@5ns data=8'haa\n@26ns data=8'h55\n");
+    run("every 1500ps")
+        .failure()
+        .stderr(predicate::str::contains("invalid event time '1500ps'"));
+}
@@ -4,9 +4,9 @@ use predicates::prelude::*;
 mod common;
 use common::wavepeek_cmd;
 
-const VISIBLE_TOP_LEVEL_COMMANDS: [&str; 11] = [
-    "info", "scope", "signal", "value", "change", "property", "extract", "schema", "docs", "skill",
-    "help",
+const VISIBLE_TOP_LEVEL_COMMANDS: [&str; 19] = [
+    "info", "scope", "signal", "value", "change", "property", "cdc", "timing", "glitch", "audit",
+    "clock", "view", "saif", "extract", "export", "schema", "docs", "skill", "help",
 ];
 
 #[cfg(feature = "fsdb")]
@@ -625,7 +625,7 @@ fn info_help_uses_aligned_summary_and_simple_option_docs() {
         );
     }
 
-    assert!(long_help.contains("Behavior:\n- Prints available metadata (e.g. time unit, start/end times, etc.) in free form\n- `--json` uses the machine contract defined by `wavepeek schema`."));
+    assert!(long_help.contains("Behavior:\n- Prints available metadata (e.g. time unit, start/end times, etc.) in free form\n- Also reports file format, backend, scope/signal/timestamp counts, and header date/simulator/timescale when recorded\n- `--activity [N]` adds the N nets with the most value changes (default 10), each net listed once\n- `--json` uses the machine contract defined by `wavepeek schema`."));
     assert!(!short_help.contains("See also:"));
     for help in [&long_help, &alias_help] {
         assert!(help.contains("See also:\n  wavepeek docs show commands/info"));
@@ -659,7 +659,7 @@ fn scope_help_uses_aligned_summary_behavior_and_simple_option_docs() {
     }
 
     assert!(long_help.contains(
-        "Behavior:\n- Finds all scopes matching `--filter` and displays scope name, depth, and kind."
+        "Behavior:\n- Finds all scopes matching `--filter` and displays scope name, depth, kind, and module type when the dump records one."
     ));
     assert!(long_help.contains(
         "Includes stable scope kind aliases from hierarchy data (not only modules); excluded backend-specific spellings are normalized to the stable contract surface."
@@ -709,7 +709,7 @@ fn signal_help_uses_aligned_summary_behavior_and_simple_option_docs() {
     }
 
     assert!(long_help.contains(
-        "Behavior:\n- Finds all signals matching `--filter` within the selected scope and displays name, kind, and available metadata (for example width)."
+        "Behavior:\n- Finds all signals matching `--filter` within the selected scope and displays name, kind, and available metadata (for example width and port direction)."
     ));
     assert!(long_help.contains("Recursive mode walks child scopes depth-first in stable lexicographic order; `--max-depth` limits recursion when set."));
     assert!(long_help.contains(
@@ -1114,7 +1114,7 @@ fn extract_generic_help_is_self_descriptive() {
     for fragment in [
         "Extract protocol-neutral event rows from waveform signals.",
         "Selects edge-only event timestamps with --on.",
-        "Always samples --when and --payload at the pre-edge sample point.",
+        "Samples --when and --payload at the pre-edge sample point unless --sample-mode selects post-edge or skew:<time>.",
         "In source-file mode, --source provides one or more sources",
         "Contract for source-file mode is defined by `wavepeek schema --input`.",
         "JSON and JSONL rows include time, sample_time, source, and ordered payload values.",
@@ -0,0 +1,218 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::NamedTempFile;
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+// `clk` runs at 10ns until 35ns, stays low until 80ns, then resumes at a 20ns period.
+const CLOCK_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 2 \" sel $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n1!\nb00 \"\n",
+    "#5\n0!\n",
+    "#10\n1!\n",
+    "#15\n0!\n",
+    "#20\n1!\n",
+    "#25\n0!\n",
+    "#30\n1!\n",
+    "#35\n0!\n",
+    "#80\n1!\nb01 \"\n",
+    "#90\n0!\n",
+    "#100\n1!\n",
+    "#110\n0!\n",
+    "#120\n1!\n",
+    "#130\n0!\n",
+);
+
+#[test]
+fn clock_reports_period_duty_gating_and_frequency_changes() {
+    let fixture = write_fixture(CLOCK_VCD, ".clock-report.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "clock", "--waves", waves, "--scope", "top", "--signal", "clk",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(concat!(
+            "signal: clk\n",
+            "rising_edges: 6\n",
+            "falling_edges: 7\n",
+            "period: nominal=10ns min=10ns max=20ns mean=15ns\n",
+            "frequency: 100MHz\n",
+            "duty_cycle: min=50% max=50% mean=50%\n",
+            "gated: @35ns..@80ns duration=45ns level=1'h0\n",
+            "frequency_change: @80ns 10ns->20ns 100MHz->50MHz\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args(["clock", "--waves", waves, "--signal", "top.clk", "--json"])
+        .output()
+        .expect("clock should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["command"], "clock");
+    assert_eq!(value["data"]["path"], "top.clk");
+    assert_eq!(
+        value["data"]["period"],
+        serde_json::json!({"nominal": "10ns", "min": "10ns", "max": "20ns", "mean": "15ns"})
+    );
+    assert_eq!(
+        value["data"]["frequency_changes"][0],
+        serde_json::json!({
+            "time": "80ns",
+            "previous_period": "10ns",
+            "period": "20ns",
+            "previous_frequency": "100MHz",
+            "frequency": "50MHz",
+        })
+    );
+}
+
+#[test]
+fn clock_reports_open_gate_when_clock_stays_stopped() {
+    let fixture = write_fixture(CLOCK_VCD, ".clock-open.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "clock", "--waves", waves, "--scope", "top", "--signal", "clk",
+        ])
+        .args(["--to", "60ns"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "gated: @35ns.. duration=25ns level=1'h0\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "clock", "--waves", waves, "--scope", "top", "--signal", "clk",
+        ])
+        .args(["--to", "60ns", "--gate-periods", "3", "--json"])
+        .output()
+        .expect("clock should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["data"]["gated"], serde_json::json!([]));
+    assert!(value["data"]["frequency_changes"].as_array().is_some());
+}
+
+#[test]
+fn clock_rejects_multi_bit_signals_and_zero_gate_periods() {
+    let fixture = write_fixture(CLOCK_VCD, ".clock-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "clock", "--waves", waves, "--scope", "top", "--signal", "sel",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--signal 'sel' must be a 1-bit signal, but it is 2 bits wide",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "clock", "--waves", waves, "--scope", "top", "--signal", "clk",
+        ])
+        .args(["--gate-periods", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--gate-periods must be greater than 0",
+        ));
+}
+
+#[test]
+fn clock_warns_when_too_few_rising_edges() {
+    let fixture = write_fixture(CLOCK_VCD, ".clock-empty.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "clock", "--waves", waves, "--scope", "top", "--signal", "clk",
+        ])
+        .args(["--from", "40ns", "--to", "85ns"])
+        .assert()
+        .success()
+        .stdout("signal: clk\nrising_edges: 1\nfalling_edges: 0\n")
+        .stderr(predicate::str::contains(
+            "fewer than two ungated rising edges in selected time range",
+        ));
+}
+
+#[test]
+fn clock_renders_times_and_periods_in_time_unit_with_ticks() {
+    let fixture = write_fixture(CLOCK_VCD, ".clock-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let output = wavepeek_cmd()
+        .args([
+            "clock",
+            "--waves",
+            waves,
+            "--signal",
+            "top.clk",
+            "--time-unit",
+            "us",
+            "--ticks",
+            "--json",
+        ])
+        .output()
+        .expect("clock should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"]["period"],
+        serde_json::json!({"nominal": "0.01us", "min": "0.01us", "max": "0.02us", "mean": "0.015us"})
+    );
+    assert_eq!(value["data"]["gated"][0]["start"], "0.035us");
+    assert_eq!(value["data"]["gated"][0]["tick"], 35);
+    assert_eq!(
+        value["data"]["frequency_changes"][0],
+        serde_json::json!({
+            "time": "0.08us",
+            "tick": 80,
+            "previous_period": "0.01us",
+            "period": "0.02us",
+            "previous_frequency": "100MHz",
+            "frequency": "50MHz",
+        })
+    );
+}
+
+#[test]
+fn clock_reference_clock_numbers_gate_and_change_cycles() {
+    let fixture = write_fixture(CLOCK_VCD, ".clock-cycles.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "clock", "--waves", waves, "--scope", "top", "--signal", "clk",
+        ])
+        .args(["--clock", "posedge clk"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with(concat!(
+            "gated: @35ns..@80ns cycle=3 duration=45ns level=1'h0\n",
+            "frequency_change: @80ns cycle=4 10ns->20ns 100MHz->50MHz\n",
+        )));
+}
@@ -7,20 +7,28 @@ use tempfile::tempdir;
 mod common;
 use common::{expected_schema_url, wavepeek_cmd};
 
-const TOPIC_IDS: [&str; 24] = [
+const TOPIC_IDS: [&str; 32] = [
     "intro",
+    "commands/audit",
+    "commands/cdc",
     "commands/change",
+    "commands/clock",
     "commands/docs",
+    "commands/export",
     "commands/extract",
+    "commands/glitch",
     "commands/help",
     "commands/info",
     "commands/overview",
     "commands/property",
+    "commands/saif",
     "commands/schema",
     "commands/scope",
     "commands/signal",
     "commands/skill",
+    "commands/timing",
     "commands/value",
+    "commands/view",
     "workflows/extract-handshake",
     "workflows/find-first-change",
     "troubleshooting/clock-edge-sampling",
@@ -0,0 +1,357 @@
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::{NamedTempFile, tempdir};
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+const EXPORT_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 8 \" data [7:0] $end\n",
+    "$scope module cpu $end\n",
+    "$var wire 4 # state $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nb0 \"\nb0 #\n",
+    "#5\n1!\nb1010 \"\n",
+    "#10\n0!\nb11 #\n",
+);
+
+fn stdout_text(args: &[&str]) -> String {
+    let mut command = wavepeek_cmd();
+    let assert = command.args(args).assert().success();
+    let output = assert.get_output();
+    assert!(
+        output.stderr.is_empty(),
+        "expected empty stderr, got: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).expect("stdout should be UTF-8")
+}
+
+#[test]
+fn export_gtkw_prints_grouped_signals_with_ranges_cursor_and_markers() {
+    let fixture = write_fixture(EXPORT_VCD, ".export-gtkw.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let stdout = stdout_text(&[
+        "export",
+        "gtkw",
+        "--waves",
+        waves,
+        "--signals",
+        "top.clk,top.data,top.cpu.state",
+        "--group-by-scope",
+        "--radix",
+        "dec",
+        "--cursor",
+        "5ns",
+        "--markers",
+        "2ns,8ns,2ns",
+    ]);
+
+    let dump_path = fs::canonicalize(fixture.path()).expect("fixture should canonicalize");
+    assert!(stdout.contains(&format!("[dumpfile] \"{}\"", dump_path.display())));
+    assert!(stdout.contains("[timestart] 0\n"));
+    assert!(
+        stdout.contains(" 5 2 8 -1 "),
+        "cursor and markers: {stdout}"
+    );
+    assert!(stdout.contains(
+        "@800200\n-top\n@28\ntop.clk\n@24\ntop.data[7:0]\n@1000200\n-top\n@800200\n-top.cpu\n@24\ntop.cpu.state[3:0]\n@1000200\n-top.cpu\n"
+    ));
+}
+
+#[test]
+fn export_surfer_writes_command_file_to_out() {
+    let fixture = write_fixture(EXPORT_VCD, ".export-surfer.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("debug.sucl");
+    let out_arg = out.to_str().expect("utf-8 path");
+
+    let stdout = stdout_text(&[
+        "export",
+        "surfer",
+        "--waves",
+        waves,
+        "--scope",
+        "top",
+        "--signals",
+        "clk,data",
+        "--radix",
+        "bin",
+        "--cursor",
+        "5ns",
+        "--markers",
+        "8ns",
+        "--out",
+        out_arg,
+    ]);
+
+    assert_eq!(
+        stdout,
+        format!("wrote Surfer command file with 2 signal(s) to {out_arg}\n")
+    );
+    let written = fs::read_to_string(&out).expect("command file should exist");
+    assert_eq!(
+        written,
+        "variable_add top.clk\nvariable_add top.data\nitem_focus b\nitem_set_format Binary\nitem_unfocus\nmarker_set 1 8\ncursor_set 5\n"
+    );
+}
+
+#[test]
+fn export_markers_from_reads_saved_property_rows() {
+    let fixture = write_fixture(EXPORT_VCD, ".export-markers.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let property = wavepeek_cmd()
+        .args([
+            "property",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk",
+            "--eval",
+            "data == 8'h0",
+            "--capture",
+            "match",
+            "--json",
+        ])
+        .output()
+        .expect("property should run");
+    assert!(property.status.success());
+    let saved = write_fixture(
+        std::str::from_utf8(&property.stdout).expect("json should be UTF-8"),
+        ".export-markers.json",
+    );
+
+    let stdout = stdout_text(&[
+        "export",
+        "surfer",
+        "--waves",
+        waves,
+        "--signals",
+        "top.clk",
+        "--markers-from",
+        saved.path().to_str().expect("utf-8 path"),
+    ]);
+
+    assert_eq!(stdout, "variable_add top.clk\nmarker_set 1 5\n");
+}
+
+#[test]
+fn export_rejects_misaligned_cursor_and_json_flag() {
+    let fixture = write_fixture(EXPORT_VCD, ".export-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "export",
+            "gtkw",
+            "--waves",
+            waves,
+            "--signals",
+            "top.clk",
+            "--cursor",
+            "20ns",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "time '20ns' for --cursor is outside dump bounds",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "export",
+            "surfer",
+            "--waves",
+            waves,
+            "--signals",
+            "top.clk",
+            "--json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unexpected argument '--json'"));
+
+    wavepeek_cmd()
+        .args([
+            "export",
+            "surfer",
+            "--waves",
+            waves,
+            "--signals",
+            "top.clk",
+            "--from",
+            "2ns",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unexpected argument '--from'"));
+}
+
+#[test]
+fn export_sqlite_writes_database_with_schema_and_changes() {
+    let fixture = write_fixture(EXPORT_VCD, ".export-sqlite.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("dump.sqlite");
+    let out_arg = out.to_str().expect("utf-8 path");
+
+    let stdout = stdout_text(&[
+        "export", "sqlite", "--waves", waves, "--from", "5ns", "--out", out_arg,
+    ]);
+    assert_eq!(
+        stdout,
+        format!(
+            "wrote SQLite database with 3 signal(s) and 5 change(s) in 2 scope(s) to {out_arg}\n"
+        )
+    );
+
+    let schema = sqlite_query(&out, "SELECT sql FROM sqlite_schema ORDER BY rowid");
+    for sql in [
+        "CREATE TABLE scopes(",
+        "CREATE TABLE signals(",
+        "CREATE TABLE changes(signal_id INTEGER NOT NULL, raw_time INTEGER NOT NULL, value TEXT NOT NULL)",
+        "CREATE INDEX changes_signal_time ON changes(signal_id, raw_time)",
+    ] {
+        assert!(schema.contains(sql), "missing schema entry {sql}");
+    }
+    assert_eq!(
+        sqlite_query(
+            &out,
+            "SELECT s.path, c.raw_time, c.value FROM changes c JOIN signals s ON s.id = c.signal_id \
+             ORDER BY c.raw_time, s.path"
+        ),
+        "top.clk|5|1\ntop.cpu.state|5|0000\ntop.data|5|00001010\ntop.clk|10|0\ntop.cpu.state|10|0011\n"
+    );
+}
+
+#[test]
+fn export_sqlite_requires_out_and_known_signals() {
+    let fixture = write_fixture(EXPORT_VCD, ".export-sqlite-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("dump.sqlite");
+
+    wavepeek_cmd()
+        .args(["export", "sqlite", "--waves", waves])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--out <FILE>"));
+
+    wavepeek_cmd()
+        .args([
+            "export",
+            "sqlite",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "nope",
+            "--out",
+            out.to_str().expect("utf-8 path"),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "fatal: signal: signal 'top.nope' not found in dump",
+        ));
+}
+
+/// Runs `sql` against `db`, one line per row with columns joined by `|`.
+fn sqlite_query(db: &Path, sql: &str) -> String {
+    let db = rusqlite::Connection::open_with_flags(db, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .expect("database should open");
+    let mut statement = db.prepare(sql).expect("query should prepare");
+    let columns = statement.column_count();
+    let rows = statement
+        .query_map([], |row| {
+            (0..columns)
+                .map(|index| {
+                    Ok(match row.get_ref(index)? {
+                        rusqlite::types::ValueRef::Null => String::new(),
+                        rusqlite::types::ValueRef::Integer(value) => value.to_string(),
+                        rusqlite::types::ValueRef::Text(value) => {
+                            String::from_utf8_lossy(value).into_owned()
+                        }
+                        other => panic!("unexpected column value {other:?}"),
+                    })
+                })
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .map(|columns| columns.join("|"))
+        })
+        .expect("query should run")
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .expect("rows should read");
+    rows.iter().map(|row| format!("{row}\n")).collect()
+}
+
+#[test]
+fn export_sqlite_database_reads_back_with_indexes() {
+    // Enough changes to span several B-tree pages per table and index.
+    let mut vcd = String::from(concat!(
+        "$timescale 1ns $end\n",
+        "$scope module top $end\n",
+        "$var wire 16 ! count $end\n",
+        "$var wire 1 \" clk $end\n",
+        "$upscope $end\n",
+        "$enddefinitions $end\n",
+    ));
+    for time in 0..5000u32 {
+        vcd.push_str(&format!("#{time}\nb{time:b} !\n{}\"\n", time % 2));
+    }
+    let fixture = write_fixture(&vcd, ".export-sqlite-read.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("dump.sqlite");
+
+    stdout_text(&[
+        "export",
+        "sqlite",
+        "--waves",
+        waves,
+        "--out",
+        out.to_str().expect("utf-8 path"),
+    ]);
+
+    assert_eq!(sqlite_query(&out, "PRAGMA integrity_check;"), "ok\n");
+    assert_eq!(
+        sqlite_query(
+            &out,
+            "SELECT s.path, count(*) FROM changes c JOIN signals s ON s.id = c.signal_id \
+             GROUP BY s.path ORDER BY s.path;"
+        ),
+        "top.clk|5000\ntop.count|5000\n"
+    );
+    assert_eq!(
+        sqlite_query(
+            &out,
+            "SELECT c.value FROM changes c INDEXED BY changes_signal_time \
+             JOIN signals s ON s.id = c.signal_id \
+             WHERE s.path = 'top.count' AND c.raw_time = 4321;"
+        ),
+        format!("{:016b}\n", 4321)
+    );
+    assert_eq!(
+        sqlite_query(&out, "SELECT path, depth FROM scopes ORDER BY path;"),
+        "top|0\n"
+    );
+}
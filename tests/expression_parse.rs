@@ -50,6 +50,24 @@ fn normalize_term(term: &EventTermAst) -> NormalizedTerm {
             name: Some(name.clone()),
             iff: term.iff.as_ref().map(|expr| expr.source.clone()),
         },
+        BasicEventAst::Every { period, phase, .. } => NormalizedTerm {
+            event: "every".to_string(),
+            name: Some(match phase {
+                Some(phase) => format!("{period} phase {phase}"),
+                None => period.clone(),
+            }),
+            iff: term.iff.as_ref().map(|expr| expr.source.clone()),
+        },
+        BasicEventAst::At { times, .. } => NormalizedTerm {
+            event: "at".to_string(),
+            name: Some(times.join(",")),
+            iff: term.iff.as_ref().map(|expr| expr.source.clone()),
+        },
+        BasicEventAst::Timestamps { .. } => NormalizedTerm {
+            event: "timestamps".to_string(),
+            name: None,
+            iff: term.iff.as_ref().map(|expr| expr.source.clone()),
+        },
     }
 }
 
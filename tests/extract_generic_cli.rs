@@ -754,3 +754,194 @@ fn extract_generic_rejects_zero_max() {
         .failure()
         .stderr(predicate::str::contains("--max must be greater than 0"));
 }
+
+#[test]
+fn generic_payload_patterns_expand_and_reject_duplicates() {
+    let fixture = write_fixture(
+        concat!(
+            "$timescale 1ns $end\n",
+            "$scope module top $end\n",
+            "$var wire 1 ! clk $end\n",
+            "$var wire 8 \" axi_wdata [7:0] $end\n",
+            "$var wire 8 # axi_rdata [7:0] $end\n",
+            "$upscope $end\n",
+            "$enddefinitions $end\n",
+            "#0\n0!\nb10 \"\nb1 #\n",
+            "#5\n1!\n",
+        ),
+        ".generic-pattern.vcd",
+    );
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let output = wavepeek_cmd()
+        .args([
+            "extract",
+            "generic",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk",
+            "--when",
+            "1",
+            "--payload",
+            "axi_*data",
+            "--json",
+        ])
+        .output()
+        .expect("extract should run");
+    assert!(output.status.success());
+    let value = parse_json(&output.stdout);
+    assert_eq!(
+        value["data"][0]["payload"],
+        json!([
+            {"path": "top.axi_rdata", "value": "8'h01"},
+            {"path": "top.axi_wdata", "value": "8'h02"}
+        ])
+    );
+    assert_eq!(
+        value["diagnostics"],
+        json!([{
+            "kind": "info",
+            "message": "payload pattern 'axi_*data' expanded to 2 signal(s): axi_rdata, axi_wdata"
+        }])
+    );
+
+    wavepeek_cmd()
+        .args([
+            "extract",
+            "generic",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk",
+            "--when",
+            "1",
+            "--payload",
+            "axi_rdata,re:data$",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "payload contains duplicate signal 'axi_rdata'",
+        ));
+}
+
+const DESC_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 16 \" desc $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nb0001001000001100 \"\n",
+    "#10\n1!\n",
+    "#15\n0!\nb0011010000010101 \"\n",
+    "#20\n1!\n",
+    "#25\n0!\nb0101011000000110 \"\n",
+    "#30\n1!\n",
+);
+
+#[test]
+fn extract_generic_layout_decodes_payload_fields_and_predicate_references() {
+    let fixture = write_fixture(DESC_VCD, "extract-layout.vcd");
+    let layout = write_source(
+        json!({
+            "$schema": expected_input_schema_url(),
+            "kind": "signal.layouts",
+            "layouts": [{
+                "signal": "re:\\.desc$",
+                "fields": [
+                    {"name": "addr", "msb": 15, "lsb": 8},
+                    {"name": "op", "msb": 1, "lsb": 0, "enum": {"1": "WRITE"}}
+                ]
+            }]
+        })
+        .to_string()
+        .as_str(),
+    );
+
+    let output = wavepeek_cmd()
+        .args([
+            "extract",
+            "generic",
+            "--waves",
+            fixture.path().to_str().expect("utf-8 path"),
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk",
+            "--when",
+            "desc.addr >= 8'h34",
+            "--payload",
+            "desc",
+            "--layout",
+            layout.path().to_str().expect("utf-8 path"),
+            "--json",
+        ])
+        .output()
+        .expect("extract should run");
+    assert!(output.status.success());
+    let value = parse_json(&output.stdout);
+    assert_eq!(
+        value["data"],
+        json!([
+            {
+                "time": "20ns",
+                "sample_time": "19ns",
+                "source": "transfer",
+                "payload": [{
+                    "path": "top.desc",
+                    "value": "16'h3415",
+                    "fields": {"addr": "8'h34", "op": "WRITE"}
+                }]
+            },
+            {
+                "time": "30ns",
+                "sample_time": "29ns",
+                "source": "transfer",
+                "payload": [{
+                    "path": "top.desc",
+                    "value": "16'h5606",
+                    "fields": {"addr": "8'h56", "op": "2'h2"}
+                }]
+            }
+        ])
+    );
+
+    fs::write(
+        layout.path(),
+        json!({
+            "$schema": expected_input_schema_url(),
+            "kind": "signal.layouts",
+            "layouts": [{"signal": "top.desc", "fields": [{"name": "op", "msb": 0, "lsb": 1}]}]
+        })
+        .to_string(),
+    )
+    .expect("layout should write");
+    wavepeek_cmd()
+        .args([
+            "extract",
+            "generic",
+            "--waves",
+            fixture.path().to_str().expect("utf-8 path"),
+            "--on",
+            "posedge top.clk",
+            "--when",
+            "1",
+            "--payload",
+            "top.desc",
+            "--layout",
+            layout.path().to_str().expect("utf-8 path"),
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "layout field 'top.desc.op' must have msb >= lsb",
+        ));
+}
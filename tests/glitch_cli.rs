@@ -0,0 +1,185 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::NamedTempFile;
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+// `rst_n` drops for 300ps at 12ns and `bus` holds 4'h3 for 2ns; `irq` is written 1 then 0 at
+// 20ns, and `rst_n` is written 1 twice at 40ns, which is not a glitch.
+const GLITCH_VCD: &str = concat!(
+    "$timescale 100ps $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! rst_n $end\n",
+    "$var wire 1 \" irq $end\n",
+    "$var wire 4 # bus $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n1!\n0\"\nb0000 #\n",
+    "#120\n0!\n",
+    "#123\n1!\n",
+    "#200\n1\"\n0\"\n",
+    "#300\nb0011 #\n",
+    "#320\nb0100 #\n",
+    "#400\n1!\n1!\n",
+    "#500\nb0101 #\n",
+);
+
+#[test]
+fn glitch_reports_pulses_and_zero_width_glitches() {
+    let fixture = write_fixture(GLITCH_VCD, ".glitch-rows.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "*", "--min-width", "1ns"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("expanded"))
+        .stdout(concat!(
+            "@12000ps pulse rst_n 1'h1->1'h0->1'h1 width=300ps\n",
+            "@20000ps zero_width irq 1'h0->1'h1->1'h0 width=0ps\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "rst_n,irq,bus", "--min-width", "3ns", "--json"])
+        .output()
+        .expect("glitch should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["command"], "glitch");
+    assert_eq!(value["data"].as_array().map(Vec::len), Some(3));
+    assert_eq!(
+        value["data"][2],
+        serde_json::json!({
+            "time": "30000ps",
+            "kind": "pulse",
+            "path": "top.bus",
+            "previous": "4'h0",
+            "value": "4'h3",
+            "next": "4'h4",
+            "width": "2000ps",
+        })
+    );
+}
+
+#[test]
+fn glitch_without_min_width_reports_only_zero_width_rows() {
+    let fixture = write_fixture(GLITCH_VCD, ".glitch-zero.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "rst_n,irq"])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout("@20000ps zero_width irq 1'h0->1'h1->1'h0 width=0ps\n");
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "irq", "--from", "21ns"])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "no glitches found in selected time range",
+        ));
+}
+
+#[test]
+fn glitch_rejects_zero_and_inexact_min_width() {
+    let fixture = write_fixture(GLITCH_VCD, ".glitch-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "rst_n", "--min-width", "0ns"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--min-width must be greater than zero",
+        ));
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "rst_n", "--min-width", "150ps"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid --min-width '150ps': cannot be represented exactly in dump precision '100ps'",
+        ));
+}
+
+#[test]
+fn glitch_renders_times_and_widths_in_time_unit_with_ticks() {
+    let fixture = write_fixture(GLITCH_VCD, ".glitch-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args([
+            "--signals",
+            "rst_n",
+            "--min-width",
+            "1ns",
+            "--time-unit",
+            "auto",
+        ])
+        .assert()
+        .success()
+        .stdout("@12ns pulse rst_n 1'h1->1'h0->1'h1 width=300ps\n");
+
+    let output = wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args([
+            "--signals",
+            "rst_n",
+            "--min-width",
+            "1ns",
+            "--ticks",
+            "--json",
+        ])
+        .output()
+        .expect("glitch should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["data"][0]["time"], "12000ps");
+    assert_eq!(value["data"][0]["tick"], 120);
+}
+
+#[test]
+fn glitch_clock_numbers_cycles_in_rows_and_time_tokens() {
+    let fixture = write_fixture(GLITCH_VCD, ".glitch-cycles.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "rst_n,irq", "--min-width", "1ns"])
+        .args(["--clock", "posedge rst_n"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@12000ps pulse rst_n 1'h1->1'h0->1'h1 width=300ps\n",
+            "@20000ps cycle=1 zero_width irq 1'h0->1'h1->1'h0 width=0ps\n",
+        ));
+
+    wavepeek_cmd()
+        .args(["glitch", "--waves", waves, "--scope", "top"])
+        .args(["--signals", "rst_n,irq", "--min-width", "1ns"])
+        .args(["--clock", "posedge rst_n", "--from", "1cyc"])
+        .assert()
+        .success()
+        .stderr("info: --from time expression '1cyc' resolved to 12300ps\n")
+        .stdout("@20000ps cycle=1 zero_width irq 1'h0->1'h1->1'h0 width=0ps\n");
+}
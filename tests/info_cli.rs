@@ -81,9 +81,11 @@ fn info_opens_fst_with_verilator_pack_array_attributes() {
         .args(["info", "--waves", fixture.as_str()])
         .assert()
         .success()
-        .stdout(predicate::eq(
+        .stdout(predicate::eq(concat!(
             "time_unit: 1ps\ntime_start: 0ps\ntime_end: 1ps\n",
-        ))
+            "format: fst\nbackend: wellen\nscope_count: 3\nsignal_count: 1\n",
+            "timestamp_count: 2\nsimulator: minimal PACK/ARRAY reproducer\ntimescale: 1ps\n",
+        )))
         .stderr(predicate::str::is_empty());
 }
 
@@ -164,3 +166,105 @@ fn info_invalid_file_is_parse_error_with_exit_code_two() {
         .stdout(predicate::str::is_empty())
         .stderr(predicate::str::starts_with("fatal: file: cannot parse"));
 }
+
+const HEADER_VCD: &str = concat!(
+    "$date Mon Jan 5 10:00:00 2026 $end\n",
+    "$version Example Simulator 4.2 $end\n",
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 1 \" rst $end\n",
+    "$scope module u0 $end\n",
+    "$var wire 4 # data [3:0] $end\n",
+    "$var wire 1 ! clk_alias $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n1\"\nb0000 #\n",
+    "#5\n1!\n",
+    "#10\n0!\n0\"\nb0001 #\n",
+    "#15\n1!\n",
+    "#20\n0!\nb0010 #\n",
+);
+
+fn write_vcd(contents: &str) -> tempfile::NamedTempFile {
+    let fixture = tempfile::NamedTempFile::with_suffix(".vcd").expect("temp file should create");
+    std::fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+#[test]
+fn info_reports_format_counts_and_header_fields() {
+    let fixture = write_vcd(HEADER_VCD);
+    let path = fixture.path().to_string_lossy().into_owned();
+
+    let output = wavepeek_cmd()
+        .args(["info", "--waves", path.as_str(), "--json"])
+        .output()
+        .expect("info should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+
+    assert_eq!(value["data"]["format"], "vcd");
+    assert_eq!(value["data"]["backend"], "wellen");
+    assert_eq!(value["data"]["scope_count"], 2);
+    assert_eq!(value["data"]["signal_count"], 4);
+    assert_eq!(value["data"]["timestamp_count"], 5);
+    assert_eq!(value["data"]["date"], "Mon Jan 5 10:00:00 2026");
+    assert_eq!(value["data"]["simulator"], "Example Simulator 4.2");
+    assert_eq!(value["data"]["timescale"], "1ns");
+    assert!(value["data"].get("activity").is_none());
+}
+
+#[test]
+fn info_activity_lists_most_active_nets_once() {
+    let fixture = write_vcd(HEADER_VCD);
+    let path = fixture.path().to_string_lossy().into_owned();
+
+    wavepeek_cmd()
+        .args(["info", "--waves", path.as_str(), "--activity", "2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with(
+            "activity:\n  top.clk changes=4\n  top.u0.data changes=2\n",
+        ))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn info_activity_rejects_zero_limit() {
+    let fixture = write_vcd(HEADER_VCD);
+    let path = fixture.path().to_string_lossy().into_owned();
+
+    wavepeek_cmd()
+        .args(["info", "--waves", path.as_str(), "--activity", "0"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--activity must be greater than 0",
+        ));
+}
+
+#[test]
+fn info_activity_counts_each_differing_write_within_one_timestamp() {
+    let fixture = write_vcd(concat!(
+        "$timescale 1ns $end\n",
+        "$scope module top $end\n",
+        "$var wire 1 ! irq $end\n",
+        "$upscope $end\n",
+        "$enddefinitions $end\n",
+        "#0\n0!\n",
+        "#10\n1!\n0!\n",
+        "#20\n1!\n1!\n",
+    ));
+    let path = fixture.path().to_string_lossy().into_owned();
+
+    wavepeek_cmd()
+        .args(["info", "--waves", path.as_str(), "--activity", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with(
+            "activity:\n  top.irq changes=3\n",
+        ));
+}
@@ -1197,3 +1197,89 @@ fn property_runtime_manifest_cases_pass() {
         }
     }
 }
+
+#[test]
+fn property_timestamps_term_matches_only_recorded_dump_times() {
+    let fixture = write_fixture(RTL_SAMPLING_VCD, ".property-timestamps.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    // Grid times where `clk` is low (3ns, 12ns, ...) are not dump timestamps, so neither
+    // term matches them.
+    wavepeek_cmd()
+        .args(["property", "--waves", waves])
+        .args(["--on", "every 3ns iff top.clk or timestamps"])
+        .args([
+            "--eval",
+            "1",
+            "--capture",
+            "match",
+            "--sample-mode",
+            "native",
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@0ns match\n@5ns match\n@6ns match\n@9ns match\n@10ns match\n@15ns match\n",
+            "@18ns match\n@20ns match\n@25ns match\n@27ns match\n@30ns match\n@35ns match\n",
+        ));
+}
+
+#[test]
+fn property_layout_fields_resolve_in_on_and_eval() {
+    let fixture = write_fixture(
+        concat!(
+            "$timescale 1ns $end\n",
+            "$scope module top $end\n",
+            "$var wire 1 ! clk $end\n",
+            "$var wire 16 \" desc $end\n",
+            "$upscope $end\n",
+            "$enddefinitions $end\n",
+            "#0\n0!\nb0001001000001100 \"\n",
+            "#10\n1!\n",
+            "#15\n0!\nb0011010000010101 \"\n",
+            "#20\n1!\n",
+            "#25\n0!\nb0101011000000110 \"\n",
+            "#30\n1!\n",
+        ),
+        ".property-layout.vcd",
+    );
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let layout = write_fixture(
+        json!({
+            "$schema": common::expected_input_schema_url(),
+            "kind": "signal.layouts",
+            "layouts": [{
+                "signal": "top.desc",
+                "fields": [
+                    {"name": "addr", "msb": 15, "lsb": 8},
+                    {"name": "op", "msb": 1, "lsb": 0}
+                ]
+            }]
+        })
+        .to_string()
+        .as_str(),
+        ".layout.json",
+    );
+    let layout_path = layout.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "property",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk iff desc.addr != 8'h12",
+            "--eval",
+            "desc.op == 1",
+            "--capture",
+            "match",
+            "--layout",
+            layout_path,
+        ])
+        .assert()
+        .success()
+        .stdout("@20ns sample@19ns match\n")
+        .stderr(predicate::str::is_empty());
+}
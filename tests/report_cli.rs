@@ -0,0 +1,169 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::{NamedTempFile, tempdir};
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+const REPORT_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 1 \" valid $end\n",
+    "$var wire 8 # data [7:0] $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\nb0 #\n",
+    "#5\n1!\n",
+    "#10\n0!\n1\"\nb10100101 #\n",
+    "#15\n1!\n",
+    "#20\n0!\nx\"\n",
+    "#25\n1!\n",
+    "#30\n0!\n",
+);
+
+#[test]
+fn property_html_writes_offline_report_with_rows_and_strips() {
+    let fixture = write_fixture(REPORT_VCD, ".report-property.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("valid.html");
+    let out_arg = out.to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "property",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk",
+            "--eval",
+            "valid && data != 8'h0",
+            "--capture",
+            "match",
+            "--html",
+            out_arg,
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(format!("wrote HTML report with 1 row(s) to {out_arg}\n"));
+
+    let html = fs::read_to_string(&out).expect("report should exist");
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h1>wavepeek property</h1>"));
+    assert!(html.contains("<td>15ns</td>"), "row time: {html}");
+    assert!(html.contains("<svg"));
+    assert!(html.contains("<title>top.data</title>data</text>"));
+    assert!(html.contains("<title>data = 8&#39;ha5</title>"));
+    assert!(html.contains("class=\"unknown\""));
+    assert!(html.contains("class=\"marker\""));
+    assert!(!html.contains("src=\"http"));
+    assert!(!html.contains("href=\"http"));
+}
+
+#[test]
+fn extract_html_lists_context_rows_and_empty_result_diagnostic() {
+    let fixture = write_fixture(REPORT_VCD, ".report-extract.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("extract.html");
+    let out_arg = out.to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "extract",
+            "generic",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--on",
+            "posedge clk",
+            "--when",
+            "data == 8'hff",
+            "--payload",
+            "data",
+            "--html",
+            out_arg,
+        ])
+        .assert()
+        .success()
+        .stdout(format!("wrote HTML report with 0 row(s) to {out_arg}\n"))
+        .stderr(predicate::str::contains("warning[WPK-W0003]"));
+
+    let html = fs::read_to_string(&out).expect("report should exist");
+    assert!(html.contains("<h2>Diagnostics</h2>"));
+    assert!(html.contains("warning[WPK-W0003]"));
+    assert!(html.contains("<h2>Rows (0)</h2>"));
+}
+
+#[test]
+fn html_conflicts_with_machine_output_flags() {
+    let fixture = write_fixture(REPORT_VCD, ".report-conflict.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "property",
+            "--waves",
+            waves,
+            "--on",
+            "posedge top.clk",
+            "--eval",
+            "top.valid",
+            "--html",
+            "report.html",
+            "--json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--html <FILE>' cannot be used with '--json'",
+        ));
+}
+
+#[test]
+fn property_html_renders_rows_in_time_unit_with_strips() {
+    let fixture = write_fixture(REPORT_VCD, ".report-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("valid.html");
+    let out_arg = out.to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "property",
+            "--waves",
+            waves,
+            "--on",
+            "posedge top.clk",
+            "--eval",
+            "top.valid",
+            "--capture",
+            "match",
+            "--time-unit",
+            "ps",
+            "--ticks",
+            "--html",
+            out_arg,
+        ])
+        .assert()
+        .success()
+        .stdout(format!("wrote HTML report with 1 row(s) to {out_arg}\n"));
+
+    let html = fs::read_to_string(&out).expect("report should exist");
+    assert!(html.contains("<td>15000ps</td>"), "row time: {html}");
+    assert!(html.contains("<svg"));
+    assert!(html.contains("class=\"marker\""));
+}
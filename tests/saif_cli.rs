@@ -0,0 +1,151 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::{NamedTempFile, tempdir};
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+const SAIF_VCD: &str = concat!(
+    "$timescale 10ps $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$scope module u_core $end\n",
+    "$var wire 2 \" bus [1:0] $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nbx0 \"\n",
+    "#4\n1!\nb10 \"\n",
+    "#6\n0!\nbz0 \"\n",
+    "#10\n1!\n",
+);
+
+#[test]
+fn saif_writes_nested_instances_with_per_bit_activity() {
+    let fixture = write_fixture(SAIF_VCD, ".saif-tree.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["saif", "--waves", waves])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(predicate::str::contains(concat!(
+            "(DIVIDER / )\n",
+            "(TIMESCALE 10 ps)\n",
+            "(DURATION 10)\n",
+            "(INSTANCE top\n",
+            "  (NET\n",
+            "    (clk\n",
+            "      (T0 8) (T1 2) (TX 0) (TZ 0)\n",
+            "      (TC 3) (IG 0)\n",
+            "    )\n",
+            "  )\n",
+            "  (INSTANCE u_core\n",
+            "    (NET\n",
+            "      (bus\\[1\\]\n",
+            "        (T0 0) (T1 2) (TX 4) (TZ 4)\n",
+            "        (TC 0) (IG 0)\n",
+            "      )\n",
+            "      (bus\\[0\\]\n",
+            "        (T0 10) (T1 0) (TX 0) (TZ 0)\n",
+            "        (TC 0) (IG 0)\n",
+            "      )\n",
+            "    )\n",
+            "  )\n",
+            ")\n",
+            ")\n",
+        )));
+}
+
+#[test]
+fn saif_counts_repeated_writes_at_one_timestamp_once() {
+    // `irq` is written 1 then 0 at 20ps: one zero-width pulse and no settled toggle.
+    let fixture = write_fixture(
+        concat!(
+            "$timescale 1ps $end\n",
+            "$scope module top $end\n",
+            "$var wire 1 ! irq $end\n",
+            "$upscope $end\n",
+            "$enddefinitions $end\n",
+            "#0\n0!\n",
+            "#20\n1!\n0!\n",
+            "#30\n0!\n",
+        ),
+        ".saif-repeated.vcd",
+    );
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["saif", "--waves", waves])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(concat!(
+            "    (irq\n",
+            "      (T0 30) (T1 0) (TX 0) (TZ 0)\n",
+            "      (TC 0) (IG 1)\n",
+        )));
+}
+
+#[test]
+fn saif_scope_window_and_out_file() {
+    let fixture = write_fixture(SAIF_VCD, ".saif-scope.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let dir = tempdir().expect("tempdir should create");
+    let out = dir.path().join("core.saif");
+    let out_arg = out.to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "saif",
+            "--waves",
+            waves,
+            "--scope",
+            "top.u_core",
+            "--from",
+            "40ps",
+            "--to",
+            "80ps",
+            "--out",
+            out_arg,
+        ])
+        .assert()
+        .success()
+        .stdout(format!(
+            "wrote SAIF file with 1 net(s) in 1 instance(s) to {out_arg}\n"
+        ));
+
+    let written = fs::read_to_string(&out).expect("SAIF file should exist");
+    assert!(written.contains("(DURATION 4)\n(INSTANCE top/u_core\n"));
+    assert!(written.contains("(bus\\[1\\]\n      (T0 0) (T1 2) (TX 0) (TZ 2)\n"));
+}
+
+#[test]
+fn saif_rejects_unknown_scope_and_misaligned_time() {
+    let fixture = write_fixture(SAIF_VCD, ".saif-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["saif", "--waves", waves, "--scope", "top.nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "fatal: scope: scope 'top.nope' not found in dump",
+        ));
+
+    wavepeek_cmd()
+        .args(["saif", "--waves", waves, "--from", "5ps"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "time '5ps' cannot be represented exactly in dump precision '10ps'",
+        ));
+}
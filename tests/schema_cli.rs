@@ -520,6 +520,10 @@ fn schema_validator_accepts_extension_fields() {
             "time_unit": "1ns",
             "time_start": "0ns",
             "time_end": "10ns",
+            "format": "vcd",
+            "backend": "wellen",
+            "scope_count": 2,
+            "signal_count": 5,
             "x_client_note": "accepted extension"
         },
         "diagnostics": [{
@@ -2153,13 +2157,18 @@ fn schema_input_command_output_is_valid_json() {
             {"$ref": "#/$defs/extractApbSourceInput"},
             {"$ref": "#/$defs/extractAtbSourceInput"},
             {"$ref": "#/$defs/extractAxiSourceInput"},
-            {"$ref": "#/$defs/extractAxiStreamSourceInput"}
+            {"$ref": "#/$defs/extractAxiStreamSourceInput"},
+            {"$ref": "#/$defs/signalLayoutsInput"}
         ])
     );
     assert_eq!(
         value["$defs"]["extractGenericSourcesInput"]["properties"]["kind"]["const"],
         "extract.generic.sources"
     );
+    assert_eq!(
+        value["$defs"]["signalLayoutsInput"]["properties"]["kind"]["const"],
+        "signal.layouts"
+    );
     assert_eq!(
         value["$defs"]["extractAhbSourceInput"]["properties"]["kind"]["const"],
         "extract.ahb.source"
@@ -2579,6 +2588,11 @@ fn schema_stream_command_exposes_waveform_command_contract() {
             "value",
             "change",
             "property",
+            "cdc",
+            "timing",
+            "glitch",
+            "audit",
+            "clock",
             "extract ahb",
             "extract apb",
             "extract atb",
@@ -3167,7 +3181,16 @@ fn schema_stream_validator_accepts_representative_waveform_records() {
             "type": "item",
             "seq": 1,
             "command": "info",
-            "item": {"time_unit": "1ns", "time_start": "0ns", "time_end": "10ns"}
+            "item": {
+                "time_unit": "1ns",
+                "time_start": "0ns",
+                "time_end": "10ns",
+                "format": "vcd",
+                "backend": "wellen",
+                "scope_count": 2,
+                "signal_count": 5,
+                "activity": [{"path": "top.clk", "changes": 20}]
+            }
         }),
         json!({
             "type": "item",
@@ -435,6 +435,53 @@ fn scope_invalid_regex_is_args_error() {
         .stderr(predicate::str::contains("See 'wavepeek scope --help'."));
 }
 
+#[test]
+fn scope_module_type_filter_skips_scopes_without_recorded_module() {
+    let fixture = fixture_path("scope_mixed_kinds.vcd");
+    let fixture = fixture.to_string_lossy().into_owned();
+
+    let all = wavepeek_cmd()
+        .args(["scope", "--waves", fixture.as_str(), "--json"])
+        .output()
+        .expect("scope run should execute");
+    assert!(all.status.success());
+    let value = parse_scope_json(&all.stdout);
+    let data = value["data"].as_array().expect("data should be array");
+    assert!(!data.is_empty());
+    assert!(data.iter().all(|entry| entry.get("module").is_none()));
+
+    let filtered = wavepeek_cmd()
+        .args([
+            "scope",
+            "--waves",
+            fixture.as_str(),
+            "--module-type",
+            ".*",
+            "--json",
+        ])
+        .output()
+        .expect("scope run should execute");
+    assert!(filtered.status.success());
+    let value = parse_scope_json(&filtered.stdout);
+    assert_eq!(value["data"], json!([]));
+    assert_eq!(value["diagnostics"][0]["message"], "no scopes found");
+
+    wavepeek_cmd()
+        .args([
+            "scope",
+            "--waves",
+            fixture.as_str(),
+            "--module-type",
+            "fifo_(",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with(
+            "fatal: args: invalid regex 'fifo_('",
+        ))
+        .stderr(predicate::str::contains("See 'wavepeek scope --help'."));
+}
+
 #[test]
 fn scope_output_is_bit_for_bit_deterministic_across_runs() {
     let fixture = fixture_path("m2_core.vcd");
@@ -637,3 +684,180 @@ fn scope_external_scr1_fixture_keeps_hierarchy_semantics() {
         "scope listing unexpectedly contains signal-like leaves"
     );
 }
+
+const SOURCE_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$attrbegin misc 03 rtl/top.sv 1 $end\n",
+    "$attrbegin misc 03 rtl/fifo.sv 2 $end\n",
+    "$attrbegin misc 04 1 3 $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$attrbegin misc 04 2 12 $end\n",
+    "$attrbegin misc 05 1 40 $end\n",
+    "$scope module u_fifo $end\n",
+    "$var wire 1 \" push $end\n",
+    "$upscope $end\n",
+    "$scope begin blk $end\n",
+    "$var wire 1 # flag $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\n0#\n",
+);
+
+#[test]
+fn scope_with_source_reports_recorded_declaration_locations() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".scope-source.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), SOURCE_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["scope", "--waves", waves])
+        .assert()
+        .success()
+        .stdout("0 top kind=module\n1 top.blk kind=begin\n1 top.u_fifo kind=module\n");
+
+    wavepeek_cmd()
+        .args(["scope", "--waves", waves, "--with-source"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "0 top kind=module source=rtl/top.sv:3\n",
+            "1 top.blk kind=begin\n",
+            "1 top.u_fifo kind=module source=rtl/fifo.sv:12 instantiated=rtl/top.sv:40\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args(["scope", "--waves", waves, "--with-source", "--json"])
+        .output()
+        .expect("scope should run");
+    assert!(output.status.success());
+    let value = parse_scope_json(&output.stdout);
+    assert_eq!(
+        value["data"][2],
+        json!({
+            "path": "top.u_fifo",
+            "depth": 1,
+            "kind": "module",
+            "source": {"file": "rtl/fifo.sv", "line": 12},
+            "instantiation": {"file": "rtl/top.sv", "line": 40}
+        })
+    );
+    assert!(value["data"][1].get("source").is_none());
+}
+
+const COMPARE_A_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module tb_a $end\n",
+    "$scope module dut $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$scope module core $end\n",
+    "$var wire 1 \" busy $end\n",
+    "$upscope $end\n",
+    "$scope module legacy $end\n",
+    "$var wire 1 # flag $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\n0#\n",
+);
+
+const COMPARE_B_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module tb_b $end\n",
+    "$scope module dut $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$scope module core $end\n",
+    "$var wire 1 \" busy $end\n",
+    "$upscope $end\n",
+    "$scope module extra $end\n",
+    "$var wire 1 # flag $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\n0#\n",
+);
+
+#[test]
+fn scope_compare_lists_scopes_missing_from_either_dump() {
+    let a = tempfile::NamedTempFile::with_suffix(".scope-compare-a.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(a.path(), COMPARE_A_VCD).expect("fixture should write");
+    let b = tempfile::NamedTempFile::with_suffix(".scope-compare-b.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(b.path(), COMPARE_B_VCD).expect("fixture should write");
+    let a = a.path().to_str().expect("utf-8 path");
+    let b = b.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "scope",
+            "--waves",
+            a,
+            "--compare",
+            b,
+            "--remap-prefix",
+            "tb_a=tb_b",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(concat!(
+            "2 tb_a.dut.legacy kind=module compare=only-a\n",
+            "2 tb_b.dut.extra kind=module compare=only-b\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "scope",
+            "--waves",
+            a,
+            "--compare",
+            b,
+            "--remap-prefix",
+            "tb_a=tb_b",
+            "--filter",
+            "^tb_a\\.dut\\.(core|extra)$",
+            "--json",
+        ])
+        .output()
+        .expect("scope should run");
+    assert!(output.status.success());
+    let value = parse_scope_json(&output.stdout);
+    assert_eq!(
+        value["data"],
+        json!([{
+            "path": "tb_b.dut.extra",
+            "depth": 2,
+            "kind": "module",
+            "compare": {"status": "only-b"}
+        }])
+    );
+
+    wavepeek_cmd()
+        .args(["scope", "--waves", a, "--compare", a])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("warning[WPK-W0003]: no scope differences found\n");
+
+    wavepeek_cmd()
+        .args([
+            "scope",
+            "--waves",
+            a,
+            "--compare",
+            b,
+            "--remap-prefix",
+            "tb_a",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "invalid --remap-prefix 'tb_a': expected A_PREFIX=B_PREFIX",
+        ));
+}
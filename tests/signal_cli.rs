@@ -850,3 +850,395 @@ fn signal_external_picorv32_fixture_uses_short_names_by_default() {
         ))
         .stdout(predicate::str::contains("testbench.top.uut.BARREL_SHIFTER").not());
 }
+
+const GLOBAL_SEARCH_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$scope module dma $end\n",
+    "$var wire 512 \" m_tdata [511:0] $end\n",
+    "$var wire 8 # s_tdata [7:0] $end\n",
+    "$upscope $end\n",
+    "$scope module noc $end\n",
+    "$scope module port0 $end\n",
+    "$var wire 512 $ tdata [511:0] $end\n",
+    "$var reg 64 % tdata_q [63:0] $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n",
+);
+
+fn write_global_fixture() -> tempfile::NamedTempFile {
+    let fixture = tempfile::NamedTempFile::with_suffix(".signal-global.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), GLOBAL_SEARCH_VCD).expect("fixture should write");
+    fixture
+}
+
+#[test]
+fn signal_global_search_ranks_whole_name_matches_and_filters_width() {
+    let fixture = write_global_fixture();
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "signal", "--waves", waves, "--global", "--filter", "tdata", "--width", "512",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout("top.noc.port0.tdata kind=wire width=512\ntop.dma.m_tdata kind=wire width=512\n");
+
+    let output = wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--global",
+            "--path",
+            "^top\\.noc\\.",
+            "--kind",
+            "reg",
+            "--min-width",
+            "2",
+            "--json",
+        ])
+        .output()
+        .expect("signal should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"],
+        json!([{"name":"tdata_q","path":"top.noc.port0.tdata_q","kind":"reg","width":64}])
+    );
+}
+
+#[test]
+fn signal_global_search_truncates_ranked_rows_and_reports_empty_results() {
+    let fixture = write_global_fixture();
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--global",
+            "--max-width",
+            "8",
+            "--max",
+            "1",
+        ])
+        .assert()
+        .success()
+        .stdout("top.clk kind=wire width=1\n")
+        .stderr(predicate::str::contains("warning[WPK-W0002]"));
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--global",
+            "--direction",
+            "input",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "warning[WPK-W0003]: no signals matched the search filters",
+        ));
+}
+
+#[test]
+fn signal_global_rejects_scope_and_inverted_width_range() {
+    let fixture = write_global_fixture();
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["signal", "--waves", waves, "--global", "--scope", "top"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--global' cannot be used with '--scope <SCOPE>'",
+        ));
+
+    wavepeek_cmd()
+        .args(["signal", "--waves", waves])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--scope <SCOPE>"));
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--global",
+            "--min-width",
+            "8",
+            "--max-width",
+            "2",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "fatal: args: --min-width must be less than or equal to --max-width",
+        ));
+}
+
+const ALIAS_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 8 \" data [7:0] $end\n",
+    "$scope module u_core $end\n",
+    "$var wire 1 ! clk_i $end\n",
+    "$var wire 8 \" din [7:0] $end\n",
+    "$var wire 1 # busy $end\n",
+    "$scope module u_alu $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nb0 \"\n0#\n",
+);
+
+fn write_alias_fixture() -> tempfile::NamedTempFile {
+    let fixture = tempfile::NamedTempFile::with_suffix(".signal-alias.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), ALIAS_VCD).expect("fixture should write");
+    fixture
+}
+
+#[test]
+fn signal_aliases_of_lists_every_path_sharing_waveform_data() {
+    let fixture = write_alias_fixture();
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--aliases-of",
+            "top.u_core.u_alu.clk",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(concat!(
+            "top.clk kind=wire width=1\n",
+            "top.u_core.clk_i kind=wire width=1\n",
+            "top.u_core.u_alu.clk kind=wire width=1\n",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--aliases-of",
+            "top.u_core.busy",
+        ])
+        .assert()
+        .success()
+        .stdout("top.u_core.busy kind=wire width=1\n");
+
+    wavepeek_cmd()
+        .args(["signal", "--waves", waves, "--aliases-of", "top.nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "fatal: signal: signal 'top.nope' not found in dump",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--aliases-of",
+            "top.clk",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn signal_dedupe_aliases_keeps_first_listed_path_per_net() {
+    let fixture = write_alias_fixture();
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--recursive",
+            "--dedupe-aliases",
+        ])
+        .assert()
+        .success()
+        .stdout("clk kind=wire width=1\ndata kind=wire width=8\nu_core.busy kind=wire width=1\n")
+        .stderr("info: --dedupe-aliases hid 3 alias path(s) of listed signals\n");
+
+    let output = wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            waves,
+            "--scope",
+            "top.u_core",
+            "--dedupe-aliases",
+            "--json",
+        ])
+        .output()
+        .expect("signal should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["data"].as_array().map(Vec::len), Some(3));
+    assert_eq!(value["diagnostics"], json!([]));
+}
+
+const COMPARE_A_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module tb_a $end\n",
+    "$scope module dut $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 8 \" data [7:0] $end\n",
+    "$var wire 1 # old_flag $end\n",
+    "$var wire 4 $ state [3:0] $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nb0 \"\n0#\nb0 $\n",
+);
+
+const COMPARE_B_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module tb_b $end\n",
+    "$scope module dut $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 16 \" data [15:0] $end\n",
+    "$var wire 1 # new_flag $end\n",
+    "$var reg 4 $ state [3:0] $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nb0 \"\n0#\nb0 $\n",
+);
+
+#[test]
+fn signal_compare_reports_one_sided_and_reshaped_signals() {
+    let a = tempfile::NamedTempFile::with_suffix(".signal-compare-a.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(a.path(), COMPARE_A_VCD).expect("fixture should write");
+    let b = tempfile::NamedTempFile::with_suffix(".signal-compare-b.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(b.path(), COMPARE_B_VCD).expect("fixture should write");
+    let a = a.path().to_str().expect("utf-8 path");
+    let b = b.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            a,
+            "--scope",
+            "tb_a.dut",
+            "--compare",
+            b,
+            "--remap-prefix",
+            "tb_a=tb_b",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(concat!(
+            "data kind=wire width=8 compare=changed b_kind=wire b_width=16\n",
+            "old_flag kind=wire width=1 compare=only-a\n",
+            "state kind=wire width=4 compare=changed b_kind=reg b_width=4\n",
+            "new_flag kind=wire width=1 compare=only-b\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            a,
+            "--global",
+            "--filter",
+            ".*flag",
+            "--compare",
+            b,
+            "--remap-prefix",
+            "tb_a=tb_b",
+            "--json",
+        ])
+        .output()
+        .expect("signal should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"],
+        json!([
+            {
+                "name": "old_flag",
+                "path": "tb_a.dut.old_flag",
+                "kind": "wire",
+                "width": 1,
+                "compare": {"status": "only-a"}
+            },
+            {
+                "name": "new_flag",
+                "path": "tb_b.dut.new_flag",
+                "kind": "wire",
+                "width": 1,
+                "compare": {"status": "only-b"}
+            }
+        ])
+    );
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            a,
+            "--scope",
+            "tb_a.dut",
+            "--compare",
+            b,
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "clk kind=wire width=1 compare=only-a\n",
+            "data kind=wire width=8 compare=only-a\n",
+            "old_flag kind=wire width=1 compare=only-a\n",
+            "state kind=wire width=4 compare=only-a\n",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "signal",
+            "--waves",
+            a,
+            "--scope",
+            "tb_a.dut",
+            "--remap-prefix",
+            "tb_a=tb_b",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--compare <FILE>"));
+}
@@ -0,0 +1,267 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::NamedTempFile;
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+// clk rises at 10/20/30ns; d changes 2ns before and 1ns after edges, q at and 1ns before them.
+const TIMING_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 1 \" d $end\n",
+    "$var wire 2 # q $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\nb00 #\n",
+    "#5\n1\"\n",
+    "#8\n0\"\n",
+    "#10\n1!\n",
+    "#15\n0!\n",
+    "#20\n1!\nb01 #\n",
+    "#21\n1\"\n",
+    "#25\n0!\n",
+    "#29\nb10 #\n",
+    "#30\n1!\n",
+);
+
+const CHECK_ARGS: [&str; 8] = [
+    "--scope",
+    "top",
+    "--clock",
+    "posedge clk",
+    "--setup",
+    "2ns",
+    "--hold",
+    "1ns",
+];
+
+#[test]
+fn timing_reports_setup_and_hold_rows_with_slack() {
+    let fixture = write_fixture(TIMING_VCD, ".timing-rows.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["timing", "--waves", waves, "--signals", "d,q"])
+        .args(CHECK_ARGS)
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(concat!(
+            "@8ns setup d 1'h1->1'h0 edge@10ns slack=0ns\n",
+            "@20ns cycle=2 setup q 2'h0->2'h1 edge@20ns slack=-2ns\n",
+            "@21ns cycle=2 hold d 1'h0->1'h1 edge@20ns slack=0ns\n",
+            "@29ns cycle=2 setup q 2'h1->2'h2 edge@30ns slack=-1ns\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args(["timing", "--waves", waves, "--signals", "q", "--json"])
+        .args(CHECK_ARGS)
+        .output()
+        .expect("timing should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["command"], "timing");
+    assert_eq!(
+        value["data"][1],
+        serde_json::json!({
+            "time": "29ns",
+            "cycle": 2,
+            "kind": "setup",
+            "path": "top.q",
+            "previous": "2'h1",
+            "value": "2'h2",
+            "edge": "30ns",
+            "slack": "-1ns",
+        })
+    );
+}
+
+#[test]
+fn timing_limits_rows_and_warns_on_empty_ranges() {
+    let fixture = write_fixture(TIMING_VCD, ".timing-limits.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["timing", "--waves", waves, "--signals", "d,q", "--max", "1"])
+        .args(CHECK_ARGS)
+        .assert()
+        .success()
+        .stdout("@8ns setup d 1'h1->1'h0 edge@10ns slack=0ns\n")
+        .stderr(predicate::str::contains("truncated output to 1 entries"));
+
+    wavepeek_cmd()
+        .args([
+            "timing",
+            "--waves",
+            waves,
+            "--signals",
+            "d",
+            "--from",
+            "11ns",
+            "--to",
+            "19ns",
+        ])
+        .args(CHECK_ARGS)
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "no setup or hold violations found in selected time range",
+        ));
+}
+
+#[test]
+fn timing_rejects_inexact_limits() {
+    let fixture = write_fixture(TIMING_VCD, ".timing-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "timing",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--clock",
+            "posedge clk",
+            "--signals",
+            "d",
+            "--setup",
+            "500ps",
+            "--hold",
+            "1ns",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid --setup '500ps': cannot be represented exactly in dump precision '1ns'",
+        ));
+}
+
+// clk rises at 10ns; a changes one tick outside the 2ns/1ns windows, b exactly on their edges.
+const BOUNDARY_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 1 \" a $end\n",
+    "$var wire 1 # b $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\n0#\n",
+    "#7\n1\"\n",
+    "#8\n1#\n",
+    "#10\n1!\n",
+    "#11\n0#\n",
+    "#12\n0\"\n",
+    "#15\n0!\n",
+);
+
+#[test]
+fn timing_windows_include_their_limits() {
+    let fixture = write_fixture(BOUNDARY_VCD, ".timing-boundary.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let expected = concat!(
+        "@8ns setup b 1'h0->1'h1 edge@10ns slack=0ns\n",
+        "@11ns cycle=1 hold b 1'h1->1'h0 edge@10ns slack=0ns\n",
+    );
+
+    wavepeek_cmd()
+        .args(["timing", "--waves", waves, "--signals", "a,b"])
+        .args(CHECK_ARGS)
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(expected);
+
+    wavepeek_cmd()
+        .args([
+            "timing",
+            "--waves",
+            waves,
+            "--signals",
+            "a,b",
+            "--from",
+            "8ns",
+        ])
+        .args(CHECK_ARGS)
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(expected);
+}
+
+#[test]
+fn timing_checks_each_write_of_a_zero_delay_glitch() {
+    let fixture = write_fixture(
+        concat!(
+            "$timescale 1ns $end\n",
+            "$scope module top $end\n",
+            "$var wire 1 ! clk $end\n",
+            "$var wire 1 \" d $end\n",
+            "$upscope $end\n",
+            "$enddefinitions $end\n",
+            "#0\n0!\n0\"\n",
+            "#9\n1\"\n0\"\n",
+            "#10\n1!\n",
+        ),
+        ".timing-glitch.vcd",
+    );
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["timing", "--waves", waves, "--signals", "d"])
+        .args(CHECK_ARGS)
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(concat!(
+            "@9ns setup d 1'h0->1'h1 edge@10ns slack=-1ns\n",
+            "@9ns setup d 1'h1->1'h0 edge@10ns slack=-1ns\n",
+        ));
+}
+
+#[test]
+fn timing_renders_times_and_slack_in_time_unit_with_ticks() {
+    let fixture = write_fixture(TIMING_VCD, ".timing-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args(["timing", "--waves", waves, "--signals", "q"])
+        .args(["--time-unit", "ps"])
+        .args(CHECK_ARGS)
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@20000ps cycle=2 setup q 2'h0->2'h1 edge@20000ps slack=-2000ps\n",
+            "@29000ps cycle=2 setup q 2'h1->2'h2 edge@30000ps slack=-1000ps\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "timing",
+            "--waves",
+            waves,
+            "--signals",
+            "q",
+            "--ticks",
+            "--json",
+        ])
+        .args(CHECK_ARGS)
+        .output()
+        .expect("timing should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(value["data"][1]["time"], "29ns");
+    assert_eq!(value["data"][1]["tick"], 29);
+}
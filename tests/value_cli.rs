@@ -790,3 +790,623 @@ fn value_debug_trace_can_precede_fatal_output() {
             .starts_with("fatal: ")
     );
 }
+
+const PATTERN_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$scope module u_core $end\n",
+    "$var wire 1 \" rd_valid $end\n",
+    "$var wire 1 # wr_valid $end\n",
+    "$var wire 8 $ axi_rdata [7:0] $end\n",
+    "$var wire 8 % axi_wdata [7:0] $end\n",
+    "$scope module sub $end\n",
+    "$var wire 1 & deep_valid $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\n0\"\n1#\nb1 $\nb10 %\n0&\n",
+    "#5\n1!\n1\"\n",
+);
+
+#[test]
+fn value_signal_patterns_expand_in_lexicographic_order() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-pattern.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), PATTERN_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "u_core.*_valid,clk",
+            "--at",
+            "5ns",
+        ])
+        .assert()
+        .success()
+        .stdout("@5ns u_core.rd_valid=1'h1 u_core.wr_valid=1'h1 clk=1'h1\n")
+        .stderr(
+            "info: --signals pattern 'u_core.*_valid' expanded to 2 signal(s): u_core.rd_valid, u_core.wr_valid\n",
+        );
+
+    let output = wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--signals",
+            "re:axi_.*data$",
+            "--at",
+            "5ns",
+            "--json",
+        ])
+        .output()
+        .expect("value should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"][0]["signals"],
+        json!([
+            {"path": "top.u_core.axi_rdata", "value": "8'h01"},
+            {"path": "top.u_core.axi_wdata", "value": "8'h02"}
+        ])
+    );
+    assert_eq!(value["diagnostics"][0]["kind"], "info");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "nope*",
+            "--at",
+            "5ns",
+        ])
+        .assert()
+        .failure()
+        .stderr("fatal: signal: pattern 'nope*' matched no signals in scope 'top'\n");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--signals",
+            "re:(",
+            "--at",
+            "5ns",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("invalid signal pattern 're:('"));
+}
+
+const SELECT_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 16 ! data [15:0] $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n",
+    "b1010010111110000 !\n",
+    "#10\n",
+    "b1010010100001111 !\n",
+);
+
+#[test]
+fn value_bit_and_part_selects_slice_the_sampled_value() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-select.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), SELECT_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "data[15:8],data[4],data[4+:4],data[7-:4]",
+            "--at",
+            "0ns",
+        ])
+        .assert()
+        .success()
+        .stdout("@0ns data[15:8]=8'ha5 data[4]=1'h1 data[4+:4]=4'hf data[7-:4]=4'hf\n");
+
+    let output = wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--signals",
+            "top.data[3:0]",
+            "--at",
+            "10ns",
+            "--json",
+        ])
+        .output()
+        .expect("value should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"][0]["signals"],
+        json!([{"path": "top.data[3:0]", "value": "4'hf"}])
+    );
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--signals",
+            "top.data[16]",
+            "--at",
+            "0ns",
+        ])
+        .assert()
+        .failure()
+        .stderr("fatal: signal: select '[16]' is outside the 16-bit signal 'top.data'\n");
+
+    wavepeek_cmd()
+        .args([
+            "value", "--waves", waves, "--signals", "top.data[a]", "--at", "0ns",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "invalid bit select in signal 'top.data[a]': indices must be constant non-negative integers",
+        ));
+}
+
+const STRUCT_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$scope struct pkt $end\n",
+    "$scope struct hdr $end\n",
+    "$var logic 4 \" len $end\n",
+    "$var logic 1 # valid $end\n",
+    "$upscope $end\n",
+    "$var logic 8 $ data $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n",
+    "0!\n",
+    "b0011 \"\n",
+    "1#\n",
+    "b01011010 $\n",
+    "#10\n",
+    "b0100 \"\n",
+);
+
+#[test]
+fn value_struct_names_return_nested_field_values() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-struct.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), STRUCT_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "pkt,pkt.hdr.len,clk",
+            "--at",
+            "10ns",
+        ])
+        .assert()
+        .success()
+        .stdout("@10ns pkt='{hdr:'{len:4'h4,valid:1'h1},data:8'h5a} pkt.hdr.len=4'h4 clk=1'h0\n");
+
+    let output = wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--signals",
+            "top.pkt.hdr",
+            "--at",
+            "0ns",
+            "--json",
+        ])
+        .output()
+        .expect("value should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"][0]["signals"],
+        json!([{
+            "path": "top.pkt.hdr",
+            "value": "'{len:4'h3,valid:1'h1}",
+            "fields": {"len": "4'h3", "valid": "1'h1"}
+        }])
+    );
+}
+
+const ARRAY_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$scope sv_array mem $end\n",
+    "$var wire 8 ! mem[1] $end\n",
+    "$var wire 8 \" mem[0] $end\n",
+    "$upscope $end\n",
+    "$scope struct req $end\n",
+    "$var logic 1 # valid $end\n",
+    "$var logic 4 $ addr $end\n",
+    "$upscope $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n",
+    "b00000001 !\n",
+    "b00000010 \"\n",
+    "1#\n",
+    "b1010 $\n",
+);
+
+#[test]
+fn value_aggregates_keep_declaration_order_and_expand_arrays() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-array.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), ARRAY_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "req,mem",
+            "--at",
+            "0ns",
+        ])
+        .assert()
+        .success()
+        .stdout("@0ns req='{valid:1'h1,addr:4'ha} mem='{1:8'h01,0:8'h02}\n");
+
+    let output = wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "req,mem",
+            "--at",
+            "0ns",
+            "--json",
+        ])
+        .output()
+        .expect("value should run");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("utf-8 stdout");
+    assert!(
+        stdout.contains(r#""fields":{"valid":"1'h1","addr":"4'ha"}"#),
+        "struct fields should keep declaration order: {stdout}"
+    );
+    assert!(
+        stdout.contains(r#""fields":{"[1]":"8'h01","[0]":"8'h02"}"#),
+        "array elements should keep declaration order: {stdout}"
+    );
+}
+
+#[test]
+fn value_treats_scopes_with_fst_array_attributes_as_aggregates() {
+    let fixture = fixture_path("verilator_pack_array.fst");
+    let waves = fixture.to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "a",
+            "--at",
+            "0ns",
+        ])
+        .assert()
+        .failure()
+        .stderr("fatal: signal: aggregate 'top.a' has no recorded fields\n");
+}
+
+const DESC_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 16 \" desc $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nb0001001000001100 \"\n",
+    "#10\n1!\n",
+    "#15\n0!\nb0011010000010101 \"\n",
+    "#20\n1!\n",
+    "#25\n0!\nb0101011000000110 \"\n",
+    "#30\n1!\n",
+);
+
+fn layout_json(signal: &str, addr_msb: u32) -> String {
+    json!({
+        "$schema": common::expected_input_schema_url(),
+        "kind": "signal.layouts",
+        "layouts": [{
+            "signal": signal,
+            "fields": [
+                {"name": "addr", "msb": addr_msb, "lsb": 8},
+                {"name": "len", "msb": 7, "lsb": 2},
+                {"name": "op", "msb": 1, "lsb": 0, "enum": {"0": "READ", "0x1": "WRITE"}}
+            ]
+        }]
+    })
+    .to_string()
+}
+
+#[test]
+fn value_layout_files_decode_packed_signals_into_fields() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-layout.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), DESC_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+    let layout =
+        tempfile::NamedTempFile::with_suffix(".layout.json").expect("temp layout should create");
+    std::fs::write(layout.path(), layout_json("top.desc", 15)).expect("layout should write");
+    let layout_path = layout.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc,clk",
+            "--at",
+            "0ns,30ns",
+            "--layout",
+            layout_path,
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@0ns desc=16'h120c '{addr:8'h12,len:6'h03,op:READ} clk=1'h0\n",
+            "@30ns desc=16'h5606 '{addr:8'h56,len:6'h01,op:2'h2} clk=1'h1\n",
+        ));
+
+    let output = wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--signals",
+            "top.desc",
+            "--at",
+            "20ns",
+            "--layout",
+            layout_path,
+            "--json",
+        ])
+        .output()
+        .expect("value should run");
+    assert!(output.status.success());
+    let value: Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        value["data"][0]["signals"],
+        json!([{
+            "path": "top.desc",
+            "value": "16'h3415",
+            "fields": {"addr": "8'h34", "len": "6'h05", "op": "WRITE"}
+        }])
+    );
+
+    std::fs::write(layout.path(), layout_json("top.desc", 16)).expect("layout should write");
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--signals",
+            "top.desc",
+            "--at",
+            "0ns",
+            "--layout",
+            layout_path,
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "layout field 'addr' [16:8] is outside the 16-bit signal 'top.desc'",
+        ));
+}
+
+#[test]
+fn value_at_accepts_event_anchors_and_relative_entries() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-time-expr.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), DESC_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc",
+            "--at",
+            "nth(2, posedge clk),+5ns,last(posedge clk)-1ns,end",
+        ])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "@20ns desc=16'h3415\n",
+            "@25ns desc=16'h5606\n",
+            "@29ns desc=16'h5606\n",
+            "@30ns desc=16'h5606\n",
+        ))
+        .stderr(concat!(
+            "info: --at time expression 'nth(2, posedge clk)' resolved to 20ns\n",
+            "info: --at time expression '+5ns' resolved to 25ns\n",
+            "info: --at time expression 'last(posedge clk)-1ns' resolved to 29ns\n",
+            "info: --at time expression 'end' resolved to 30ns\n",
+        ));
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc",
+            "--at",
+            "first(posedge clk)-1ps",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid time expression 'first(posedge clk)-1ps' for --at: '1ps' cannot be represented exactly in dump precision '1ns'",
+        ));
+}
+
+#[test]
+fn value_clock_reports_cycles_and_accepts_cycle_times() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-clock.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), DESC_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let output = wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc",
+            "--clock",
+            "posedge clk",
+            "--at",
+            "0ns,2cyc,+1ns",
+            "--json",
+        ])
+        .output()
+        .expect("value should run");
+    assert!(output.status.success());
+    let value: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("json output should parse");
+    let rows = value["data"].as_array().expect("data should be an array");
+    assert!(rows[0].get("cycle").is_none());
+    assert_eq!(rows[1]["cycle"], 2);
+    assert_eq!(rows[2]["cycle"], 2);
+    assert_eq!(rows[2]["time"].as_str(), Some("21ns"));
+}
+
+#[test]
+fn value_cycle_times_match_nth_clock_edges() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-clock-nth.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), DESC_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    for n in 1..=2 {
+        let output = wavepeek_cmd()
+            .args([
+                "value",
+                "--waves",
+                waves,
+                "--scope",
+                "top",
+                "--signals",
+                "desc",
+                "--clock",
+                "posedge clk",
+                "--at",
+                &format!("{n}cyc,nth({n}, posedge clk)"),
+                "--json",
+            ])
+            .output()
+            .expect("value should run");
+        assert!(output.status.success());
+        let value: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("json output should parse");
+        let rows = value["data"].as_array().expect("data should be an array");
+        assert_eq!(rows[0]["time"], rows[1]["time"], "{n}cyc and nth({n}, ...)");
+        assert_eq!(rows[0]["cycle"], n);
+        assert_eq!(rows[1]["cycle"], n);
+    }
+}
+
+#[test]
+fn value_time_unit_auto_picks_units_per_row() {
+    let fixture = tempfile::NamedTempFile::with_suffix(".value-time-unit.vcd")
+        .expect("temp fixture should create");
+    std::fs::write(fixture.path(), DESC_VCD).expect("fixture should write");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc",
+            "--at",
+            "0ns,20ns",
+            "--time-unit",
+            "auto",
+        ])
+        .assert()
+        .success()
+        .stdout(concat!("@0ns desc=16'h120c\n", "@20ns desc=16'h3415\n"));
+
+    wavepeek_cmd()
+        .args([
+            "value",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "desc",
+            "--at",
+            "20ns",
+            "--time-unit",
+            "us",
+        ])
+        .assert()
+        .success()
+        .stdout("@0.02us desc=16'h3415\n");
+}
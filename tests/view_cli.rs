@@ -0,0 +1,191 @@
+use std::fs;
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use tempfile::NamedTempFile;
+
+mod common;
+use common::wavepeek_cmd;
+
+fn write_fixture(contents: &str, suffix: &str) -> NamedTempFile {
+    let fixture = NamedTempFile::with_suffix(suffix).expect("temp fixture should create");
+    fs::write(fixture.path(), contents).expect("fixture should write");
+    fixture
+}
+
+const VIEW_VCD: &str = concat!(
+    "$timescale 1ns $end\n",
+    "$scope module top $end\n",
+    "$var wire 1 ! clk $end\n",
+    "$var wire 8 \" data $end\n",
+    "$var wire 1 # valid $end\n",
+    "$upscope $end\n",
+    "$enddefinitions $end\n",
+    "#0\n0!\nbxxxxxxxx \"\n0#\n",
+    "#5\n1!\n",
+    "#10\n0!\nb00001111 \"\n1#\n",
+    "#15\n1!\n",
+    "#20\n0!\nb00010000 \"\n",
+    "#25\n1!\n",
+    "#30\n0!\nbzzzzzzzz \"\n0#\n",
+    "#35\n1!\n",
+    "#40\n0!\n",
+);
+
+fn stdout_text(args: &[&str]) -> String {
+    let mut command = wavepeek_cmd();
+    let assert = command.args(args).assert().success();
+    let output = assert.get_output();
+    assert!(
+        output.stderr.is_empty(),
+        "expected empty stderr, got: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).expect("stdout should be UTF-8")
+}
+
+#[test]
+fn view_draws_bit_and_bus_traces_under_time_ruler() {
+    let fixture = write_fixture(VIEW_VCD, ".view-unicode.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let stdout = stdout_text(&[
+        "view",
+        "--waves",
+        waves,
+        "--scope",
+        "top",
+        "--signals",
+        "clk,data,valid",
+        "--width",
+        "60",
+    ]);
+
+    assert_eq!(
+        stdout,
+        concat!(
+            "      |0ns |5ns |10ns |16ns |22ns |28ns |34ns |40ns\n",
+            "clk   ▁▁▁▁▁╱▔▔▔▔╲▁▁▁▁╱▔▔▔▔╲▁▁▁▁╱▔▔▔▔╲▁▁▁▁╱▔▔▔▔╲\n",
+            "data  ▒▒▒▒▒▒▒▒▒▒<0f      ><10      >───────────\n",
+            "valid ▁▁▁▁▁▁▁▁▁▁╱▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔▔╲▁▁▁▁▁▁▁▁▁▁\n",
+        )
+    );
+}
+
+#[test]
+fn view_ascii_mode_and_width_bound_every_line() {
+    let fixture = write_fixture(VIEW_VCD, ".view-ascii.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let stdout = stdout_text(&[
+        "view",
+        "--waves",
+        waves,
+        "--signals",
+        "top.clk,top.data",
+        "--from",
+        "10ns",
+        "--to",
+        "40ns",
+        "--width",
+        "24",
+        "--ascii",
+    ]);
+
+    assert!(stdout.is_ascii());
+    for line in stdout.lines() {
+        assert!(line.chars().count() <= 24, "line exceeds width: {line:?}");
+    }
+    assert!(stdout.lines().nth(1).unwrap().starts_with("top.clk  "));
+    assert!(stdout.contains("|10ns"));
+}
+
+#[test]
+fn view_uses_columns_environment_when_width_is_omitted() {
+    let fixture = write_fixture(VIEW_VCD, ".view-columns.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let mut command = wavepeek_cmd();
+    let assert = command
+        .env("COLUMNS", "30")
+        .args([
+            "view",
+            "--waves",
+            waves,
+            "--scope",
+            "top",
+            "--signals",
+            "clk",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).expect("utf-8 stdout");
+
+    for line in stdout.lines() {
+        assert!(line.chars().count() <= 30, "line exceeds width: {line:?}");
+    }
+}
+
+#[test]
+fn view_rejects_narrow_width_and_json_flags() {
+    let fixture = write_fixture(VIEW_VCD, ".view-errors.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let mut command = wavepeek_cmd();
+    command
+        .args([
+            "view",
+            "--waves",
+            waves,
+            "--signals",
+            "top.clk",
+            "--width",
+            "8",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "fatal: args: --width must be at least 20 columns.",
+        ));
+
+    let mut command = wavepeek_cmd();
+    command
+        .args(["view", "--waves", waves, "--signals", "top.clk", "--json"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::starts_with("fatal: args:"));
+}
+
+#[test]
+fn view_renders_ruler_in_time_unit() {
+    let fixture = write_fixture(VIEW_VCD, ".view-time-unit.vcd");
+    let waves = fixture.path().to_str().expect("utf-8 path");
+
+    let stdout = stdout_text(&[
+        "view",
+        "--waves",
+        waves,
+        "--scope",
+        "top",
+        "--signals",
+        "clk",
+        "--width",
+        "60",
+        "--time-unit",
+        "ps",
+    ]);
+
+    assert!(
+        stdout.starts_with("    |0ps "),
+        "unexpected ruler: {stdout}"
+    );
+    assert!(
+        stdout
+            .lines()
+            .next()
+            .expect("ruler line")
+            .ends_with("|40000ps")
+    );
+}
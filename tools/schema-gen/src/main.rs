@@ -131,6 +131,28 @@ fn validate_schemas(schema_dir: &Path) -> Result<(), String> {
         &invalid_input,
         "input sources must not be empty",
     )?;
+
+    let valid_layouts = json!({
+        "$schema": INPUT_SCHEMA_URL,
+        "kind": "signal.layouts",
+        "layouts": [{
+            "signal": "top.dma.desc",
+            "fields": [
+                {"name": "addr", "msb": 63, "lsb": 32},
+                {"name": "op", "msb": 1, "lsb": 0, "enum": {"0": "READ", "0x1": "WRITE"}}
+            ]
+        }],
+    });
+    input_validator
+        .validate(&valid_layouts)
+        .map_err(|error| format!("valid layout sample failed validation: {error}"))?;
+    let mut invalid_layouts = valid_layouts;
+    invalid_layouts["layouts"][0]["fields"][0]["name"] = json!("addr.hi");
+    expect_invalid(
+        &input_validator,
+        &invalid_layouts,
+        "layout field names must be identifiers",
+    )?;
     Ok(())
 }
 